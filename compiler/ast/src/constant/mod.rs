@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier, Node, Type};
+
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A program-scope constant declaration, e.g `const MAX_PLAYERS: u8 = 8u8;`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstDeclaration {
+    /// The name of the constant.
+    pub identifier: Identifier,
+    /// The type of the constant.
+    pub type_: Type,
+    /// The value of the constant.
+    pub value: Expression,
+    /// The span excluding the semicolon.
+    pub span: Span,
+}
+
+impl fmt::Display for ConstDeclaration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "const {}: {} = {};", self.identifier, self.type_, self.value)
+    }
+}
+
+crate::simple_node_impl!(ConstDeclaration);
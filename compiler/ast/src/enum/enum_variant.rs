@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Node, Type};
+
+use leo_span::{Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A variant of an enum, e.g. `Open` or `Limit(u64, u64)`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumVariant {
+    /// The name of the variant.
+    pub identifier: Identifier,
+    /// The types of the values carried by the variant, if any.
+    pub payload: Vec<Type>,
+    /// The span of the variant.
+    pub span: Span,
+}
+
+impl EnumVariant {
+    /// Returns the name of the variant without span.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+}
+
+impl fmt::Display for EnumVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.payload.is_empty() {
+            true => write!(f, "{}", self.identifier),
+            false => write!(
+                f,
+                "{}({})",
+                self.identifier,
+                self.payload
+                    .iter()
+                    .map(|type_| type_.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+crate::simple_node_impl!(EnumVariant);
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod enum_variant;
+pub use enum_variant::*;
+
+use crate::{
+    Expression, GroupLiteral, Identifier, IntegerType, Literal, Member, Mode, Node, Struct, StructExpression,
+    StructVariableInitializer, Type,
+};
+use leo_span::{Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The zero address, used as the value of the `address` payloads of inactive variants.
+const ZERO_ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+/// An enum type definition, e.g. `enum Status { Open, Filled, Cancelled }`.
+/// A variant may carry values, e.g. `enum Order { Market(u64), Limit(u64, u64) }`.
+///
+/// Enums are lowered to structs before code generation.
+/// The struct has a `tag: u8` member holding the index of the variant,
+/// followed by a member `<Variant>_<i>` for the `i`-th payload of each variant.
+/// The payloads of the inactive variants are set to zero, so that two values are equal exactly when their variants and payloads are.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Enum {
+    /// The name of the type in the type system in this module.
+    pub identifier: Identifier,
+    /// The variants of the enum, in order of declaration.
+    pub variants: Vec<EnumVariant>,
+    /// The entire span of the enum definition.
+    pub span: Span,
+}
+
+impl PartialEq for Enum {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+    }
+}
+
+impl Eq for Enum {}
+
+impl Enum {
+    /// The maximum number of variants of an enum, which is the number of values of its `u8` tag.
+    pub const MAX_VARIANTS: usize = 256;
+
+    /// Returns the enum name as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+
+    /// Returns the index and the definition of the variant `name`, if it exists.
+    pub fn variant(&self, name: Symbol) -> Option<(usize, &EnumVariant)> {
+        self.variants
+            .iter()
+            .enumerate()
+            .find(|(_, variant)| variant.name() == name)
+    }
+
+    /// Returns the name of the member of the lowered struct that stores the tag.
+    pub fn tag_member() -> Symbol {
+        Symbol::intern("tag")
+    }
+
+    /// Returns the name of the member of the lowered struct that stores the `index`-th payload of `variant`.
+    pub fn payload_member(variant: Symbol, index: usize) -> Symbol {
+        Symbol::intern(&format!("{variant}_{index}"))
+    }
+
+    /// Returns the tag of the variant with the given index.
+    pub fn tag(index: usize, span: Span) -> Literal {
+        Literal::Integer(IntegerType::U8, index.to_string(), span)
+    }
+
+    /// Returns the struct that the enum is lowered to.
+    pub fn to_struct(&self) -> Struct {
+        let tag = Member {
+            mode: Mode::None,
+            identifier: Identifier {
+                name: Self::tag_member(),
+                span: self.identifier.span,
+            },
+            type_: Type::Integer(IntegerType::U8),
+            span: self.identifier.span,
+        };
+        let payloads = self.variants.iter().flat_map(|variant| {
+            variant.payload.iter().enumerate().map(|(index, type_)| Member {
+                mode: Mode::None,
+                identifier: Identifier {
+                    name: Self::payload_member(variant.name(), index),
+                    span: variant.span,
+                },
+                type_: type_.clone(),
+                span: variant.span,
+            })
+        });
+
+        Struct {
+            identifier: self.identifier,
+            members: std::iter::once(tag).chain(payloads).collect(),
+            is_record: false,
+            span: self.span,
+        }
+    }
+
+    /// Returns the initialization of the lowered struct for the value `variant(payload)`,
+    /// or `None` if the enum has no such variant, or the variant carries a different number of values.
+    pub fn construct(&self, variant: Symbol, payload: Vec<Expression>, span: Span) -> Option<Expression> {
+        let (index, definition) = self.variant(variant)?;
+        if definition.payload.len() != payload.len() {
+            return None;
+        }
+        let mut payload = payload.into_iter();
+
+        let tag = StructVariableInitializer {
+            identifier: Identifier {
+                name: Self::tag_member(),
+                span,
+            },
+            expression: Some(Expression::Literal(Self::tag(index, span))),
+        };
+        let payloads = self.variants.iter().flat_map(|other| {
+            other
+                .payload
+                .iter()
+                .enumerate()
+                .map(|(i, type_)| StructVariableInitializer {
+                    identifier: Identifier {
+                        name: Self::payload_member(other.name(), i),
+                        span,
+                    },
+                    expression: Some(match other.name() == variant {
+                        true => payload.next().unwrap(),
+                        false => Self::zero(type_, span),
+                    }),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Some(Expression::Struct(StructExpression {
+            name: self.identifier,
+            members: std::iter::once(tag).chain(payloads).collect(),
+            span,
+        }))
+    }
+
+    /// Returns the zero value of a payload type.
+    fn zero(type_: &Type, span: Span) -> Expression {
+        Expression::Literal(match type_ {
+            Type::Address => Literal::Address(ZERO_ADDRESS.to_string(), span),
+            Type::Boolean => Literal::Boolean(false, span),
+            Type::Field => Literal::Field("0".to_string(), span),
+            Type::Group => Literal::Group(Box::new(GroupLiteral::Single("0".to_string(), span))),
+            Type::Integer(integer_type) => Literal::Integer(*integer_type, "0".to_string(), span),
+            Type::Scalar => Literal::Scalar("0".to_string(), span),
+            _ => unreachable!("Type checking guarantees that the payloads of enum variants have primitive types."),
+        })
+    }
+}
+
+impl fmt::Debug for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "enum {} {{ ", self.identifier)?;
+        for variant in self.variants.iter() {
+            writeln!(f, "    {variant},")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(Enum);
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::ArrayType;
+
+use std::cell::RefCell;
+
+/// An array expression, e.g., `[true, false, true, false]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrayExpression {
+    /// The elements of the array.
+    /// In the example above, it would be `true`, `false`, `true`, and `false`.
+    pub elements: Vec<Expression>,
+    /// The type of the array, as determined by type checking.
+    #[serde(skip)]
+    pub type_: RefCell<Option<ArrayType>>,
+    /// The span from `[` to `]`.
+    pub span: Span,
+}
+
+impl fmt::Display for ArrayExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}]",
+            self.elements
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+crate::simple_node_impl!(ArrayExpression);
@@ -15,12 +15,15 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::Type;
 use leo_span::{sym, Symbol};
 
+use std::cell::RefCell;
+
 /// A binary operator.
 ///
 /// Precedence is defined in the parser.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BinaryOperation {
     /// Addition, i.e. `+`, `.add()`.
     Add,
@@ -173,6 +176,9 @@ pub struct BinaryExpression {
     pub right: Box<Expression>,
     /// The operand defining the meaning of the resulting binary expression.
     pub op: BinaryOperation,
+    /// The type of the left operand, as determined by type checking, e.g. `group` for `group * scalar`.
+    #[serde(skip)]
+    pub operand_type: RefCell<Option<Type>>,
     /// The span from `left` to `right`.
     pub span: Span,
 }
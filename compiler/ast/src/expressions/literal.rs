@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, IntegerType};
+use crate::{GroupLiteral, IntegerType, Type};
 
 use super::*;
 
@@ -42,6 +42,21 @@ pub enum Literal {
     String(String, #[serde(with = "leo_span::span_json")] Span),
 }
 
+impl Literal {
+    /// Returns the type of the literal.
+    pub fn type_(&self) -> Type {
+        match self {
+            Self::Address(..) => Type::Address,
+            Self::Boolean(..) => Type::Boolean,
+            Self::Field(..) => Type::Field,
+            Self::Group(..) => Type::Group,
+            Self::Integer(integer_type, ..) => Type::Integer(*integer_type),
+            Self::Scalar(..) => Type::Scalar,
+            Self::String(..) => Type::String,
+        }
+    }
+}
+
 impl fmt::Display for Literal {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self {
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::MatchPattern;
+
+/// A `match expression { pattern => expression, ... }` expression.
+/// The arms are tried in order, and the expression evaluates to the expression of the first arm whose pattern matches.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchExpression {
+    /// The value being matched.
+    pub expression: Box<Expression>,
+    /// The arms of the expression.
+    pub arms: Vec<MatchExpressionArm>,
+    /// The span from `match` to `}`.
+    pub span: Span,
+}
+
+impl fmt::Display for MatchExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "match {} {{ {} }}",
+            self.expression,
+            self.arms
+                .iter()
+                .map(|arm| arm.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+crate::simple_node_impl!(MatchExpression);
+
+/// An arm `pattern => expression` of a `match` expression.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchExpressionArm {
+    /// The pattern that the value is compared against.
+    pub pattern: MatchPattern,
+    /// The expression to evaluate if the pattern matches.
+    pub expression: Expression,
+    /// The span from the pattern to the end of the expression.
+    pub span: Span,
+}
+
+impl fmt::Display for MatchExpressionArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.expression)
+    }
+}
+
+crate::simple_node_impl!(MatchExpressionArm);
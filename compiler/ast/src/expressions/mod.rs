@@ -23,12 +23,18 @@ use std::fmt;
 mod access;
 pub use access::*;
 
+mod array;
+pub use array::*;
+
 mod binary;
 pub use binary::*;
 
 mod call;
 pub use call::*;
 
+mod cast;
+pub use cast::*;
+
 mod struct_init;
 pub use struct_init::*;
 
@@ -50,15 +56,22 @@ pub use unit::*;
 mod literal;
 pub use literal::*;
 
+mod match_;
+pub use match_::*;
+
 /// Expression that evaluates to a value.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Expression {
     /// A struct access expression, e.g., `Foo.bar`.
     Access(AccessExpression),
+    /// An array expression, e.g., `[true, false, true, false]`.
+    Array(ArrayExpression),
     /// A binary expression, e.g., `42 + 24`.
     Binary(BinaryExpression),
     /// A call expression, e.g., `my_fun(args)`.
     Call(CallExpression),
+    /// A cast expression, e.g., `42u32 as u8`.
+    Cast(CastExpression),
     /// An expression constructing a struct like `Foo { bar: 42, baz }`.
     Struct(StructExpression),
     /// An expression of type "error".
@@ -68,6 +81,8 @@ pub enum Expression {
     Identifier(Identifier),
     /// A literal expression.
     Literal(Literal),
+    /// A match expression, e.g. `match x { 0u8 => a, _ => b }`.
+    Match(MatchExpression),
     /// A ternary conditional expression `cond ? if_expr : else_expr`.
     Ternary(TernaryExpression),
     /// A tuple expression e.g., `(foo, 42, true)`.
@@ -83,12 +98,15 @@ impl Node for Expression {
         use Expression::*;
         match self {
             Access(n) => n.span(),
+            Array(n) => n.span(),
             Binary(n) => n.span(),
             Call(n) => n.span(),
+            Cast(n) => n.span(),
             Struct(n) => n.span(),
             Err(n) => n.span(),
             Identifier(n) => n.span(),
             Literal(n) => n.span(),
+            Match(n) => n.span(),
             Ternary(n) => n.span(),
             Tuple(n) => n.span(),
             Unary(n) => n.span(),
@@ -100,11 +118,14 @@ impl Node for Expression {
         use Expression::*;
         match self {
             Access(n) => n.set_span(span),
+            Array(n) => n.set_span(span),
             Binary(n) => n.set_span(span),
             Call(n) => n.set_span(span),
+            Cast(n) => n.set_span(span),
             Struct(n) => n.set_span(span),
             Identifier(n) => n.set_span(span),
             Literal(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             Err(n) => n.set_span(span),
             Ternary(n) => n.set_span(span),
             Tuple(n) => n.set_span(span),
@@ -119,12 +140,15 @@ impl fmt::Display for Expression {
         use Expression::*;
         match &self {
             Access(n) => n.fmt(f),
+            Array(n) => n.fmt(f),
             Binary(n) => n.fmt(f),
             Call(n) => n.fmt(f),
+            Cast(n) => n.fmt(f),
             Struct(n) => n.fmt(f),
             Err(n) => n.fmt(f),
             Identifier(n) => n.fmt(f),
             Literal(n) => n.fmt(f),
+            Match(n) => n.fmt(f),
             Ternary(n) => n.fmt(f),
             Tuple(n) => n.fmt(f),
             Unary(n) => n.fmt(f),
@@ -15,10 +15,13 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::Type;
 use leo_span::{sym, Symbol};
 
+use std::cell::RefCell;
+
 /// A unary operator for a unary expression.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum UnaryOperation {
     /// Absolute value checking for overflow, i.e. `.abs()`.
     Abs,
@@ -76,6 +79,9 @@ pub struct UnaryExpression {
     pub receiver: Box<Expression>,
     /// The unary operator to apply to `inner`.
     pub op: UnaryOperation,
+    /// The type of the operand, as determined by type checking.
+    #[serde(skip)]
+    pub operand_type: RefCell<Option<Type>>,
     /// The span covering `op inner`.
     pub span: Span,
 }
@@ -119,6 +119,13 @@ impl Function {
         self.name() == sym::main
     }
 
+    /// Returns `true` if the function is annotated with `@test`.
+    pub fn is_test(&self) -> bool {
+        self.annotations
+            .iter()
+            .any(|annotation| annotation.identifier.name == sym::test)
+    }
+
     ///
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{normalize_json_value, remove_key_from_json, Expression, Struct, Type};
+use crate::{
+    normalize_json_value, remove_key_from_json, AccessExpression, AssociatedConstant, AssociatedFunction, Enum,
+    Expression, Node, Struct, Type,
+};
 
 use super::*;
 use leo_errors::{AstError, Result};
@@ -41,12 +44,49 @@ pub struct InputAst {
 
 impl InputAst {
     /// Returns all values of the input AST for execution with `leo run`.
-    pub fn program_inputs(&self, program_name: &str, structs: IndexMap<Symbol, Struct>) -> Vec<String> {
-        self.sections
-            .iter()
-            .filter(|section| section.name() == program_name)
-            .flat_map(|section| {
-                section.definitions.iter().map(|definition| match &definition.type_ {
+    /// Enum values, e.g. `Status::Open`, are given as the structs they are lowered to.
+    pub fn program_inputs(
+        &self,
+        program_name: &str,
+        structs: IndexMap<Symbol, Struct>,
+        enums: IndexMap<Symbol, Enum>,
+    ) -> Result<Vec<String>> {
+        let mut inputs = Vec::new();
+        for section in self.sections.iter().filter(|section| section.name() == program_name) {
+            for definition in section.definitions.iter() {
+                inputs.push(match &definition.type_ {
+                    // Handle case where the input is an enum value.
+                    Type::Identifier(identifier) if enums.contains_key(&identifier.name) => {
+                        let (variant, payload, span) = match &definition.value {
+                            Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                                name,
+                                span,
+                                ..
+                            })) => (name.name, Vec::new(), *span),
+                            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                                name,
+                                args,
+                                span,
+                                ..
+                            })) => (name.name, args.clone(), *span),
+                            value => return Err(InputError::expected_enum_variant(identifier, value.span()).into()),
+                        };
+                        let enum_ = &enums[&identifier.name];
+                        match enum_.variant(variant) {
+                            None => return Err(InputError::unknown_enum_variant(identifier, variant, span).into()),
+                            Some((_, declaration)) if declaration.payload.len() != payload.len() => {
+                                return Err(InputError::incorrect_enum_payload_count(
+                                    variant,
+                                    declaration.payload.len(),
+                                    payload.len(),
+                                    span,
+                                )
+                                .into());
+                            }
+                            // Note that the variant exists and is given the right number of values, so it can be constructed.
+                            Some(_) => enum_.construct(variant, payload, span).unwrap().to_string(),
+                        }
+                    }
                     // Handle case where the input may be record.
                     Type::Identifier(identifier) => {
                         match structs.get(&identifier.name) {
@@ -66,9 +106,10 @@ impl InputAst {
                         }
                     }
                     _ => definition.value.to_string(),
-                })
-            })
-            .collect::<Vec<_>>()
+                });
+            }
+        }
+        Ok(inputs)
     }
 
     /// Serializes the `Input` into a JSON Value.
@@ -30,9 +30,15 @@ pub use self::access::*;
 pub mod r#struct;
 pub use self::r#struct::*;
 
+pub mod r#enum;
+pub use self::r#enum::*;
+
 pub mod common;
 pub use self::common::*;
 
+pub mod constant;
+pub use self::constant::*;
+
 pub mod expressions;
 pub use self::expressions::*;
 
@@ -134,6 +140,13 @@ impl Ast {
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 
+    /// Writes the ast into a file as Leo source code.
+    pub fn to_leo_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        Ok(std::fs::write(&path, self.ast.to_string())
+            .map_err(|e| AstError::failed_to_write_ast_to_leo_file(&path, &e))?)
+    }
+
     /// Deserializes the JSON string into a ast.
     pub fn from_json_string(json: &str) -> Result<Self> {
         let ast: Program = serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
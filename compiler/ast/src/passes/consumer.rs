@@ -26,12 +26,15 @@ pub trait ExpressionConsumer {
     fn consume_expression(&mut self, input: Expression) -> Self::Output {
         match input {
             Expression::Access(access) => self.consume_access(access),
+            Expression::Array(array) => self.consume_array(array),
             Expression::Binary(binary) => self.consume_binary(binary),
             Expression::Call(call) => self.consume_call(call),
+            Expression::Cast(cast) => self.consume_cast(cast),
             Expression::Struct(struct_) => self.consume_struct_init(struct_),
             Expression::Err(err) => self.consume_err(err),
             Expression::Identifier(identifier) => self.consume_identifier(identifier),
             Expression::Literal(value) => self.consume_literal(value),
+            Expression::Match(match_) => self.consume_match_expression(match_),
             Expression::Ternary(ternary) => self.consume_ternary(ternary),
             Expression::Tuple(tuple) => self.consume_tuple(tuple),
             Expression::Unary(unary) => self.consume_unary(unary),
@@ -41,10 +44,14 @@ pub trait ExpressionConsumer {
 
     fn consume_access(&mut self, _input: AccessExpression) -> Self::Output;
 
+    fn consume_array(&mut self, _input: ArrayExpression) -> Self::Output;
+
     fn consume_binary(&mut self, _input: BinaryExpression) -> Self::Output;
 
     fn consume_call(&mut self, _input: CallExpression) -> Self::Output;
 
+    fn consume_cast(&mut self, _input: CastExpression) -> Self::Output;
+
     fn consume_struct_init(&mut self, _input: StructExpression) -> Self::Output;
 
     fn consume_err(&mut self, _input: ErrExpression) -> Self::Output {
@@ -55,6 +62,10 @@ pub trait ExpressionConsumer {
 
     fn consume_literal(&mut self, _input: Literal) -> Self::Output;
 
+    fn consume_match_expression(&mut self, _input: MatchExpression) -> Self::Output {
+        unreachable!("`MatchExpression`s should not be in the AST at this phase of compilation.")
+    }
+
     fn consume_ternary(&mut self, _input: TernaryExpression) -> Self::Output;
 
     fn consume_tuple(&mut self, _input: TupleExpression) -> Self::Output;
@@ -80,6 +91,7 @@ pub trait StatementConsumer {
             Statement::Expression(stmt) => self.consume_expression_statement(stmt),
             Statement::Increment(stmt) => self.consume_increment(stmt),
             Statement::Iteration(stmt) => self.consume_iteration(*stmt),
+            Statement::Match(stmt) => self.consume_match(stmt),
             Statement::Return(stmt) => self.consume_return(stmt),
         }
     }
@@ -104,6 +116,10 @@ pub trait StatementConsumer {
 
     fn consume_iteration(&mut self, input: IterationStatement) -> Self::Output;
 
+    fn consume_match(&mut self, _input: MatchStatement) -> Self::Output {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+    }
+
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output;
 }
 
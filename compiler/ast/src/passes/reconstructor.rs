@@ -27,12 +27,15 @@ pub trait ExpressionReconstructor {
     fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
         match input {
             Expression::Access(access) => self.reconstruct_access(access),
+            Expression::Array(array) => self.reconstruct_array(array),
             Expression::Binary(binary) => self.reconstruct_binary(binary),
             Expression::Call(call) => self.reconstruct_call(call),
+            Expression::Cast(cast) => self.reconstruct_cast(cast),
             Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
             Expression::Err(err) => self.reconstruct_err(err),
             Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
             Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Match(match_) => self.reconstruct_match_expression(match_),
             Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
             Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
             Expression::Unary(unary) => self.reconstruct_unary(unary),
@@ -43,6 +46,11 @@ pub trait ExpressionReconstructor {
     fn reconstruct_access(&mut self, input: AccessExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Access(match input {
+                AccessExpression::Array(array) => AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.reconstruct_expression(*array.array).0),
+                    index: Box::new(self.reconstruct_expression(*array.index).0),
+                    span: array.span,
+                }),
                 AccessExpression::AssociatedFunction(function) => {
                     AccessExpression::AssociatedFunction(AssociatedFunction {
                         ty: function.ty,
@@ -71,6 +79,21 @@ pub trait ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_array(&mut self, input: ArrayExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Array(ArrayExpression {
+                elements: input
+                    .elements
+                    .into_iter()
+                    .map(|element| self.reconstruct_expression(element).0)
+                    .collect(),
+                type_: input.type_,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Binary(BinaryExpression {
@@ -78,6 +101,7 @@ pub trait ExpressionReconstructor {
                 right: Box::new(self.reconstruct_expression(*input.right).0),
                 op: input.op,
                 span: input.span,
+                operand_type: input.operand_type,
             }),
             Default::default(),
         )
@@ -99,6 +123,19 @@ pub trait ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: input.type_,
+                expression_type: input.expression_type,
+                lossy: input.lossy,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
         (Expression::Struct(input), Default::default())
     }
@@ -115,6 +152,25 @@ pub trait ExpressionReconstructor {
         (Expression::Literal(input), Default::default())
     }
 
+    fn reconstruct_match_expression(&mut self, input: MatchExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Match(MatchExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchExpressionArm {
+                        pattern: arm.pattern,
+                        expression: self.reconstruct_expression(arm.expression).0,
+                        span: arm.span,
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Ternary(TernaryExpression {
@@ -146,6 +202,7 @@ pub trait ExpressionReconstructor {
             Expression::Unary(UnaryExpression {
                 receiver: Box::new(self.reconstruct_expression(*input.receiver).0),
                 op: input.op,
+                operand_type: input.operand_type,
                 span: input.span,
             }),
             Default::default(),
@@ -174,6 +231,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
             Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
             Statement::Increment(stmt) => self.reconstruct_increment(stmt),
             Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Match(stmt) => self.reconstruct_match(stmt),
             Statement::Return(stmt) => self.reconstruct_return(stmt),
         }
     }
@@ -319,6 +377,25 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_match(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Match(MatchStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        pattern: arm.pattern,
+                        block: self.reconstruct_block(arm.block).0,
+                        span: arm.span,
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Return(ReturnStatement {
@@ -345,6 +422,8 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 .into_iter()
                 .map(|(id, import)| (id, (self.reconstruct_import(import.0), import.1)))
                 .collect(),
+            stubs: input.stubs,
+            modules: input.modules,
             program_scopes: input
                 .program_scopes
                 .into_iter()
@@ -356,11 +435,21 @@ pub trait ProgramReconstructor: StatementReconstructor {
     fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
         ProgramScope {
             program_id: input.program_id,
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| (i, self.reconstruct_const(c)))
+                .collect(),
             structs: input
                 .structs
                 .into_iter()
                 .map(|(i, c)| (i, self.reconstruct_struct(c)))
                 .collect(),
+            enums: input
+                .enums
+                .into_iter()
+                .map(|(i, e)| (i, self.reconstruct_enum(e)))
+                .collect(),
             mappings: input
                 .mappings
                 .into_iter()
@@ -396,10 +485,23 @@ pub trait ProgramReconstructor: StatementReconstructor {
         }
     }
 
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> ConstDeclaration {
+        ConstDeclaration {
+            identifier: input.identifier,
+            type_: input.type_,
+            value: self.reconstruct_expression(input.value).0,
+            span: input.span,
+        }
+    }
+
     fn reconstruct_struct(&mut self, input: Struct) -> Struct {
         input
     }
 
+    fn reconstruct_enum(&mut self, input: Enum) -> Enum {
+        input
+    }
+
     fn reconstruct_import(&mut self, input: Program) -> Program {
         self.reconstruct_program(input)
     }
@@ -28,12 +28,15 @@ pub trait ExpressionVisitor<'a> {
     fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
         match input {
             Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
             Expression::Binary(binary) => self.visit_binary(binary, additional),
             Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
             Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
             Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Match(match_) => self.visit_match_expression(match_, additional),
             Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
             Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
             Expression::Unary(unary) => self.visit_unary(unary, additional),
@@ -43,6 +46,10 @@ pub trait ExpressionVisitor<'a> {
 
     fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
         match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
             AccessExpression::AssociatedFunction(function) => {
                 function.args.iter().for_each(|arg| {
                     self.visit_expression(arg, &Default::default());
@@ -60,6 +67,13 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.elements.iter().for_each(|expr| {
+            self.visit_expression(expr, additional);
+        });
+        Default::default()
+    }
+
     fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.left, additional);
         self.visit_expression(&input.right, additional);
@@ -73,6 +87,11 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        Default::default()
+    }
+
     fn visit_struct_init(&mut self, _input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
         Default::default()
     }
@@ -89,6 +108,18 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_match_expression(
+        &mut self,
+        input: &'a MatchExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        self.visit_expression(&input.expression, &Default::default());
+        input.arms.iter().for_each(|arm| {
+            self.visit_expression(&arm.expression, additional);
+        });
+        Default::default()
+    }
+
     fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.condition, additional);
         self.visit_expression(&input.if_true, additional);
@@ -127,6 +158,7 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Increment(stmt) => self.visit_increment(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -199,6 +231,11 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         self.visit_block(&input.block);
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        self.visit_expression(&input.expression, &Default::default());
+        input.arms.iter().for_each(|arm| self.visit_block(&arm.block));
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
         if let Some(arguments) = &input.finalize_arguments {
@@ -212,6 +249,8 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
 /// A Visitor trait for the program represented by the AST.
 pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
     fn visit_program(&mut self, input: &'a Program) {
+        input.stubs.values().for_each(|stub| self.visit_stub(&stub.0));
+
         input.imports.values().for_each(|import| self.visit_import(&import.0));
 
         input
@@ -221,8 +260,12 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
     }
 
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        input.consts.values().for_each(|const_| self.visit_const(const_));
+
         input.structs.values().for_each(|function| self.visit_struct(function));
 
+        input.enums.values().for_each(|enum_| self.visit_enum(enum_));
+
         input.mappings.values().for_each(|mapping| self.visit_mapping(mapping));
 
         input
@@ -235,8 +278,18 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
         self.visit_program(input)
     }
 
+    /// Visits the interface of a program imported from Aleo instructions.
+    /// Note that the transitions of an interface have empty bodies, so it is not visited by default.
+    fn visit_stub(&mut self, _input: &'a Program) {}
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_expression(&input.value, &Default::default());
+    }
+
     fn visit_struct(&mut self, _input: &'a Struct) {}
 
+    fn visit_enum(&mut self, _input: &'a Enum) {}
+
     fn visit_mapping(&mut self, _input: &'a Mapping) {}
 
     fn visit_function(&mut self, input: &'a Function) {
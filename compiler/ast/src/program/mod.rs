@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program consists of import statements and program scopes.
+//! A Leo program consists of import statements, module declarations, and program scopes.
+
+pub mod module;
+pub use module::*;
 
 pub mod program_id;
 pub use program_id::*;
@@ -32,16 +35,34 @@ use std::fmt;
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Program {
     /// A map from import names to import definitions.
+    /// The definitions are empty until imports are resolved, after which the map contains every program
+    /// that is imported, directly or indirectly, in dependency order.
     pub imports: IndexMap<Symbol, (Program, Span)>,
+    /// A map from the names of programs imported from Aleo instructions, e.g. `import foo.aleo;`, to their interfaces.
+    /// The interfaces are empty until imports are resolved, after which the map contains the interface of every such program
+    /// that is imported, directly or indirectly, in dependency order.
+    /// An interface declares the records, structs, mappings, and transitions of a deployed program, where each transition has an empty body.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub stubs: IndexMap<Symbol, (Program, Span)>,
+    /// A map from the names of the modules declared by `mod foo;` to their definitions.
+    /// The definitions are empty until modules are resolved, after which their items are merged into the program scope.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub modules: IndexMap<Symbol, (Module, Span)>,
     /// A map from program names to program scopes.
     pub program_scopes: IndexMap<ProgramId, ProgramScope>,
 }
 
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, _stub) in self.stubs.iter() {
+            writeln!(f, "import {id}.aleo;")?;
+        }
         for (id, _import) in self.imports.iter() {
             writeln!(f, "import {id}.leo;")?;
         }
+        for (id, _module) in self.modules.iter() {
+            writeln!(f, "mod {id};")?;
+        }
         for (_, program_scope) in self.program_scopes.iter() {
             program_scope.fmt(f)?;
             writeln!(f,)?;
@@ -55,6 +76,8 @@ impl Default for Program {
     fn default() -> Self {
         Self {
             imports: IndexMap::new(),
+            stubs: IndexMap::new(),
+            modules: IndexMap::new(),
             program_scopes: IndexMap::new(),
         }
     }
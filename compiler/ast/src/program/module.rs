@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A Leo module consists of constant, struct, and inline function definitions, declared in a file of its own.
+
+use crate::{ConstDeclaration, Function, Struct};
+
+use indexmap::IndexMap;
+use leo_span::{Span, Symbol};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Stores the Leo module abstract syntax tree.
+/// A module `utils` is declared by `mod utils;` in the main file, and its items are referred to by paths, e.g. `utils::Point`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Module {
+    /// A map from constant names to constant declarations.
+    pub consts: IndexMap<Symbol, ConstDeclaration>,
+    /// A map from struct names to struct definitions.
+    pub structs: IndexMap<Symbol, Struct>,
+    /// A map from function names to function definitions.
+    pub functions: IndexMap<Symbol, Function>,
+    /// The span associated with the module.
+    pub span: Span,
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (_, const_) in self.consts.iter() {
+            writeln!(f, "{const_}")?;
+        }
+        for (_, struct_) in self.structs.iter() {
+            writeln!(f, "{struct_}")?;
+        }
+        for (_, function) in self.functions.iter() {
+            writeln!(f, "{function}")?;
+        }
+        Ok(())
+    }
+}
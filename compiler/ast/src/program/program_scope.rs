@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program scope consists of struct, function, and mapping definitions.
+//! A Leo program scope consists of constant, struct, enum, function, and mapping definitions.
 
-use crate::{Function, Mapping, ProgramId, Struct};
+use crate::{ConstDeclaration, Enum, Function, Mapping, ProgramId, Struct};
 
 use indexmap::IndexMap;
 use leo_span::{Span, Symbol};
@@ -28,8 +28,14 @@ use std::fmt;
 pub struct ProgramScope {
     /// The program id of the program scope.
     pub program_id: ProgramId,
+    /// A map from constant names to constant declarations.
+    pub consts: IndexMap<Symbol, ConstDeclaration>,
     /// A map from struct names to struct definitions.
     pub structs: IndexMap<Symbol, Struct>,
+    /// A map from enum names to enum definitions.
+    /// The map is empty after enums are lowered, which replaces each enum with a struct.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub enums: IndexMap<Symbol, Enum>,
     /// A map from mapping names to mapping definitions.
     pub mappings: IndexMap<Symbol, Mapping>,
     /// A map from function names to function definitions.
@@ -41,9 +47,15 @@ pub struct ProgramScope {
 impl fmt::Display for ProgramScope {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "program {} {{", self.program_id)?;
+        for (_, const_) in self.consts.iter() {
+            writeln!(f, "    {const_}")?;
+        }
         for (_, struct_) in self.structs.iter() {
             writeln!(f, "    {struct_}")?;
         }
+        for (_, enum_) in self.enums.iter() {
+            writeln!(f, "    {enum_}")?;
+        }
         for (_, mapping) in self.mappings.iter() {
             writeln!(f, "    {mapping}")?;
         }
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Literal, Node};
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A pattern against which the value of a `match` is compared.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum MatchPattern {
+    /// A boolean or integer literal, e.g. `true` or `-1i8`.
+    Literal(Literal),
+    /// A variant of an enum, e.g. `Status::Open` or `Order::Limit(price, amount)`.
+    Variant(VariantPattern),
+    /// The wildcard `_`, which matches any value.
+    Wildcard(Span),
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Literal(x) => x.fmt(f),
+            MatchPattern::Variant(x) => x.fmt(f),
+            MatchPattern::Wildcard(_) => write!(f, "_"),
+        }
+    }
+}
+
+impl Node for MatchPattern {
+    fn span(&self) -> Span {
+        match self {
+            MatchPattern::Literal(n) => n.span(),
+            MatchPattern::Variant(n) => n.span(),
+            MatchPattern::Wildcard(span) => *span,
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            MatchPattern::Literal(n) => n.set_span(span),
+            MatchPattern::Variant(n) => n.set_span(span),
+            MatchPattern::Wildcard(n) => *n = span,
+        }
+    }
+}
+
+/// A pattern matching a variant of an enum, which binds the values carried by the variant to new variables.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct VariantPattern {
+    /// The name of the enum.
+    pub enum_name: Identifier,
+    /// The name of the variant.
+    pub variant: Identifier,
+    /// The variables bound to the values carried by the variant, in order.
+    pub bindings: Vec<Identifier>,
+    /// The span from the enum name to the end of the pattern.
+    pub span: Span,
+}
+
+impl fmt::Display for VariantPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.enum_name, self.variant)?;
+        if !self.bindings.is_empty() {
+            write!(
+                f,
+                "({})",
+                self.bindings
+                    .iter()
+                    .map(|binding| binding.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+crate::simple_node_impl!(VariantPattern);
@@ -44,6 +44,9 @@ pub use increment::*;
 pub mod iteration;
 pub use iteration::*;
 
+pub mod match_;
+pub use match_::*;
+
 pub mod return_;
 pub use return_::*;
 
@@ -77,6 +80,8 @@ pub enum Statement {
     Increment(IncrementStatement),
     /// A `for` statement.
     Iteration(Box<IterationStatement>),
+    /// A `match` statement.
+    Match(MatchStatement),
     /// A return statement `return expr;`.
     Return(ReturnStatement),
 }
@@ -104,6 +109,7 @@ impl fmt::Display for Statement {
             Statement::Expression(x) => x.fmt(f),
             Statement::Increment(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
+            Statement::Match(x) => x.fmt(f),
             Statement::Return(x) => x.fmt(f),
         }
     }
@@ -123,6 +129,7 @@ impl Node for Statement {
             Expression(n) => n.span(),
             Increment(n) => n.span(),
             Iteration(n) => n.span(),
+            Match(n) => n.span(),
             Return(n) => n.span(),
         }
     }
@@ -140,6 +147,7 @@ impl Node for Statement {
             Expression(n) => n.set_span(span),
             Increment(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             Return(n) => n.set_span(span),
         }
     }
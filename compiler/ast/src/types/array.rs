@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{PositiveNumber, Type};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An array type of a fixed length, e.g., `[u8; 32]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ArrayType {
+    /// The type of the elements in the array.
+    pub element_type: Box<Type>,
+    /// The number of elements in the array.
+    pub length: PositiveNumber,
+}
+
+impl ArrayType {
+    /// Returns the number of elements in the array.
+    pub fn length(&self) -> usize {
+        self.length.to_usize()
+    }
+}
+
+impl fmt::Display for ArrayType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type, self.length)
+    }
+}
@@ -42,6 +42,18 @@ impl IntegerType {
         matches!(self, I8 | I16 | I32 | I64 | I128)
     }
 
+    /// Returns the number of bits of the integer type.
+    pub fn size_in_bits(&self) -> u32 {
+        use IntegerType::*;
+        match self {
+            U8 | I8 => 8,
+            U16 | I16 => 16,
+            U32 | I32 => 32,
+            U64 | I64 => 64,
+            U128 | I128 => 128,
+        }
+    }
+
     /// Returns the symbol for the integer type.
     pub fn symbol(self) -> Symbol {
         match self {
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Identifier, IntegerType, MappingType, Tuple};
+use crate::{ArrayType, Identifier, IntegerType, MappingType, Tuple};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -26,6 +26,8 @@ pub enum Type {
     // Data types
     /// The `address` type.
     Address,
+    /// A fixed-size array type, e.g., `[u8; 32]`.
+    Array(ArrayType),
     /// The `bool` type.
     Boolean,
     /// The `field` type.
@@ -55,7 +57,7 @@ impl Type {
     ///
     /// Returns `true` if the self `Type` is equal to the other `Type`.
     ///
-    /// Flattens array syntax: `[[u8; 1]; 2] == [u8; (2, 1)] == true`
+    /// Arrays are equal if they have the same length and their element types are equal.
     ///
     pub fn eq_flat(&self, other: &Self) -> bool {
         match (self, other) {
@@ -66,6 +68,9 @@ impl Type {
             | (Type::Scalar, Type::Scalar)
             | (Type::String, Type::String)
             | (Type::Unit, Type::Unit) => true,
+            (Type::Array(left), Type::Array(right)) => {
+                left.length() == right.length() && left.element_type.eq_flat(&right.element_type)
+            }
             (Type::Integer(left), Type::Integer(right)) => left.eq(right),
             (Type::Mapping(left), Type::Mapping(right)) => {
                 left.key.eq_flat(&right.key) && left.value.eq_flat(&right.value)
@@ -84,6 +89,7 @@ impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Type::Address => write!(f, "address"),
+            Type::Array(ref array_type) => write!(f, "{array_type}"),
             Type::Boolean => write!(f, "boolean"),
             Type::Field => write!(f, "field"),
             Type::Group => write!(f, "group"),
@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, Identifier, IntegerType, Literal, Type};
+use crate::{
+    ArrayType, BinaryOperation, GroupLiteral, Identifier, IntegerType, Literal, PositiveNumber, Type, UnaryOperation,
+};
 
 use leo_errors::{type_name, FlattenError, LeoError, Result};
 use leo_span::{Span, Symbol};
@@ -198,6 +200,8 @@ pub enum Value {
     U128(u128, Span),
     Scalar(String, Span),
     String(String, Span),
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
 }
 
 impl Value {
@@ -486,6 +490,20 @@ impl Value {
         ]
     );
 
+    implement_const_binary!(
+        @overflowing
+        name: modulo,
+        method: checked_rem,
+        string: "%",
+        patterns: [
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
     implement_const_binary!(
         @overflowing
         name: mul,
@@ -585,6 +603,43 @@ impl Value {
         ]
     );
 
+    implement_const_binary!(
+        @overflowing
+        name: rem,
+        method: checked_rem,
+        string: "%",
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
+    implement_const_binary!(
+        @non-overflowing
+        name: rem_wrapped,
+        method: wrapping_rem,
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
     implement_const_binary!(
         @overflowing
         name: shl,
@@ -722,6 +777,179 @@ impl Value {
     );
 }
 
+impl Value {
+    /// Returns `true` if the value is an integer equal to zero.
+    pub fn is_zero(&self) -> bool {
+        use Value::*;
+        matches!(
+            self,
+            I8(0, _)
+                | I16(0, _)
+                | I32(0, _)
+                | I64(0, _)
+                | I128(0, _)
+                | U8(0, _)
+                | U16(0, _)
+                | U32(0, _)
+                | U64(0, _)
+                | U128(0, _)
+        )
+    }
+
+    /// Casts the value to the given type, if the cast can be evaluated at compile time.
+    /// Returns `Ok(None)` if the cast cannot be evaluated, for example, when casting to or from a group element.
+    /// Returns an error if the value cannot be represented in the given type.
+    pub fn cast(self, cast_type: &Type, span: Span) -> Result<Option<Self>> {
+        use Value::*;
+
+        // Casting a value to its own type is the identity.
+        if &Type::from(&self) == cast_type {
+            return Ok(Some(self));
+        }
+
+        // The decimal representation of the value.
+        let string = match &self {
+            Boolean(value, _) => (*value as u8).to_string(),
+            Field(value, _) | Scalar(value, _) => value.clone(),
+            I8(..) | I16(..) | I32(..) | I64(..) | I128(..) | U8(..) | U16(..) | U32(..) | U64(..) | U128(..) => {
+                self.to_string()
+            }
+            _ => return Ok(None),
+        };
+
+        let out_of_range = || FlattenError::cast_out_of_range(&self, cast_type, span);
+
+        Ok(Some(match cast_type {
+            Type::Boolean => match string.as_str() {
+                "0" => Boolean(false, span),
+                "1" => Boolean(true, span),
+                _ => return Err(out_of_range().into()),
+            },
+            // Negative values are reduced modulo the field or scalar modulus, so they are left to be cast at runtime.
+            Type::Field if !string.starts_with('-') => Field(string, span),
+            // Only values that are known to be less than the scalar modulus are cast to scalars.
+            Type::Scalar if string.parse::<u128>().is_ok() => Scalar(string, span),
+            Type::Integer(integer_type) => {
+                macro_rules! parse {
+                    ($variant:ident) => {
+                        $variant(string.parse().map_err(|_| out_of_range())?, span)
+                    };
+                }
+                match integer_type {
+                    IntegerType::I8 => parse!(I8),
+                    IntegerType::I16 => parse!(I16),
+                    IntegerType::I32 => parse!(I32),
+                    IntegerType::I64 => parse!(I64),
+                    IntegerType::I128 => parse!(I128),
+                    IntegerType::U8 => parse!(U8),
+                    IntegerType::U16 => parse!(U16),
+                    IntegerType::U32 => parse!(U32),
+                    IntegerType::U64 => parse!(U64),
+                    IntegerType::U128 => parse!(U128),
+                }
+            }
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Casts the value to the given type, keeping the lowest bits of its two's complement representation, e.g. `300u16 as u8` is `44u8`.
+    /// Returns `Ok(None)` if the cast cannot be evaluated, i.e. unless it is a cast between integer types.
+    pub fn cast_lossy(self, cast_type: &Type, span: Span) -> Result<Option<Self>> {
+        use Value::*;
+
+        // The bits of the value, where signed values are sign extended.
+        let bits = match self {
+            I8(value, _) => value as u128,
+            I16(value, _) => value as u128,
+            I32(value, _) => value as u128,
+            I64(value, _) => value as u128,
+            I128(value, _) => value as u128,
+            U8(value, _) => value as u128,
+            U16(value, _) => value as u128,
+            U32(value, _) => value as u128,
+            U64(value, _) => value as u128,
+            U128(value, _) => value,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(match cast_type {
+            Type::Integer(IntegerType::I8) => I8(bits as i8, span),
+            Type::Integer(IntegerType::I16) => I16(bits as i16, span),
+            Type::Integer(IntegerType::I32) => I32(bits as i32, span),
+            Type::Integer(IntegerType::I64) => I64(bits as i64, span),
+            Type::Integer(IntegerType::I128) => I128(bits as i128, span),
+            Type::Integer(IntegerType::U8) => U8(bits as u8, span),
+            Type::Integer(IntegerType::U16) => U16(bits as u16, span),
+            Type::Integer(IntegerType::U32) => U32(bits as u32, span),
+            Type::Integer(IntegerType::U64) => U64(bits as u64, span),
+            Type::Integer(IntegerType::U128) => U128(bits, span),
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Applies the unary operation to the value, if the operation can be evaluated at compile time.
+    /// Returns `Ok(None)` if the operation cannot be evaluated, for example, on field elements.
+    /// Returns an error if the operation overflows.
+    pub fn unary(self, op: UnaryOperation, span: Span) -> Result<Option<Self>> {
+        if !self.is_supported_const_fold_type() {
+            return Ok(None);
+        }
+
+        Ok(Some(match op {
+            UnaryOperation::Abs => self.abs(span)?,
+            UnaryOperation::AbsWrapped => self.abs_wrapped(span)?,
+            UnaryOperation::Negate => self.neg(span)?,
+            UnaryOperation::Not => self.not(span)?,
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Applies the binary operation to the values, if the operation can be evaluated at compile time.
+    /// Returns `Ok(None)` if the operation cannot be evaluated, for example, on field elements.
+    /// Returns an error if the operation overflows.
+    pub fn binary(self, op: BinaryOperation, other: Self, span: Span) -> Result<Option<Self>> {
+        if !self.is_supported_const_fold_type() || !other.is_supported_const_fold_type() {
+            return Ok(None);
+        }
+
+        // Note that wrapping division by zero is left to be evaluated at runtime, where it halts.
+        if matches!(op, BinaryOperation::DivWrapped | BinaryOperation::RemWrapped) && other.is_zero() {
+            return Ok(None);
+        }
+
+        Ok(Some(match op {
+            BinaryOperation::Add => self.add(other, span)?,
+            BinaryOperation::AddWrapped => self.add_wrapped(other, span)?,
+            BinaryOperation::And | BinaryOperation::BitwiseAnd => self.bitand(other, span)?,
+            BinaryOperation::Div => self.div(other, span)?,
+            BinaryOperation::DivWrapped => self.div_wrapped(other, span)?,
+            BinaryOperation::Eq => self.eq(other, span)?,
+            BinaryOperation::Gte => self.ge(other, span)?,
+            BinaryOperation::Gt => self.gt(other, span)?,
+            BinaryOperation::Lte => self.le(other, span)?,
+            BinaryOperation::Lt => self.lt(other, span)?,
+            BinaryOperation::Mul => self.mul(other, span)?,
+            BinaryOperation::MulWrapped => self.mul_wrapped(other, span)?,
+            BinaryOperation::Nand => self.bitand(other, span)?.not(span)?,
+            BinaryOperation::Neq => self.eq(other, span)?.not(span)?,
+            BinaryOperation::Nor => self.bitor(other, span)?.not(span)?,
+            BinaryOperation::Or | BinaryOperation::BitwiseOr => self.bitor(other, span)?,
+            BinaryOperation::Pow => self.pow(other, span)?,
+            BinaryOperation::PowWrapped => self.pow_wrapped(other, span)?,
+            BinaryOperation::Shl => self.shl(other, span)?,
+            BinaryOperation::ShlWrapped => self.shl_wrapped(other, span)?,
+            BinaryOperation::Shr => self.shr(other, span)?,
+            BinaryOperation::ShrWrapped => self.shr_wrapped(other, span)?,
+            BinaryOperation::Sub => self.sub(other, span)?,
+            BinaryOperation::SubWrapped => self.sub_wrapped(other, span)?,
+            BinaryOperation::Xor => self.xor(other, span)?,
+            BinaryOperation::Mod => self.modulo(other, span)?,
+            BinaryOperation::Rem => self.rem(other, span)?,
+            BinaryOperation::RemWrapped => self.rem_wrapped(other, span)?,
+        }))
+    }
+}
+
 impl Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use Value::*;
@@ -744,6 +972,16 @@ impl Display for Value {
             U128(val, _) => write!(f, "{val}"),
             Scalar(val, _) => write!(f, "{val}"),
             String(val, _) => write!(f, "{val}"),
+            Array(elements) => write!(
+                f,
+                "[{}]",
+                elements.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(", ")
+            ),
+            Tuple(elements) => write!(
+                f,
+                "({})",
+                elements.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(", ")
+            ),
         }
     }
 }
@@ -860,6 +1098,13 @@ impl From<&Value> for Type {
             U128(_, _) => Type::Integer(IntegerType::U128),
             Scalar(_, _) => Type::Scalar,
             String(_, _) => Type::String,
+            Array(elements) => Type::Array(ArrayType {
+                element_type: Box::new(elements.first().map_or(Type::Err, Type::from)),
+                length: PositiveNumber {
+                    value: elements.len().to_string(),
+                },
+            }),
+            Tuple(elements) => Type::Tuple(crate::Tuple(elements.iter().map(Type::from).collect())),
         }
     }
 }
@@ -892,14 +1137,16 @@ impl From<&Literal> for Value {
     }
 }
 
-impl From<Value> for Literal {
-    fn from(v: Value) -> Self {
+/// Converts a value of a primitive type to a literal.
+/// Returns the value back if it cannot be written as a literal, i.e. if it is an input, a struct, an array, or a tuple.
+impl TryFrom<Value> for Literal {
+    type Error = Value;
+
+    fn try_from(v: Value) -> Result<Self, Self::Error> {
         use Value::*;
-        match v {
-            Input(_, _) => todo!("We need to test if this is hittable"),
+        Ok(match v {
             Address(v, span) => Literal::Address(v, span),
             Boolean(v, span) => Literal::Boolean(v, span),
-            Struct(_ident, _values) => todo!("We need to test if this is hittable"),
             Field(v, span) => Literal::Field(v, span),
             Group(v) => Literal::Group(v),
             I8(v, span) => Literal::Integer(IntegerType::I8, v.to_string(), span),
@@ -914,6 +1161,31 @@ impl From<Value> for Literal {
             U128(v, span) => Literal::Integer(IntegerType::U128, v.to_string(), span),
             Scalar(v, span) => Literal::Scalar(v, span),
             String(v, span) => Literal::String(v, span),
-        }
+            v @ (Input(..) | Struct(..) | Array(_) | Tuple(_)) => return Err(v),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_literal_from_value() {
+        let span = Span::default();
+        assert_eq!(
+            Literal::try_from(Value::I8(-1, span)),
+            Ok(Literal::Integer(IntegerType::I8, "-1".to_string(), span))
+        );
+        assert_eq!(
+            Literal::try_from(Value::Boolean(true, span)),
+            Ok(Literal::Boolean(true, span))
+        );
+
+        // Values of composite types cannot be written as literals, so they are returned back.
+        let array = Value::Array(vec![Value::U8(1, span), Value::U8(2, span)]);
+        assert_eq!(Literal::try_from(array.clone()), Err(array));
+        let tuple = Value::Tuple(vec![Value::Boolean(false, span), Value::U32(3, span)]);
+        assert_eq!(Literal::try_from(tuple.clone()), Err(tuple));
     }
 }
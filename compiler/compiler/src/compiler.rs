@@ -17,20 +17,24 @@
 //! The compiler for Leo programs.
 //!
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
-use leo_ast::Program;
 pub use leo_ast::{Ast, InputAst};
+use leo_ast::{Node, Program};
 use leo_errors::emitter::Handler;
 use leo_errors::{CompilerError, Result};
 pub use leo_passes::SymbolTable;
 use leo_passes::*;
 use leo_span::source_map::FileName;
 use leo_span::symbol::with_session_globals;
+use leo_span::Symbol;
 
+use indexmap::IndexMap;
 use sha2::{Digest, Sha256};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use crate::OutputOptions;
+use crate::validation::{check_aleo_program, split_aleo_program};
+use crate::{disassemble, AstFormat, OutputOptions};
 
 /// The primary entry point of the Leo compiler.
 #[derive(Clone)]
@@ -41,6 +45,10 @@ pub struct Compiler<'a> {
     main_file_path: PathBuf,
     /// The path to where the compiler outputs all generated files.
     output_directory: PathBuf,
+    /// The directory containing the imported programs, `imports/` in the current working directory by default.
+    pub import_directory: PathBuf,
+    /// The directory containing the modules declared by the program, the directory of the main file by default.
+    pub module_directory: PathBuf,
     /// The program name,
     pub program_name: String,
     /// The network name,
@@ -51,9 +59,18 @@ pub struct Compiler<'a> {
     pub input_ast: Option<InputAst>,
     /// Compiler options on some optional output files.
     output_options: OutputOptions,
+    /// The pass manager, which runs the compiler passes.
+    pass_manager: PassManager,
 }
 
 impl<'a> Compiler<'a> {
+    /// The name of the parsing stage, which is not a pass.
+    pub const PARSE: &'static str = "parse";
+
+    /// The names of the compiler stages, in the order in which they run, i.e. parsing followed by the passes in `PASSES`.
+    /// The AST can be written after each stage, and compilation can stop after each stage.
+    pub const STAGES: [&'static str; PASSES.len() + 1] = stage_names();
+
     /// Returns a new Leo compiler.
     pub fn new(
         program_name: String,
@@ -63,15 +80,21 @@ impl<'a> Compiler<'a> {
         output_directory: PathBuf,
         output_options: Option<OutputOptions>,
     ) -> Self {
+        let output_options = output_options.unwrap_or_default();
+        let pass_manager = PassManager::new(output_options.stop_after.clone(), output_options.time_passes);
+        let module_directory = main_file_path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
         Self {
             handler,
             main_file_path,
             output_directory,
+            import_directory: PathBuf::from("imports"),
+            module_directory,
             program_name,
             network,
             ast: Ast::new(Program::default()),
             input_ast: None,
-            output_options: output_options.unwrap_or_default(),
+            output_options,
+            pass_manager,
         }
     }
 
@@ -111,11 +134,7 @@ impl<'a> Compiler<'a> {
             .into());
         }
 
-        if self.output_options.initial_ast {
-            self.write_ast_to_json("initial_ast.json")?;
-        }
-
-        Ok(())
+        self.write_snapshot(Self::PARSE)
     }
 
     /// Parses and stores the main program file, constructs a syntax tree, and generates a program.
@@ -127,6 +146,33 @@ impl<'a> Compiler<'a> {
         self.parse_program_from_string(&program_string, FileName::Real(self.main_file_path.clone()))
     }
 
+    /// Parses a test file and adds its declarations to the program, so that its `@test` functions can be run.
+    /// The test file must declare a program scope with the same name as the program.
+    pub fn parse_test_file(&mut self, test_file_path: &Path) -> Result<()> {
+        // Load the test file into the source map.
+        let test_sf = with_session_globals(|s| s.source_map.load_file(test_file_path))
+            .map_err(|e| CompilerError::file_read_error(test_file_path, e))?;
+
+        // Note that parsing enforces that there is exactly one program scope in a file.
+        let test_ast = leo_parser::parse_ast(self.handler, &test_sf.src, test_sf.start_pos)?;
+        let test_scope = test_ast.ast.program_scopes.into_values().next().unwrap();
+        let program_scope = self.ast.ast.program_scopes.values_mut().next().unwrap();
+        if test_scope.program_id.name.name != program_scope.program_id.name.name {
+            return Err(CompilerError::program_scope_name_does_not_match(
+                test_scope.program_id.name,
+                program_scope.program_id.name,
+                test_scope.program_id.name.span,
+            )
+            .into());
+        }
+
+        // Add the declarations of the test file to the program.
+        merge_declarations(&mut program_scope.consts, test_scope.consts)?;
+        merge_declarations(&mut program_scope.structs, test_scope.structs)?;
+        merge_declarations(&mut program_scope.mappings, test_scope.mappings)?;
+        merge_declarations(&mut program_scope.functions, test_scope.functions)
+    }
+
     /// Parses and stores the input file, constructs a syntax tree, and generates a program input.
     pub fn parse_input(&mut self, input_file_path: PathBuf) -> Result<()> {
         if input_file_path.exists() {
@@ -136,7 +182,7 @@ impl<'a> Compiler<'a> {
 
             // Parse and serialize it.
             let input_ast = leo_parser::parse_input(self.handler, &input_sf.src, input_sf.start_pos)?;
-            if self.output_options.initial_ast {
+            if self.output_options.initial_input_ast {
                 // Write the input AST snapshot post parsing.
                 if self.output_options.spans_enabled {
                     input_ast.to_json_file(
@@ -157,97 +203,180 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
-    /// Runs the symbol table pass.
-    pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
-        SymbolTableCreator::do_pass((&self.ast, self.handler))
-    }
-
-    /// Runs the type checker pass.
-    pub fn type_checker_pass(&'a self, symbol_table: SymbolTable) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        TypeChecker::do_pass((&self.ast, self.handler, symbol_table))
+    /// Runs the code generation pass.
+    pub fn code_generation_pass(
+        &self,
+        symbol_table: &SymbolTable,
+        struct_graph: &StructGraph,
+        call_graph: &CallGraph,
+    ) -> Result<AleoProgram> {
+        self.pass_manager
+            .run::<CodeGenerator>((&self.ast, symbol_table, struct_graph, call_graph))
     }
 
-    /// Runs the loop unrolling pass.
-    pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
-        let (ast, symbol_table) = Unroller::do_pass((std::mem::take(&mut self.ast), self.handler, symbol_table))?;
-        self.ast = ast;
+    /// Checks the generated Aleo instructions with snarkVM.
+    /// If they are rejected because the program uses a feature that snarkVM does not support yet, the error points at its first use.
+    /// Otherwise, the error points at the Leo function whose instructions were rejected,
+    /// or at the Leo code that produced the rejected instruction if snarkVM names it.
+    /// Note that imported programs are compiled separately, so the instructions of a program with imports are only parsed.
+    pub fn validate_bytecode(
+        &self,
+        bytecode: &str,
+        source_map: &AleoSourceMap,
+        unsupported: &[UnsupportedFeature],
+    ) -> Result<()> {
+        let check_instructions = self.ast.ast.imports.is_empty() && self.ast.ast.stubs.is_empty();
+        let error = match check_aleo_program(bytecode, check_instructions)? {
+            Some(error) => error,
+            None => return Ok(()),
+        };
+
+        if let Some(unsupported) = unsupported.first() {
+            return Err(CompilerError::unsupported_by_snarkvm(unsupported.feature, unsupported.span).into());
+        }
 
-        if self.output_options.unrolled_ast {
-            self.write_ast_to_json("unrolled_ast.json")?;
+        // Find the first closure or function that is rejected, by checking the program one closure or function at a time.
+        // Note that callees precede their callers, so each prefix of the program is a program on its own.
+        let (mut prefix, components) = split_aleo_program(bytecode);
+        for (name, component) in components {
+            prefix.push_str(&component);
+            if let Some(error) = check_aleo_program(&prefix, check_instructions)? {
+                let instruction = source_map.instructions.iter().find(|location| {
+                    location.function == name
+                        && (error.contains(&location.instruction)
+                            || error.contains(&format!("'{}'", location.instruction.trim_end_matches(';'))))
+                });
+                let function = self
+                    .ast
+                    .ast
+                    .program_scopes
+                    .values()
+                    .find_map(|scope| scope.functions.get(&Symbol::intern(name)));
+                let span = match (instruction, function) {
+                    (Some(instruction), _) => instruction.span,
+                    (None, Some(function)) => function.identifier.span,
+                    (None, None) => return Err(CompilerError::invalid_aleo_program(&self.program_name, error).into()),
+                };
+                return Err(CompilerError::invalid_aleo_instructions(name, error, span).into());
+            }
         }
 
-        Ok(symbol_table)
+        Err(CompilerError::invalid_aleo_program(&self.program_name, error).into())
     }
 
-    /// Runs the static single assignment pass.
-    pub fn static_single_assignment_pass(&mut self, symbol_table: &SymbolTable) -> Result<Assigner> {
-        let (ast, assigner) = StaticSingleAssigner::do_pass((std::mem::take(&mut self.ast), symbol_table))?;
-        self.ast = ast;
-
-        if self.output_options.ssa_ast {
-            self.write_ast_to_json("ssa_ast.json")?;
-        }
-
-        Ok(assigner)
+    /// Runs the front-end passes after parsing, which check the program and lower it to the subset of Leo
+    /// that the interpreter and the remaining passes accept, i.e. without modules, enums, loops, or matches.
+    /// Returns `None` if compilation stops early, as requested by `OutputOptions::stop_after`.
+    pub fn front_end_passes(&mut self) -> Result<Option<(SymbolTable, StructGraph, CallGraph)>> {
+        let front_end = PASSES
+            .iter()
+            .position(|stage| stage.name == MatchLowerer::NAME)
+            .expect("the match lowering pass is registered");
+        self.run_passes(&PASSES[..=front_end])
     }
 
-    /// Runs the flattening pass.
-    pub fn flattening_pass(&mut self, symbol_table: &SymbolTable, assigner: Assigner) -> Result<Assigner> {
-        let (ast, assigner) = Flattener::do_pass((std::mem::take(&mut self.ast), symbol_table, assigner))?;
-        self.ast = ast;
+    /// Runs the compiler stages after parsing.
+    /// Returns `None` if compilation stops early, as requested by `OutputOptions::stop_after`.
+    pub fn compiler_stages(&mut self) -> Result<Option<(SymbolTable, StructGraph, CallGraph)>> {
+        self.run_passes(PASSES)
+    }
 
-        if self.output_options.flattened_ast {
-            self.write_ast_to_json("flattened_ast.json")?;
+    /// Runs the given passes in order, writing the AST after each pass if it was requested.
+    /// Returns `None` if compilation stops early, as requested by `OutputOptions::stop_after`.
+    fn run_passes(&mut self, passes: &[Stage]) -> Result<Option<(SymbolTable, StructGraph, CallGraph)>> {
+        let mut outputs = StageOutputs::default();
+        for stage in passes {
+            (stage.run)(self, &mut outputs)?;
+            self.write_snapshot(stage.name)?;
+            if self.stops_after(stage.name)? {
+                return Ok(None);
+            }
         }
 
-        Ok(assigner)
+        Ok(Some((
+            outputs.symbol_table.expect("the symbol table pass has run"),
+            outputs.struct_graph.expect("the type checking pass has run"),
+            outputs.call_graph.expect("the type checking pass has run"),
+        )))
     }
 
-    /// Runs the function inlining pass.
-    pub fn function_inlining_pass(&mut self, call_graph: &CallGraph, assigner: Assigner) -> Result<Assigner> {
-        let (ast, assigner) = FunctionInliner::do_pass((std::mem::take(&mut self.ast), call_graph, assigner))?;
-        self.ast = ast;
-
-        if self.output_options.inlined_ast {
-            self.write_ast_to_json("inlined_ast.json")?;
+    /// Returns a compiled Leo program, the resulting bytecode, its source map and its cost.
+    /// Returns `None` if compilation stops early, as requested by `OutputOptions::stop_after`.
+    // TODO: Remove when code generation is ready to be integrated into the compiler.
+    pub fn compile_and_generate_instructions(
+        &mut self,
+    ) -> Result<Option<(SymbolTable, String, AleoSourceMap, ProgramStats)>> {
+        self.check_stages()?;
+        self.parse_program()?;
+        if self.stops_after(Self::PARSE)? {
+            return Ok(None);
         }
 
-        Ok(assigner)
+        match self.compiler_stages()? {
+            Some((symbol_table, struct_graph, call_graph)) => {
+                let program = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+                let (bytecode, source_map) = program.print();
+                self.validate_bytecode(&bytecode, &source_map, &program.unsupported)?;
+                Ok(Some((symbol_table, bytecode, source_map, program.stats())))
+            }
+            None => Ok(None),
+        }
     }
 
-    /// Runs the compiler stages.
-    pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let st = self.symbol_table_pass()?;
-        let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
-
-        // TODO: Make this pass optional.
-        let st = self.loop_unrolling_pass(st)?;
-
-        // TODO: Make this pass optional.
-        let assigner = self.static_single_assignment_pass(&st)?;
-
-        let assigner = self.flattening_pass(&st, assigner)?;
-
-        let _ = self.function_inlining_pass(&call_graph, assigner)?;
+    /// Returns a compiled Leo program.
+    /// Returns `None` if compilation stops early, as requested by `OutputOptions::stop_after`.
+    pub fn compile(&mut self) -> Result<Option<SymbolTable>> {
+        self.check_stages()?;
+        self.parse_program()?;
+        if self.stops_after(Self::PARSE)? {
+            return Ok(None);
+        }
 
-        Ok((st, struct_graph, call_graph))
+        Ok(self.compiler_stages()?.map(|(st, _, _)| st))
     }
 
-    /// Returns a compiled Leo program and prints the resulting bytecode.
-    // TODO: Remove when code generation is ready to be integrated into the compiler.
-    pub fn compile_and_generate_instructions(&mut self) -> Result<(SymbolTable, String)> {
-        self.parse_program()?;
-        let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
+    /// Returns the name and wall time of each pass that was run, if `OutputOptions::time_passes` is enabled.
+    pub fn pass_timings(&self) -> Vec<(&'static str, Duration)> {
+        self.pass_manager.timings()
+    }
 
-        let bytecode = CodeGenerator::do_pass((&self.ast, &symbol_table, &struct_graph, &call_graph))?;
+    /// Returns whether compilation stops after the given stage, as requested by `OutputOptions::stop_after`.
+    /// The parser recovers from syntax errors and the early passes only fail on their own errors,
+    /// so compilation that stops early fails if any error was emitted so far.
+    fn stops_after(&self, stage: &str) -> Result<bool> {
+        if !self.pass_manager.stops_after(stage) {
+            return Ok(false);
+        }
+        self.handler.last_err().map_err(|e| *e)?;
+        Ok(true)
+    }
 
-        Ok((symbol_table, bytecode))
+    /// Checks that the stages named in the output options exist.
+    fn check_stages(&self) -> Result<()> {
+        let mut stages = self
+            .output_options
+            .emit
+            .iter()
+            .chain(self.output_options.stop_after.iter());
+        match stages.find(|stage| !Self::STAGES.contains(&stage.as_str())) {
+            Some(stage) => Err(CompilerError::unknown_compiler_stage(stage, Self::STAGES.join(", ")).into()),
+            None => Ok(()),
+        }
     }
 
-    /// Returns a compiled Leo program.
-    pub fn compile(&mut self) -> Result<SymbolTable> {
-        self.parse_program()?;
-        self.compiler_stages().map(|(st, _, _)| st)
+    /// Writes the AST to a file, if it was requested for the given stage.
+    fn write_snapshot(&self, stage: &str) -> Result<()> {
+        if !self.output_options.emit.iter().any(|name| name == stage) {
+            return Ok(());
+        }
+
+        match self.output_options.ast_format {
+            AstFormat::Json => self.write_ast_to_json(&format!("{stage}.json")),
+            AstFormat::Leo => self.ast.to_leo_file(
+                self.output_directory.clone(),
+                &format!("{}.{stage}.leo", self.program_name),
+            ),
+        }
     }
 
     /// Writes the AST to a JSON file.
@@ -268,3 +397,218 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 }
+
+/// The outputs of the compiler passes that later passes take as inputs.
+#[derive(Default)]
+struct StageOutputs {
+    symbol_table: Option<SymbolTable>,
+    struct_graph: Option<StructGraph>,
+    call_graph: Option<CallGraph>,
+    assigner: Option<Assigner>,
+}
+
+impl StageOutputs {
+    fn symbol_table(&self) -> &SymbolTable {
+        self.symbol_table.as_ref().expect("the symbol table pass runs first")
+    }
+
+    fn take_symbol_table(&mut self) -> SymbolTable {
+        self.symbol_table.take().expect("the symbol table pass runs first")
+    }
+
+    fn call_graph(&self) -> &CallGraph {
+        self.call_graph.as_ref().expect("the type checking pass runs first")
+    }
+
+    fn take_assigner(&mut self) -> Assigner {
+        self.assigner
+            .take()
+            .expect("the static single assignment pass runs first")
+    }
+}
+
+/// A compiler stage after parsing, which runs a pass on the AST and the outputs of the preceding passes.
+struct Stage {
+    /// The name of the stage, which is the name of its pass.
+    name: &'static str,
+    /// Runs the pass, taking its inputs from and storing its outputs in the `StageOutputs`.
+    run: for<'a> fn(&mut Compiler<'a>, &mut StageOutputs) -> Result<()>,
+}
+
+/// The compiler passes after parsing, in the order in which they run.
+/// Note that the front end ends with the match lowering pass, see `Compiler::front_end_passes`.
+const PASSES: &[Stage] = &[
+    Stage {
+        name: ModuleResolver::NAME,
+        run: |compiler, _| {
+            let ast = std::mem::take(&mut compiler.ast);
+            compiler.ast =
+                compiler
+                    .pass_manager
+                    .run::<ModuleResolver>((ast, compiler.handler, &compiler.module_directory))?;
+            Ok(())
+        },
+    },
+    Stage {
+        name: ImportResolver::NAME,
+        run: |compiler, _| {
+            let ast = std::mem::take(&mut compiler.ast);
+            compiler.ast = compiler.pass_manager.run::<ImportResolver>((
+                ast,
+                compiler.handler,
+                &compiler.import_directory,
+                disassemble,
+            ))?;
+            Ok(())
+        },
+    },
+    Stage {
+        name: SymbolTableCreator::NAME,
+        run: |compiler, outputs| {
+            outputs.symbol_table = Some(
+                compiler
+                    .pass_manager
+                    .run::<SymbolTableCreator>((&compiler.ast, compiler.handler))?,
+            );
+            Ok(())
+        },
+    },
+    Stage {
+        name: TypeChecker::NAME,
+        run: |compiler, outputs| {
+            let symbol_table = outputs.take_symbol_table();
+            let (symbol_table, struct_graph, call_graph) =
+                compiler
+                    .pass_manager
+                    .run::<TypeChecker>((&compiler.ast, compiler.handler, symbol_table))?;
+            // The parser recovers from syntax errors so that semantic errors are reported too,
+            // but the remaining passes require a program without errors.
+            compiler.handler.last_err().map_err(|e| *e)?;
+            outputs.symbol_table = Some(symbol_table);
+            outputs.struct_graph = Some(struct_graph);
+            outputs.call_graph = Some(call_graph);
+            Ok(())
+        },
+    },
+    Stage {
+        name: EnumLowerer::NAME,
+        run: |compiler, outputs| {
+            let input = (std::mem::take(&mut compiler.ast), outputs.take_symbol_table());
+            let (ast, symbol_table) = compiler.pass_manager.run::<EnumLowerer>(input)?;
+            compiler.ast = ast;
+            outputs.symbol_table = Some(symbol_table);
+            Ok(())
+        },
+    },
+    // TODO: Make this pass optional.
+    Stage {
+        name: Unroller::NAME,
+        run: |compiler, outputs| {
+            let input = (
+                std::mem::take(&mut compiler.ast),
+                compiler.handler,
+                outputs.take_symbol_table(),
+            );
+            let (ast, symbol_table) = compiler.pass_manager.run::<Unroller>(input)?;
+            compiler.ast = ast;
+            outputs.symbol_table = Some(symbol_table);
+            Ok(())
+        },
+    },
+    Stage {
+        name: MatchLowerer::NAME,
+        run: |compiler, outputs| {
+            let input = (std::mem::take(&mut compiler.ast), outputs.symbol_table());
+            compiler.ast = compiler.pass_manager.run::<MatchLowerer>(input)?;
+            Ok(())
+        },
+    },
+    // TODO: Make this pass optional.
+    Stage {
+        name: StaticSingleAssigner::NAME,
+        run: |compiler, outputs| {
+            let input = (std::mem::take(&mut compiler.ast), outputs.symbol_table());
+            let (ast, assigner) = compiler.pass_manager.run::<StaticSingleAssigner>(input)?;
+            compiler.ast = ast;
+            outputs.assigner = Some(assigner);
+            Ok(())
+        },
+    },
+    Stage {
+        name: ConstantFolder::NAME,
+        run: |compiler, _| {
+            let input = (std::mem::take(&mut compiler.ast), compiler.handler);
+            compiler.ast = compiler.pass_manager.run::<ConstantFolder>(input)?;
+            Ok(())
+        },
+    },
+    Stage {
+        name: Flattener::NAME,
+        run: |compiler, outputs| {
+            let assigner = outputs.take_assigner();
+            let input = (std::mem::take(&mut compiler.ast), outputs.symbol_table(), assigner);
+            let (ast, assigner) = compiler.pass_manager.run::<Flattener>(input)?;
+            compiler.ast = ast;
+            outputs.assigner = Some(assigner);
+            Ok(())
+        },
+    },
+    Stage {
+        name: FunctionInliner::NAME,
+        run: |compiler, outputs| {
+            let assigner = outputs.take_assigner();
+            let input = (std::mem::take(&mut compiler.ast), outputs.call_graph(), assigner);
+            let (ast, assigner) = compiler.pass_manager.run::<FunctionInliner>(input)?;
+            compiler.ast = ast;
+            outputs.assigner = Some(assigner);
+            Ok(())
+        },
+    },
+    Stage {
+        name: CommonSubexpressionEliminator::NAME,
+        run: |compiler, _| {
+            let input = std::mem::take(&mut compiler.ast);
+            compiler.ast = compiler.pass_manager.run::<CommonSubexpressionEliminator>(input)?;
+            Ok(())
+        },
+    },
+    Stage {
+        name: DeadCodeEliminator::NAME,
+        run: |compiler, outputs| {
+            let input = (std::mem::take(&mut compiler.ast), outputs.call_graph());
+            compiler.ast = compiler.pass_manager.run::<DeadCodeEliminator>(input)?;
+            Ok(())
+        },
+    },
+    // Warns when private data becomes public.
+    Stage {
+        name: TaintAnalyzer::NAME,
+        run: |compiler, _| {
+            compiler
+                .pass_manager
+                .run::<TaintAnalyzer>((&compiler.ast, compiler.handler))
+        },
+    },
+];
+
+/// Returns the names of the compiler stages, i.e. parsing followed by the passes in `PASSES`.
+const fn stage_names<const N: usize>() -> [&'static str; N] {
+    let mut names = [Compiler::PARSE; N];
+    let mut i = 1;
+    while i < N {
+        names[i] = PASSES[i - 1].name;
+        i += 1;
+    }
+    names
+}
+
+/// Adds the declarations to those of the program, checking that none of them are already declared.
+fn merge_declarations<T: Node>(program: &mut IndexMap<Symbol, T>, declarations: IndexMap<Symbol, T>) -> Result<()> {
+    for (name, declaration) in declarations {
+        if program.contains_key(&name) {
+            return Err(CompilerError::duplicate_test_declaration(name, declaration.span()).into());
+        }
+        program.insert(name, declaration);
+    }
+    Ok(())
+}
@@ -0,0 +1,285 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Constructs the Leo interface of a deployed program from its Aleo instructions.
+
+use leo_ast::{
+    Block, External, Function, FunctionInput, FunctionOutput, Identifier, Input, IntegerType, Mapping, Member, Mode,
+    Output, Program, ProgramId, ProgramScope, Struct, Type, Variant,
+};
+use leo_errors::{CompilerError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+use snarkvm::prelude::{EntryType, PlaintextType, Program as AleoProgram, Testnet3, ValueType};
+use std::{fmt::Display, str::FromStr};
+
+/// The network of the imported programs.
+type Network = Testnet3;
+
+/// Parses the Aleo instructions of an imported program, and returns the interface of the program, i.e. its stub.
+/// The stub declares the structs, records, mappings, and functions of the program, where each function is a transition with an empty body.
+/// The programs imported by the program are empty, as in a parsed Leo program.
+/// Note that closures and finalize blocks are omitted, since they cannot be called by other programs.
+/// Similarly, functions with finalize blocks are omitted, since Leo programs cannot await the futures they output.
+/// Every node of the stub is located at `span`, the import statement naming the program.
+pub fn disassemble(bytecode: &str, span: Span) -> Result<Program> {
+    let program =
+        AleoProgram::<Network>::from_str(bytecode).map_err(|e| CompilerError::invalid_aleo_import(e, span))?;
+    let name = program.id().to_string();
+    let identifier = |name: &dyn Display| Identifier {
+        name: Symbol::intern(&name.to_string()),
+        span,
+    };
+
+    // Converts a plaintext type of the program into a Leo type.
+    let plaintext_type = |type_: &PlaintextType<Network>| -> Result<Type> {
+        match type_ {
+            PlaintextType::Literal(literal) => match literal.to_string().as_str() {
+                "address" => Ok(Type::Address),
+                "boolean" => Ok(Type::Boolean),
+                "field" => Ok(Type::Field),
+                "group" => Ok(Type::Group),
+                "scalar" => Ok(Type::Scalar),
+                "string" => Ok(Type::String),
+                "i8" => Ok(Type::Integer(IntegerType::I8)),
+                "i16" => Ok(Type::Integer(IntegerType::I16)),
+                "i32" => Ok(Type::Integer(IntegerType::I32)),
+                "i64" => Ok(Type::Integer(IntegerType::I64)),
+                "i128" => Ok(Type::Integer(IntegerType::I128)),
+                "u8" => Ok(Type::Integer(IntegerType::U8)),
+                "u16" => Ok(Type::Integer(IntegerType::U16)),
+                "u32" => Ok(Type::Integer(IntegerType::U32)),
+                "u64" => Ok(Type::Integer(IntegerType::U64)),
+                "u128" => Ok(Type::Integer(IntegerType::U128)),
+                literal => Err(CompilerError::unsupported_aleo_import_type(literal, &name, span).into()),
+            },
+            PlaintextType::Struct(struct_name) => Ok(Type::Identifier(identifier(struct_name))),
+            // Leo arrays are lowered to structs, so they do not correspond to Aleo arrays.
+            PlaintextType::Array(array) => Err(CompilerError::unsupported_aleo_import_type(array, &name, span).into()),
+        }
+    };
+
+    // Converts the type of a function input or output into a Leo input, named `register`.
+    let input = |register: &dyn Display, value_type: &ValueType<Network>| -> Result<Input> {
+        let (mode, type_) = match value_type {
+            ValueType::Constant(type_) => (Mode::Constant, plaintext_type(type_)?),
+            ValueType::Public(type_) => (Mode::Public, plaintext_type(type_)?),
+            ValueType::Private(type_) => (Mode::Private, plaintext_type(type_)?),
+            ValueType::Record(record) => (Mode::None, Type::Identifier(identifier(record))),
+            ValueType::ExternalRecord(locator) => {
+                return Ok(Input::External(External {
+                    identifier: identifier(register),
+                    program_name: identifier(locator.program_id().name()),
+                    record: identifier(locator.resource()),
+                    span,
+                }))
+            }
+            ValueType::Future(locator) => {
+                return Err(
+                    CompilerError::unsupported_aleo_import_type(format!("{locator}.future"), &name, span).into(),
+                )
+            }
+        };
+        Ok(Input::Internal(FunctionInput {
+            identifier: identifier(register),
+            mode,
+            type_,
+            span,
+        }))
+    };
+
+    let mut structs = IndexMap::new();
+    for (struct_name, struct_) in program.structs() {
+        let members = struct_
+            .members()
+            .iter()
+            .map(|(member, type_)| {
+                Ok(Member {
+                    mode: Mode::None,
+                    identifier: identifier(member),
+                    type_: plaintext_type(type_)?,
+                    span,
+                })
+            })
+            .collect::<Result<_>>()?;
+        let struct_ = Struct {
+            identifier: identifier(struct_name),
+            members,
+            is_record: false,
+            span,
+        };
+        structs.insert(struct_.name(), struct_);
+    }
+
+    for (record_name, record) in program.records() {
+        // Note that `gates` is an ordinary entry of Aleo records, so only the owner is declared separately.
+        let mut members = vec![Member {
+            mode: match record.owner().is_public() {
+                true => Mode::Public,
+                false => Mode::Private,
+            },
+            identifier: identifier(&sym::owner),
+            type_: Type::Address,
+            span,
+        }];
+        for (entry, entry_type) in record.entries() {
+            let (mode, type_) = match entry_type {
+                EntryType::Constant(type_) => (Mode::Constant, type_),
+                EntryType::Public(type_) => (Mode::Public, type_),
+                EntryType::Private(type_) => (Mode::Private, type_),
+            };
+            members.push(Member {
+                mode,
+                identifier: identifier(entry),
+                type_: plaintext_type(type_)?,
+                span,
+            });
+        }
+        let record = Struct {
+            identifier: identifier(record_name),
+            members,
+            is_record: true,
+            span,
+        };
+        structs.insert(record.name(), record);
+    }
+
+    let mut mappings = IndexMap::new();
+    for (mapping_name, mapping) in program.mappings() {
+        let mapping = Mapping {
+            identifier: identifier(mapping_name),
+            key_type: plaintext_type(mapping.key().plaintext_type())?,
+            value_type: plaintext_type(mapping.value().plaintext_type())?,
+            span,
+        };
+        mappings.insert(mapping.identifier.name, mapping);
+    }
+
+    let mut functions = IndexMap::new();
+    for (function_name, function) in program
+        .functions()
+        .iter()
+        .filter(|(_, function)| function.finalize_logic().is_none())
+    {
+        let inputs = function
+            .inputs()
+            .iter()
+            .map(|function_input| input(function_input.register(), function_input.value_type()))
+            .collect::<Result<_>>()?;
+        let outputs = function
+            .outputs()
+            .iter()
+            .map(|function_output| {
+                Ok(match input(function_output.operand(), function_output.value_type())? {
+                    Input::Internal(output) => Output::Internal(FunctionOutput {
+                        mode: output.mode,
+                        type_: output.type_,
+                        span,
+                    }),
+                    Input::External(output) => Output::External(output),
+                })
+            })
+            .collect::<Result<_>>()?;
+        let function = Function::new(
+            Vec::new(),
+            Variant::Transition,
+            identifier(function_name),
+            inputs,
+            outputs,
+            Block {
+                statements: Vec::new(),
+                span,
+            },
+            None,
+            span,
+        );
+        functions.insert(function.name(), function);
+    }
+
+    let program_id = ProgramId {
+        name: identifier(program.id().name()),
+        network: identifier(&sym::aleo),
+    };
+    let program_scope = ProgramScope {
+        program_id,
+        consts: IndexMap::new(),
+        structs,
+        enums: IndexMap::new(),
+        mappings,
+        functions,
+        span,
+    };
+
+    // Note that the imports of the program are resolved by the caller.
+    let stubs = program
+        .imports()
+        .keys()
+        .map(|import| (Symbol::intern(&import.name().to_string()), (Program::default(), span)))
+        .collect();
+
+    Ok(Program {
+        imports: IndexMap::new(),
+        stubs,
+        modules: IndexMap::new(),
+        program_scopes: IndexMap::from([(program_id, program_scope)]),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_disassemble_declarations() {
+        create_session_if_not_set_then(|_| {
+            // Neither the comment nor the unusual layout of the declarations affect the stub,
+            // since its structs and records are read from the parsed program.
+            let bytecode = "\
+program shapes.aleo;
+
+// struct fake:
+struct point :
+    x as u8;
+    y as u8;
+
+record   token:
+    owner as address.private;
+    amount as u64.public;
+
+function mint:
+    input r0 as address.private;
+    cast r0 0u64 into r1 as token.record;
+    output r1 as token.record;
+";
+            let program = disassemble(bytecode, Span::default()).unwrap();
+            let program_scope = program.program_scopes.values().next().unwrap();
+
+            let structs: Vec<_> = program_scope
+                .structs
+                .values()
+                .map(|struct_| (struct_.name().to_string(), struct_.is_record, struct_.members.len()))
+                .collect();
+            assert_eq!(
+                structs,
+                vec![("point".to_string(), false, 2), ("token".to_string(), true, 2)]
+            );
+            assert!(program_scope.functions.contains_key(&Symbol::intern("mint")));
+        });
+    }
+}
@@ -22,5 +22,10 @@
 mod compiler;
 pub use compiler::*;
 
+mod disassembler;
+pub use disassembler::*;
+
 mod options;
 pub use options::*;
+
+mod validation;
@@ -16,20 +16,43 @@
 
 // NOTE: If compiler passes are made optional, pass preconditions and invariants may not necessarily hold true.
 
+use std::str::FromStr;
+
+/// The format in which AST snapshots are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AstFormat {
+    /// The AST serialized as JSON.
+    #[default]
+    Json,
+    /// The AST printed as Leo source code.
+    Leo,
+}
+
+impl FromStr for AstFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "leo" => Ok(Self::Leo),
+            _ => Err(format!("unknown AST format `{s}`, expected `json` or `leo`")),
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct OutputOptions {
     /// Whether spans are enabled in the output ASTs.
     pub spans_enabled: bool,
-    /// If enabled writes the AST after parsing.
-    pub initial_ast: bool,
     /// If enabled writes the input AST after parsing.
     pub initial_input_ast: bool,
-    /// If enabled writes the AST after loop unrolling.
-    pub unrolled_ast: bool,
-    /// If enabled writes the AST after static single assignment.
-    pub ssa_ast: bool,
-    /// If enabled writes the AST after flattening.
-    pub flattened_ast: bool,
-    /// If enabled writes the AST after inlining.
-    pub inlined_ast: bool,
+    /// The names of the compiler stages after which the AST is written, e.g. `ssa`.
+    /// See `Compiler::STAGES` for the names of the stages.
+    pub emit: Vec<String>,
+    /// The format in which the ASTs are written.
+    pub ast_format: AstFormat,
+    /// The name of the compiler stage after which compilation stops, if any.
+    pub stop_after: Option<String>,
+    /// If enabled records the wall time of each compiler pass.
+    pub time_passes: bool,
 }
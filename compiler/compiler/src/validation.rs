@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks the Aleo instructions produced by code generation with snarkVM.
+
+use leo_errors::{CompilerError, Result};
+
+use snarkvm::prelude::{Process, Program, Stack, Testnet3};
+use std::str::FromStr;
+
+/// The network for which the generated Aleo instructions are checked.
+type Network = Testnet3;
+
+lazy_static::lazy_static! {
+    /// The snarkVM process against which programs are checked, loaded once and shared by every compilation.
+    /// Note that loading it only reads the bundled verifying keys of `credits.aleo`, and no proving keys.
+    static ref PROCESS: std::result::Result<Process<Network>, String> =
+        Process::<Network>::load().map_err(|error| error.to_string());
+}
+
+/// Parses the given Aleo program and, if `check_instructions` is set, checks its instructions against the shared snarkVM process.
+/// Returns the error reported by snarkVM if the program is rejected.
+pub(crate) fn check_aleo_program(bytecode: &str, check_instructions: bool) -> Result<Option<String>> {
+    let program = match Program::<Network>::from_str(bytecode) {
+        Ok(program) => program,
+        Err(error) => return Ok(Some(error.to_string())),
+    };
+
+    // Note that `credits.aleo` is already part of the process, so it is not checked again, as in `Process::add_program`.
+    if check_instructions && program.id().to_string() != "credits.aleo" {
+        let process = PROCESS
+            .as_ref()
+            .map_err(CompilerError::failed_to_load_snarkvm_process)?;
+        // Note that the program is checked without being added, so the process stays the same for later programs.
+        if let Err(error) = Stack::new(process, &program) {
+            return Ok(Some(error.to_string()));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Splits an Aleo program into its declarations, followed by each of its closures and functions along with their names.
+/// Note that a finalize block belongs to the function that precedes it.
+pub(crate) fn split_aleo_program(bytecode: &str) -> (String, Vec<(&str, String)>) {
+    let mut declarations = String::new();
+    let mut components: Vec<(&str, String)> = Vec::new();
+
+    for line in bytecode.split_inclusive('\n') {
+        let name = line
+            .strip_prefix("closure ")
+            .or_else(|| line.strip_prefix("function "))
+            .map(|name| name.trim().trim_end_matches(':'));
+        match (name, components.last_mut()) {
+            (Some(name), _) => components.push((name, line.to_string())),
+            (None, Some((_, component))) => component.push_str(line),
+            (None, None) => declarations.push_str(line),
+        }
+    }
+
+    (declarations, components)
+}
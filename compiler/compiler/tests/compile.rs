@@ -17,16 +17,16 @@
 mod utilities;
 use utilities::{compile_and_process, parse_program, BufferEmitter};
 
-use leo_errors::{emitter::Handler, LeoError};
+use leo_errors::emitter::Handler;
 use leo_span::symbol::create_session_if_not_set_then;
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
     Test,
 };
 
-use snarkvm::prelude::*;
-
-use crate::utilities::{get_cwd_option, hash_asts, hash_content, setup_build_directory};
+use crate::utilities::{
+    compile_until, get_cwd_option, get_input_file_option, get_stop_after_option, hash_asts, hash_content,
+};
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
 use std::rc::Rc;
@@ -43,7 +43,7 @@ impl Namespace for CompileNamespace {
         let buf = BufferEmitter(Rc::default(), Rc::default());
         let handler = Handler::new(Box::new(buf.clone()));
 
-        create_session_if_not_set_then(|_| run_test(test, &handler).map_err(|()| buf.0.take().to_string()))
+        create_session_if_not_set_then(|_| run_test(test, &handler, &buf).map_err(|()| buf.0.take().to_string()))
     }
 }
 
@@ -52,30 +52,49 @@ struct CompileOutput {
     pub initial_ast: String,
     pub unrolled_ast: String,
     pub ssa_ast: String,
+    pub constant_folded_ast: String,
     pub flattened_ast: String,
     pub inlined_ast: String,
+    pub cse_ast: String,
+    pub dce_ast: String,
     pub bytecode: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub warnings: String,
 }
 
-fn run_test(test: Test, handler: &Handler) -> Result<Value, ()> {
+fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value, ()> {
+    // Compile the program from a file until the given stage, if the test stops early.
+    // Note that the errors are not extended, since compilation must fail on its own if any error was emitted.
+    if let Some(stage) = get_stop_after_option(&test) {
+        let result = compile_until(handler, &test.content, stage.clone());
+        fs::remove_dir_all(Path::new("/tmp/output")).expect("Error failed to clean up output dir.");
+        return match result {
+            Ok(()) => Ok(Value::String(format!("stopped after {stage}"))),
+            Err(err) => {
+                handler.emit_err(err);
+                Err(())
+            }
+        };
+    }
+
     // Check for CWD option:
     let cwd = get_cwd_option(&test);
 
     // Parse the program.
-    let mut parsed = handler.extend_if_error(parse_program(handler, &test.content, cwd))?;
+    // Note that the parser recovers from syntax errors, so that the type checker can report errors as well.
+    let mut parsed = parse_program(handler, &test.content, cwd).map_err(|err| handler.emit_err(err))?;
+
+    // Parse the input file, if any.
+    if let Some(input_file) = get_input_file_option(&test) {
+        handler.extend_if_error(parsed.parse_input(input_file))?;
+    }
 
     // Compile the program to bytecode.
-    let program_name = format!("{}.{}", parsed.program_name, parsed.network);
     let bytecode = handler.extend_if_error(compile_and_process(&mut parsed))?;
 
-    // Set up the build directory.
-    let package = setup_build_directory(&program_name, &bytecode, handler)?;
-
-    // Get the program process and check all instructions.
-    handler.extend_if_error(package.get_process().map_err(LeoError::Anyhow))?;
-
     // Hash the ast files.
-    let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, inlined_ast) = hash_asts();
+    let (initial_ast, unrolled_ast, ssa_ast, constant_folded_ast, flattened_ast, inlined_ast, cse_ast, dce_ast) =
+        hash_asts();
 
     // Clean up the output directory.
     if fs::read_dir("/tmp/output").is_ok() {
@@ -86,9 +105,13 @@ fn run_test(test: Test, handler: &Handler) -> Result<Value, ()> {
         initial_ast,
         unrolled_ast,
         ssa_ast,
+        constant_folded_ast,
         flattened_ast,
         inlined_ast,
+        cse_ast,
+        dce_ast,
         bytecode: hash_content(&bytecode),
+        warnings: buf.1.take().to_string(),
     };
     Ok(serde_yaml::to_value(final_output).expect("serialization failed"))
 }
@@ -16,11 +16,11 @@
 
 mod utilities;
 use utilities::{buffer_if_err, compile_and_process, parse_program, BufferEmitter};
-use utilities::{get_cwd_option, setup_build_directory, Aleo, Network};
+use utilities::get_cwd_option;
 
 use crate::utilities::{hash_asts, hash_content};
 
-use leo_errors::emitter::Handler;
+use leo_errors::{emitter::Handler, LeoError};
 use leo_span::symbol::create_session_if_not_set_then;
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
@@ -28,6 +28,8 @@ use leo_test_framework::{
 };
 
 use snarkvm::console;
+use snarkvm::file::Manifest;
+use snarkvm::package::Package;
 use snarkvm::prelude::*;
 
 use leo_test_framework::test::TestExpectationMode;
@@ -35,7 +37,15 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
 use std::collections::BTreeMap;
-use std::{fs, path::Path, rc::Rc};
+use std::{
+    fs::{self, File},
+    path::Path,
+    rc::Rc,
+};
+use tempfile::TempDir;
+
+type Network = Testnet3;
+type Aleo = snarkvm::circuit::AleoV0;
 
 // TODO: Evaluate namespace.
 struct ExecuteNamespace;
@@ -53,14 +63,48 @@ impl Namespace for ExecuteNamespace {
     }
 }
 
+/// Writes the bytecode to a new package in a temporary directory, and opens the package.
+/// The directory is removed when the returned `TempDir` is dropped.
+fn setup_build_directory(
+    program_name: &str,
+    bytecode: &String,
+    handler: &Handler,
+) -> Result<(TempDir, Package<Network>), ()> {
+    // Initialize a temporary directory.
+    let temp_dir = tempfile::tempdir().expect("Failed to open temporary directory");
+    let directory = temp_dir.path();
+
+    // Create the program id.
+    let program_id = ProgramID::<Network>::from_str(program_name).unwrap();
+
+    // Write the program string to a file in the temporary directory.
+    let path = directory.join("main.aleo");
+    let mut file = File::create(path).unwrap();
+    file.write_all(bytecode.as_bytes()).unwrap();
+
+    // Create the manifest file.
+    let _manifest_file = Manifest::create(directory, &program_id).unwrap();
+
+    // Create the build directory.
+    let build_directory = directory.join("build");
+    fs::create_dir_all(build_directory).unwrap();
+
+    // Open the package at the temporary directory.
+    let package = handler.extend_if_error(Package::<Network>::open(directory).map_err(LeoError::Anyhow))?;
+    Ok((temp_dir, package))
+}
+
 // TODO: Format this better.
 #[derive(Deserialize, PartialEq, Eq, Serialize)]
 struct ExecuteOutput {
     pub initial_ast: String,
     pub unrolled_ast: String,
     pub ssa_ast: String,
+    pub constant_folded_ast: String,
     pub flattened_ast: String,
     pub inlined_ast: String,
+    pub cse_ast: String,
+    pub dce_ast: String,
     pub bytecode: String,
     pub results: BTreeMap<String, Vec<BTreeMap<String, String>>>,
 }
@@ -78,7 +122,8 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
     let cwd = get_cwd_option(&test);
 
     // Parse the program.
-    let mut parsed = handler.extend_if_error(parse_program(handler, &test.content, cwd))?;
+    // Note that the parser recovers from syntax errors, so that the type checker can report errors as well.
+    let mut parsed = parse_program(handler, &test.content, cwd).map_err(|err| handler.emit_err(err))?;
 
     // Compile the program to bytecode.
     let program_name = format!("{}.{}", parsed.program_name, parsed.network);
@@ -97,11 +142,14 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
     let mut results = BTreeMap::new();
 
     // Setup the build directory.
-    let package = setup_build_directory(&program_name, &bytecode, handler)?;
+    let (_temp_dir, package) = setup_build_directory(&program_name, &bytecode, handler)?;
 
     // Initialize an rng.
     let rng = &mut rand::thread_rng();
 
+    // Sample the private key of the caller.
+    let private_key = PrivateKey::<Network>::new(rng).unwrap();
+
     // Run each test case for each function.
     for (function_name, function_cases) in all_cases {
         let function_name = Identifier::from_str(function_name.as_str().unwrap()).unwrap();
@@ -122,14 +170,8 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
 
             // TODO: Add support for custom config like custom private keys.
             // Execute the program and get the outputs.
-            let output_string = match package.run::<Aleo, _>(
-                None,
-                package.manifest_file().development_private_key(),
-                function_name,
-                &inputs,
-                rng,
-            ) {
-                Ok((response, _, _, _)) => format!(
+            let output_string = match package.run::<Aleo, _>(&private_key, function_name, &inputs, rng) {
+                Ok((response, _)) => format!(
                     "[{}]",
                     response
                         .outputs()
@@ -161,7 +203,8 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
     }
 
     // Hash the ast files.
-    let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, inlined_ast) = hash_asts();
+    let (initial_ast, unrolled_ast, ssa_ast, constant_folded_ast, flattened_ast, inlined_ast, cse_ast, dce_ast) =
+        hash_asts();
 
     // Clean up the output directory.
     if fs::read_dir("/tmp/output").is_ok() {
@@ -172,8 +215,11 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
         initial_ast,
         unrolled_ast,
         ssa_ast,
+        constant_folded_ast,
         flattened_ast,
         inlined_ast,
+        cse_ast,
+        dce_ast,
         bytecode: hash_content(&bytecode),
         results,
     };
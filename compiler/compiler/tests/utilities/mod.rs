@@ -19,34 +19,37 @@ use leo_errors::{
     emitter::{Buffer, Emitter, Handler},
     LeoError, LeoWarning,
 };
-use leo_passes::{CodeGenerator, Pass};
 use leo_span::source_map::FileName;
 use leo_test_framework::Test;
 
-use snarkvm::prelude::*;
-
-use snarkvm::file::Manifest;
-use snarkvm::package::Package;
-use std::fs::File;
 use std::{
     cell::RefCell,
+    fmt::{self, Display, Formatter},
     fs,
     path::{Path, PathBuf},
     rc::Rc,
 };
 
-pub type Network = Testnet3;
-#[allow(unused)]
-pub type Aleo = snarkvm::circuit::AleoV0;
-
-pub fn hash_asts() -> (String, String, String, String, String) {
-    let initial_ast = hash_file("/tmp/output/test.initial_ast.json");
-    let unrolled_ast = hash_file("/tmp/output/test.unrolled_ast.json");
-    let ssa_ast = hash_file("/tmp/output/test.ssa_ast.json");
-    let flattened_ast = hash_file("/tmp/output/test.flattened_ast.json");
-    let inlined_ast = hash_file("/tmp/output/test.inlined_ast.json");
-
-    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, inlined_ast)
+pub fn hash_asts() -> (String, String, String, String, String, String, String, String) {
+    let initial_ast = hash_file("/tmp/output/test.parse.json");
+    let unrolled_ast = hash_file("/tmp/output/test.unroll.json");
+    let ssa_ast = hash_file("/tmp/output/test.ssa.json");
+    let constant_folded_ast = hash_file("/tmp/output/test.const_fold.json");
+    let flattened_ast = hash_file("/tmp/output/test.flatten.json");
+    let inlined_ast = hash_file("/tmp/output/test.inline.json");
+    let cse_ast = hash_file("/tmp/output/test.cse.json");
+    let dce_ast = hash_file("/tmp/output/test.dce.json");
+
+    (
+        initial_ast,
+        unrolled_ast,
+        ssa_ast,
+        constant_folded_ast,
+        flattened_ast,
+        inlined_ast,
+        cse_ast,
+        dce_ast,
+    )
 }
 
 pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
@@ -61,27 +64,27 @@ pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
     })
 }
 
-pub fn setup_build_directory(program_name: &str, bytecode: &String, handler: &Handler) -> Result<Package<Network>, ()> {
-    // Initialize a temporary directory.
-    let directory = temp_dir();
-
-    // Create the program id.
-    let program_id = ProgramID::<Network>::from_str(program_name).unwrap();
-
-    // Write the program string to a file in the temporary directory.
-    let path = directory.join("main.aleo");
-    let mut file = File::create(path).unwrap();
-    file.write_all(bytecode.as_bytes()).unwrap();
-
-    // Create the manifest file.
-    let _manifest_file = Manifest::create(&directory, &program_id).unwrap();
-
-    // Create the build directory.
-    let build_directory = directory.join("build");
-    fs::create_dir_all(build_directory).unwrap();
+#[allow(unused)]
+pub fn get_input_file_option(test: &Test) -> Option<PathBuf> {
+    // Check for input file option:
+    // ``` input_file: inputs/main.in ```
+    // When set, the values of the input file, relative to the test file, are checked against the program.
+    test.config.extra.get("input_file").map(|val| {
+        let mut path = test.path.clone();
+        path.pop();
+        path.join(val.as_str().unwrap())
+    })
+}
 
-    // Open the package at the temporary directory.
-    handler.extend_if_error(Package::<Testnet3>::open(&directory).map_err(LeoError::Anyhow))
+#[allow(unused)]
+pub fn get_stop_after_option(test: &Test) -> Option<String> {
+    // Check for stop after option:
+    // ``` stop_after: parse ```
+    // When set, the program is compiled from a file until the given stage, as `leo build --stop-after` does.
+    test.config
+        .extra
+        .get("stop_after")
+        .map(|val| val.as_str().unwrap().to_string())
 }
 
 pub fn new_compiler(handler: &Handler, main_file_path: PathBuf) -> Compiler<'_> {
@@ -97,11 +100,8 @@ pub fn new_compiler(handler: &Handler, main_file_path: PathBuf) -> Compiler<'_>
         Some(OutputOptions {
             spans_enabled: false,
             initial_input_ast: true,
-            initial_ast: true,
-            unrolled_ast: true,
-            ssa_ast: true,
-            flattened_ast: true,
-            inlined_ast: true,
+            emit: Compiler::STAGES.iter().map(|stage| stage.to_string()).collect(),
+            ..Default::default()
         }),
     )
 }
@@ -111,13 +111,40 @@ pub fn parse_program<'a>(
     program_string: &str,
     cwd: Option<PathBuf>,
 ) -> Result<Compiler<'a>, LeoError> {
-    let mut compiler = new_compiler(handler, cwd.clone().unwrap_or_else(|| "compiler-test".into()));
-    let name = cwd.map_or_else(|| FileName::Custom("compiler-test".into()), FileName::Real);
-    compiler.parse_program_from_string(program_string, name)?;
+    let mut compiler = new_compiler(handler, "compiler-test".into());
+    // The imported programs are resolved from `imports/`, and the modules from `src/`, in the directory given by the `cwd` option.
+    if let Some(cwd) = cwd {
+        compiler.import_directory = cwd.join("imports");
+        compiler.module_directory = cwd.join("src");
+    }
+    compiler.parse_program_from_string(program_string, FileName::Custom("compiler-test".into()))?;
 
     Ok(compiler)
 }
 
+#[allow(unused)]
+pub fn compile_until(handler: &Handler, program_string: &str, stage: String) -> Result<(), LeoError> {
+    let output_dir = PathBuf::from("/tmp/output/");
+    fs::create_dir_all(output_dir.clone()).unwrap();
+    let main_file_path = output_dir.join("compiler-test.leo");
+    fs::write(&main_file_path, program_string).unwrap();
+
+    let mut compiler = Compiler::new(
+        String::from("test"),
+        String::from("aleo"),
+        handler,
+        main_file_path,
+        output_dir,
+        Some(OutputOptions {
+            stop_after: Some(stage),
+            ..Default::default()
+        }),
+    );
+    assert!(compiler.compile()?.is_none(), "compilation stops early");
+
+    Ok(())
+}
+
 pub fn hash_content(content: &str) -> String {
     use sha2::{Digest, Sha256};
     let mut hasher = Sha256::new();
@@ -177,27 +204,23 @@ pub fn buffer_if_err<T>(buf: &BufferEmitter, res: Result<T, String>) -> Result<T
     res.map_err(|err| buf.0.borrow_mut().push(LeoOrString::String(err)))
 }
 
-pub fn temp_dir() -> PathBuf {
-    tempfile::tempdir()
-        .expect("Failed to open temporary directory")
-        .into_path()
-}
-
 pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
-    let st = parsed.symbol_table_pass()?;
-
-    let (st, struct_graph, call_graph) = parsed.type_checker_pass(st)?;
-
-    let st = parsed.loop_unrolling_pass(st)?;
-
-    let assigner = parsed.static_single_assignment_pass(&st)?;
+    // The tests run without `stop_after`, so the compiler stages always run to completion.
+    let (st, struct_graph, call_graph) = parsed.compiler_stages()?.expect("compilation does not stop early");
 
-    let assigner = parsed.flattening_pass(&st, assigner)?;
+    // Compile Leo program to bytecode.
+    let program = parsed.code_generation_pass(&st, &struct_graph, &call_graph)?;
+    let (bytecode, source_map) = program.print();
 
-    let _ = parsed.function_inlining_pass(&call_graph, assigner)?;
+    // Check the bytecode with snarkVM.
+    parsed.validate_bytecode(&bytecode, &source_map, &program.unsupported)?;
 
-    // Compile Leo program to bytecode.
-    let bytecode = CodeGenerator::do_pass((&parsed.ast, &st, &struct_graph, &call_graph))?;
+    // Check the values of the input file, if any, against the structs and enums of the program.
+    if let Some(input_ast) = &parsed.input_ast {
+        for section in input_ast.sections.iter() {
+            input_ast.program_inputs(&section.name(), st.structs.clone(), st.enums.clone())?;
+        }
+    }
 
     Ok(bytecode)
 }
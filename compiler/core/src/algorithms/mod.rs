@@ -26,7 +26,7 @@ use leo_ast::Type;
 use leo_span::{sym, Symbol};
 
 /// A core instruction that maps directly to an AVM bytecode instruction.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CoreInstruction {
     BHP256Commit,
     BHP256Hash,
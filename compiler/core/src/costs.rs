@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{IntegerType, Type};
+
+/// Returns an estimate of the number of constraints that the Aleo instruction with the given opcode synthesizes
+/// on operands of the given type, e.g. `add` on `u8`, or `None` if the opcode does not synthesize constraints on its own.
+///
+/// The type is that of the first operand, e.g. `group` for a `mul` of a group by a scalar. Where it is not known,
+/// e.g. for the ternaries introduced by static single assignment, or where it is a struct or an address,
+/// the operands are assumed to be 64-bit integers. Hashes and commitments are estimated regardless of their operands.
+/// The estimates are meant for comparing the cost of programs rather than predicting it exactly.
+/// Note that a `call` to a closure costs as much as the closure itself, and a `cast` depends on its target type,
+/// see `estimated_cast_constraints`, neither of which is known from the opcode.
+/// Finalize commands are executed on-chain and are not proven.
+pub fn estimated_constraints(opcode: &str, operand_type: Option<&Type>) -> Option<u64> {
+    // Hashes and commitments cost roughly the same, regardless of whether they hash or commit.
+    if let Some(algorithm) = opcode.strip_prefix("hash.").or_else(|| opcode.strip_prefix("commit.")) {
+        return match algorithm {
+            "bhp256" => Some(1_300),
+            "bhp512" => Some(1_700),
+            "bhp768" => Some(2_500),
+            "bhp1024" => Some(3_300),
+            "ped64" => Some(500),
+            "ped128" => Some(1_000),
+            "psd2" => Some(250),
+            "psd4" => Some(350),
+            "psd8" => Some(550),
+            _ => None,
+        };
+    }
+
+    match operand_type {
+        Some(Type::Boolean) => estimated_boolean_constraints(opcode),
+        Some(Type::Field) => estimated_field_constraints(opcode),
+        Some(Type::Group) => estimated_group_constraints(opcode),
+        Some(Type::Scalar) => estimated_scalar_constraints(opcode),
+        Some(Type::Integer(integer_type)) => estimated_integer_constraints(opcode, *integer_type),
+        _ => estimated_integer_constraints(opcode, IntegerType::U64),
+    }
+}
+
+/// Booleans are single bits, so each operation on them costs about one constraint.
+fn estimated_boolean_constraints(opcode: &str) -> Option<u64> {
+    Some(match opcode {
+        "not" => 0,
+        "and" | "nand" | "nor" | "or" | "xor" => 1,
+        "assert.eq" | "assert.neq" | "is.eq" | "is.neq" | "ternary" => 1,
+        _ => return None,
+    })
+}
+
+/// Field elements are native to the constraint system, so linear operations are free and multiplications cost one constraint,
+/// whereas comparisons and exponentiations decompose the operands into their 253 bits.
+fn estimated_field_constraints(opcode: &str) -> Option<u64> {
+    Some(match opcode {
+        "add" | "double" | "neg" | "sub" => 0,
+        "assert.eq" | "mul" | "square" | "ternary" => 1,
+        "div" | "inv" => 2,
+        "assert.neq" | "is.eq" | "is.neq" => 3,
+        "sqrt" => 200,
+        "gt" | "gte" | "lt" | "lte" => 760,
+        "pow" => 1_000,
+        _ => return None,
+    })
+}
+
+/// Group elements are points of a twisted Edwards curve, so additions cost a few constraints,
+/// whereas a multiplication by a scalar doubles and adds for each of the bits of the scalar.
+fn estimated_group_constraints(opcode: &str) -> Option<u64> {
+    Some(match opcode {
+        "neg" => 0,
+        "assert.eq" | "ternary" => 2,
+        "assert.neq" | "double" | "is.eq" | "is.neq" => 5,
+        "add" | "sub" => 6,
+        "mul" => 3_000,
+        _ => return None,
+    })
+}
+
+/// Scalars are checked to be less than the order of the scalar field, which decomposes them into their 251 bits.
+/// A multiplication of a scalar by a group costs as much as that of a group by a scalar.
+fn estimated_scalar_constraints(opcode: &str) -> Option<u64> {
+    Some(match opcode {
+        "assert.eq" | "ternary" => 1,
+        "assert.neq" | "is.eq" | "is.neq" => 3,
+        "add" | "gt" | "gte" | "lt" | "lte" => 760,
+        "mul" => 3_000,
+        _ => return None,
+    })
+}
+
+/// Integers are represented by their bits, so the cost of most operations grows linearly with the width of the type,
+/// where the checked variants also check for overflow.
+fn estimated_integer_constraints(opcode: &str, integer_type: IntegerType) -> Option<u64> {
+    let bits = u64::from(integer_type.size_in_bits());
+    Some(match opcode {
+        // Operations on the bits of the operands.
+        "and" | "nand" | "nor" | "or" | "xor" => bits,
+        "not" => 0,
+        "shl" | "shr" => 4 * bits + 4,
+        "shl.w" | "shr.w" => 3 * bits + 8,
+
+        // Arithmetic operations, where an exponentiation costs about as much as a few dozen multiplications.
+        "abs" | "abs.w" | "neg" => 2 * bits + 2,
+        "add" | "sub" => bits + 2,
+        "add.w" | "sub.w" => bits + 1,
+        "div" | "mod" | "rem" => 6 * bits + 16,
+        "div.w" | "rem.w" => 6 * bits + 6,
+        "mul" => 3 * bits + 4,
+        "mul.w" => 2 * bits,
+        "pow" => 30 * (3 * bits + 4),
+        "pow.w" => 40 * 2 * bits,
+
+        // Comparisons and selections.
+        "assert.eq" | "assert.neq" | "is.eq" | "is.neq" => bits + 1,
+        "gt" | "gte" | "lt" | "lte" => 2 * bits + 2,
+        "ternary" => bits,
+
+        _ => return None,
+    })
+}
+
+/// Returns an estimate of the number of constraints that a `cast` into the given literal type synthesizes, e.g. `u8`.
+///
+/// Casting into an integer, scalar or boolean checks that the operand fits the type, which decomposes it into bits,
+/// whereas casting into a group or an address recovers a point of the curve from its x-coordinate.
+/// Constructing a struct, record or array only rearranges its operands, and is not a cast into a literal type.
+pub fn estimated_cast_constraints(type_: &Type) -> u64 {
+    match type_ {
+        Type::Boolean => 2,
+        Type::Field => 1,
+        Type::Scalar => 253,
+        Type::Group | Type::Address => 500,
+        Type::Integer(integer_type) => u64::from(integer_type.size_in_bits()),
+        _ => 0,
+    }
+}
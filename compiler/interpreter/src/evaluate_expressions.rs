@@ -0,0 +1,338 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    console_cast, field_binary, field_unary, format_value, from_literal, literal_type, to_literal, to_plaintext,
+    values_equal, Interpreter, Network,
+};
+
+use leo_ast::{
+    AccessExpression, AssociatedFunction, BinaryOperation, CallExpression, Expression, Node, Type, UnaryOperation,
+    Value, Variant,
+};
+use leo_core::CoreInstruction;
+use leo_errors::{FlattenError, InterpreterError, Result};
+use leo_span::{sym, Span};
+
+use snarkvm_console::program::{Literal as ConsoleLiteral, Network as _, ToBits, ToFields};
+
+impl Interpreter<'_> {
+    /// Evaluates the expression to a value.
+    pub(crate) fn evaluate_expression(&mut self, input: &Expression) -> Result<Value> {
+        let unsupported = || Err(InterpreterError::unsupported_operation(input, input.span()).into());
+
+        match input {
+            Expression::Access(AccessExpression::Array(access)) => {
+                let array = self.evaluate_expression(&access.array)?;
+                let index = self.evaluate_expression(&access.index)?;
+                self.index_array(array, &index, access.span)
+            }
+            Expression::Access(AccessExpression::AssociatedConstant(_)) => unsupported(),
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => match &function.ty {
+                Type::Identifier(identifier) if identifier.name == sym::Mapping => {
+                    self.evaluate_mapping_operation(function)
+                }
+                _ => self.evaluate_core_function(function),
+            },
+            Expression::Access(AccessExpression::Member(access)) => match access.inner.as_ref() {
+                Expression::Identifier(identifier) if identifier.name == sym::SelfLower => Ok(self.caller.clone()),
+                inner => match self.evaluate_expression(inner)? {
+                    Value::Struct(_, mut members) => Ok(members
+                        .remove(&access.name.name)
+                        .expect("Type checking guarantees that the member exists.")),
+                    _ => unreachable!("Type checking guarantees that the inner expression is a struct."),
+                },
+            },
+            Expression::Access(AccessExpression::Tuple(access)) => match self.evaluate_expression(&access.tuple)? {
+                Value::Tuple(mut elements) => Ok(elements.swap_remove(access.index.to_usize())),
+                _ => unreachable!("Type checking guarantees that the inner expression is a tuple."),
+            },
+            Expression::Array(array) => Ok(Value::Array(
+                array
+                    .elements
+                    .iter()
+                    .map(|element| self.evaluate_expression(element))
+                    .collect::<Result<_>>()?,
+            )),
+            Expression::Binary(binary) => {
+                let left = self.evaluate_expression(&binary.left)?;
+                let right = self.evaluate_expression(&binary.right)?;
+                match binary.op {
+                    BinaryOperation::Eq => Ok(Value::Boolean(values_equal(&left, &right), binary.span)),
+                    BinaryOperation::Neq => Ok(Value::Boolean(!values_equal(&left, &right), binary.span)),
+                    // Arithmetic on fields, groups, and scalars is evaluated by snarkVM.
+                    _ if [&left, &right].iter().any(|value| is_field_like(value)) => {
+                        match field_binary(binary, &left, &right)? {
+                            Some(value) => Ok(value),
+                            None => unsupported(),
+                        }
+                    }
+                    op => {
+                        // Division by zero halts, whether or not it wraps.
+                        if matches!(
+                            op,
+                            BinaryOperation::Div
+                                | BinaryOperation::DivWrapped
+                                | BinaryOperation::Mod
+                                | BinaryOperation::Rem
+                                | BinaryOperation::RemWrapped
+                        ) && right.is_zero()
+                        {
+                            return Err(
+                                InterpreterError::operation_halted(binary, "division by zero", binary.span).into(),
+                            );
+                        }
+                        let overflow = |_| {
+                            let operation = format!("{} {op} {}", format_value(&left), format_value(&right));
+                            InterpreterError::integer_overflow(operation, binary.span)
+                        };
+                        match left.clone().binary(op, right.clone(), binary.span).map_err(overflow)? {
+                            Some(value) => Ok(value),
+                            None => unsupported(),
+                        }
+                    }
+                }
+            }
+            Expression::Call(call) => self.evaluate_call(call),
+            Expression::Cast(cast) => {
+                let value = self.evaluate_expression(&cast.expression)?;
+                // Lossy casts and the casts that are not folded by the AST are evaluated by snarkVM.
+                let folded = match cast.lossy.get() {
+                    true => None,
+                    false => value.clone().cast(&cast.type_, cast.span)?,
+                };
+                match folded {
+                    Some(value) => Ok(value),
+                    None => match console_cast(cast, &value)? {
+                        Some(value) => Ok(value),
+                        None => unsupported(),
+                    },
+                }
+            }
+            Expression::Struct(struct_) => {
+                let declaration = &self.program.structs[&struct_.name.name];
+                // Construct the members in the order that they are declared.
+                let members = declaration
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let initializer = struct_
+                            .members
+                            .iter()
+                            .find(|initializer| initializer.identifier.name == member.name())
+                            .expect("Type checking guarantees that every member is initialized.");
+                        let value = match &initializer.expression {
+                            Some(expression) => self.evaluate_expression(expression)?,
+                            None => self.lookup_variable(initializer.identifier.name).clone(),
+                        };
+                        Ok((member.name(), value))
+                    })
+                    .collect::<Result<_>>()?;
+                Ok(Value::Struct(struct_.name, members))
+            }
+            Expression::Err(_) => unreachable!("Type checking guarantees that there are no error expressions."),
+            Expression::Identifier(identifier) => Ok(self.lookup_variable(identifier.name).clone()),
+            Expression::Literal(literal) => Ok(Value::from(literal)),
+            Expression::Match(_) => unreachable!("Match lowering removes `match` expressions before interpretation."),
+            // Note that only the selected branch is evaluated.
+            Expression::Ternary(ternary) => match self.evaluate_expression(&ternary.condition)? {
+                Value::Boolean(true, _) => self.evaluate_expression(&ternary.if_true),
+                Value::Boolean(false, _) => self.evaluate_expression(&ternary.if_false),
+                _ => unreachable!("Type checking guarantees that the condition is a boolean."),
+            },
+            Expression::Tuple(tuple) => Ok(Value::Tuple(
+                tuple
+                    .elements
+                    .iter()
+                    .map(|element| self.evaluate_expression(element))
+                    .collect::<Result<_>>()?,
+            )),
+            Expression::Unary(unary) => {
+                let receiver = self.evaluate_expression(&unary.receiver)?;
+                let value = match is_field_like(&receiver) {
+                    true => field_unary(unary, &receiver)?,
+                    false => {
+                        // Only `abs` and `neg` may overflow, on the minimum value of a signed integer.
+                        let overflow = |_| {
+                            let operation = match unary.op {
+                                UnaryOperation::Negate => format!("-{}", format_value(&receiver)),
+                                _ => format!("{}.abs()", format_value(&receiver)),
+                            };
+                            InterpreterError::integer_overflow(operation, unary.span)
+                        };
+                        receiver.clone().unary(unary.op, unary.span).map_err(overflow)?
+                    }
+                };
+                match value {
+                    Some(value) => Ok(value),
+                    None => unsupported(),
+                }
+            }
+            Expression::Unit(_) => Ok(Value::Tuple(Vec::new())),
+        }
+    }
+
+    /// Returns the element of the array at the given index.
+    pub(crate) fn index_array(&self, array: Value, index: &Value, span: Span) -> Result<Value> {
+        match array {
+            Value::Array(mut elements) => {
+                let length = elements.len();
+                match index.to_string().parse::<usize>() {
+                    Ok(index) if index < length => Ok(elements.swap_remove(index)),
+                    _ => Err(FlattenError::array_index_out_of_range(index, length, span).into()),
+                }
+            }
+            _ => unreachable!("Type checking guarantees that the inner expression is an array."),
+        }
+    }
+
+    /// Evaluates a call to a function in the program.
+    fn evaluate_call(&mut self, input: &CallExpression) -> Result<Value> {
+        if input.external.is_some() {
+            return Err(InterpreterError::unsupported_operation(input, input.span).into());
+        }
+
+        let function = match input.function.as_ref() {
+            Expression::Identifier(identifier) => self.lookup_function(&identifier.to_string())?,
+            _ => unreachable!("Parsing guarantees that the function is an identifier."),
+        };
+        let arguments = input
+            .arguments
+            .iter()
+            .map(|argument| self.evaluate_expression(argument))
+            .collect::<Result<_>>()?;
+
+        // Note that transitions can only be called from test functions, in which case their finalize blocks are also run.
+        match function.variant {
+            Variant::Transition => Ok(self.call_transition(function, arguments)?.0),
+            _ => Ok(self.call_function(function, arguments)?.0),
+        }
+    }
+
+    /// Evaluates a call to a core function, e.g. `BHP256::hash(a)`, as the corresponding snarkVM instruction does.
+    fn evaluate_core_function(&mut self, input: &AssociatedFunction) -> Result<Value> {
+        let module = match &input.ty {
+            Type::Identifier(identifier) => identifier.name,
+            _ => unreachable!("Type checking guarantees that the core function belongs to a module."),
+        };
+        let instruction = CoreInstruction::from_symbols(module, input.name.name)
+            .expect("Type checking guarantees that the core function exists.");
+        let arguments = input
+            .args
+            .iter()
+            .map(|argument| self.evaluate_expression(argument))
+            .collect::<Result<Vec<_>>>()?;
+
+        let unsupported = || InterpreterError::unsupported_operation(input, input.span);
+        let input_value = to_plaintext(&arguments[0]).ok_or_else(unsupported)?;
+        let randomizer = || match arguments.get(1).and_then(to_literal) {
+            Some(ConsoleLiteral::Scalar(randomizer)) => Ok(randomizer),
+            _ => Err(unsupported()),
+        };
+        let bits = input_value.to_bits_le();
+
+        // Poseidon hashes to a field, and the other hashes and commitments to a group.
+        let output = match instruction {
+            CoreInstruction::BHP256Commit => {
+                Network::commit_to_group_bhp256(&bits, &randomizer()?).map(ConsoleLiteral::Group)
+            }
+            CoreInstruction::BHP256Hash => Network::hash_to_group_bhp256(&bits).map(ConsoleLiteral::Group),
+            CoreInstruction::BHP512Commit => {
+                Network::commit_to_group_bhp512(&bits, &randomizer()?).map(ConsoleLiteral::Group)
+            }
+            CoreInstruction::BHP512Hash => Network::hash_to_group_bhp512(&bits).map(ConsoleLiteral::Group),
+            CoreInstruction::BHP768Commit => {
+                Network::commit_to_group_bhp768(&bits, &randomizer()?).map(ConsoleLiteral::Group)
+            }
+            CoreInstruction::BHP768Hash => Network::hash_to_group_bhp768(&bits).map(ConsoleLiteral::Group),
+            CoreInstruction::BHP1024Commit => {
+                Network::commit_to_group_bhp1024(&bits, &randomizer()?).map(ConsoleLiteral::Group)
+            }
+            CoreInstruction::BHP1024Hash => Network::hash_to_group_bhp1024(&bits).map(ConsoleLiteral::Group),
+            CoreInstruction::Pedersen64Commit => {
+                Network::commit_to_group_ped64(&bits, &randomizer()?).map(ConsoleLiteral::Group)
+            }
+            CoreInstruction::Pedersen64Hash => Network::hash_to_group_ped64(&bits).map(ConsoleLiteral::Group),
+            CoreInstruction::Pedersen128Commit => {
+                Network::commit_to_group_ped128(&bits, &randomizer()?).map(ConsoleLiteral::Group)
+            }
+            CoreInstruction::Pedersen128Hash => Network::hash_to_group_ped128(&bits).map(ConsoleLiteral::Group),
+            CoreInstruction::Poseidon2Hash => input_value
+                .to_fields()
+                .and_then(|fields| Network::hash_psd2(&fields))
+                .map(ConsoleLiteral::Field),
+            CoreInstruction::Poseidon4Hash => input_value
+                .to_fields()
+                .and_then(|fields| Network::hash_psd4(&fields))
+                .map(ConsoleLiteral::Field),
+            CoreInstruction::Poseidon8Hash => input_value
+                .to_fields()
+                .and_then(|fields| Network::hash_psd8(&fields))
+                .map(ConsoleLiteral::Field),
+        };
+
+        // The output is cast to the return type, as in the generated instruction.
+        let destination = literal_type(&instruction.return_type()).expect("Core functions return primitive types.");
+        match output.and_then(|output| output.cast_lossy(destination)) {
+            Ok(output) => Ok(from_literal(output, input.span)),
+            Err(error) => Err(InterpreterError::operation_halted(input, error, input.span).into()),
+        }
+    }
+
+    /// Evaluates a mapping operation, e.g. `Mapping::get(balances, key)`.
+    /// The operations that do not produce a value evaluate to the unit value.
+    fn evaluate_mapping_operation(&mut self, input: &AssociatedFunction) -> Result<Value> {
+        let mapping = match &input.args[0] {
+            Expression::Identifier(identifier) => identifier.name,
+            _ => unreachable!("Type checking guarantees that the first argument is a mapping."),
+        };
+        let key = self.evaluate_expression(&input.args[1])?;
+
+        Ok(match input.name.name {
+            sym::contains => Value::Boolean(self.lookup_entry(mapping, &key).is_some(), input.span),
+            sym::get => match self.lookup_entry(mapping, &key) {
+                Some(value) => value.clone(),
+                None => {
+                    return Err(InterpreterError::mapping_key_not_found(mapping, format_value(&key), input.span).into())
+                }
+            },
+            sym::get_or_init => match self.lookup_entry(mapping, &key) {
+                Some(value) => value.clone(),
+                // The default value is stored in the mapping, as in `get.or_init`.
+                None => {
+                    let default = self.evaluate_expression(&input.args[2])?;
+                    self.set_entry(mapping, key, default.clone());
+                    default
+                }
+            },
+            sym::remove => {
+                self.remove_entry(mapping, &key);
+                Value::Tuple(Vec::new())
+            }
+            sym::set => {
+                let value = self.evaluate_expression(&input.args[2])?;
+                self.set_entry(mapping, key, value);
+                Value::Tuple(Vec::new())
+            }
+            _ => unreachable!("Type checking guarantees that the mapping operation is valid."),
+        })
+    }
+}
+
+/// Returns whether the value is a `field`, `group`, or `scalar`, whose arithmetic is evaluated by snarkVM.
+fn is_field_like(value: &Value) -> bool {
+    matches!(value, Value::Field(..) | Value::Group(..) | Value::Scalar(..))
+}
@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{format_value, values_equal, Interpreter, Return};
+
+use leo_ast::{AccessExpression, AssertVariant, BinaryOperation, Block, Expression, Node, Statement, Type, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+impl Interpreter<'_> {
+    /// Evaluates the statements of the block in a new scope.
+    /// Returns the output of the enclosing function if a return statement is reached.
+    pub(crate) fn evaluate_block(&mut self, input: &Block) -> Result<Option<Return>> {
+        self.scopes.push(IndexMap::new());
+        let result = self.evaluate_statements(&input.statements);
+        self.scopes.pop();
+        result
+    }
+
+    fn evaluate_statements(&mut self, statements: &[Statement]) -> Result<Option<Return>> {
+        for statement in statements {
+            if let Some(output) = self.evaluate_statement(statement)? {
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Evaluates the statement.
+    /// Returns the output of the enclosing function if a return statement is reached.
+    fn evaluate_statement(&mut self, input: &Statement) -> Result<Option<Return>> {
+        match input {
+            Statement::Assert(assert) => {
+                match &assert.variant {
+                    AssertVariant::Assert(condition) => {
+                        if let Value::Boolean(false, _) = self.evaluate_expression(condition)? {
+                            return Err(InterpreterError::assertion_failed(condition, assert.span).into());
+                        }
+                    }
+                    AssertVariant::AssertEq(left, right) => {
+                        let left = self.evaluate_expression(left)?;
+                        let right = self.evaluate_expression(right)?;
+                        if !values_equal(&left, &right) {
+                            let (left, right) = (format_value(&left), format_value(&right));
+                            return Err(InterpreterError::assert_eq_failed(left, right, assert.span).into());
+                        }
+                    }
+                    AssertVariant::AssertNeq(left, right) => {
+                        let left = self.evaluate_expression(left)?;
+                        let right = self.evaluate_expression(right)?;
+                        if values_equal(&left, &right) {
+                            let (left, right) = (format_value(&left), format_value(&right));
+                            return Err(InterpreterError::assert_neq_failed(left, right, assert.span).into());
+                        }
+                    }
+                }
+                Ok(None)
+            }
+            Statement::Assign(assign) => {
+                let value = self.evaluate_expression(&assign.value)?;
+                self.assign(&assign.place, value)?;
+                Ok(None)
+            }
+            Statement::Block(block) => self.evaluate_block(block),
+            // Note that only the selected branch is evaluated.
+            Statement::Conditional(conditional) => match self.evaluate_expression(&conditional.condition)? {
+                Value::Boolean(true, _) => self.evaluate_block(&conditional.then),
+                Value::Boolean(false, _) => match &conditional.otherwise {
+                    Some(otherwise) => self.evaluate_statement(otherwise),
+                    None => Ok(None),
+                },
+                _ => unreachable!("Type checking guarantees that the condition is a boolean."),
+            },
+            Statement::Console(_) => unreachable!("Parsing guarantees that console statements are not in the AST."),
+            Statement::Decrement(decrement) => {
+                let key = self.evaluate_expression(&decrement.index)?;
+                let amount = self.evaluate_expression(&decrement.amount)?;
+                self.update_entry(decrement.mapping.name, key, BinaryOperation::Sub, amount, input)?;
+                Ok(None)
+            }
+            Statement::Definition(definition) => {
+                let value = self.evaluate_expression(&definition.value)?;
+                let scope = self.scopes.last_mut().expect("Definitions are always in a scope.");
+                match (&definition.place, value) {
+                    (Expression::Identifier(identifier), value) => {
+                        scope.insert(identifier.name, value);
+                    }
+                    (Expression::Tuple(tuple), Value::Tuple(elements)) => {
+                        for (place, element) in tuple.elements.iter().zip(elements) {
+                            match place {
+                                Expression::Identifier(identifier) => scope.insert(identifier.name, element),
+                                _ => unreachable!(
+                                    "Type checking guarantees that tuple elements on the lhs are identifiers."
+                                ),
+                            };
+                        }
+                    }
+                    _ => unreachable!("Type checking guarantees that the definition is well-formed."),
+                }
+                Ok(None)
+            }
+            Statement::Expression(expression) => {
+                self.evaluate_expression(&expression.expression)?;
+                Ok(None)
+            }
+            Statement::Increment(increment) => {
+                let key = self.evaluate_expression(&increment.index)?;
+                let amount = self.evaluate_expression(&increment.amount)?;
+                self.update_entry(increment.mapping.name, key, BinaryOperation::Add, amount, input)?;
+                Ok(None)
+            }
+            Statement::Iteration(iteration) => {
+                let mut current = self.evaluate_expression(&iteration.start)?;
+                let stop = self.evaluate_expression(&iteration.stop)?;
+                let one = Value::U8(1, iteration.span).cast(&iteration.type_, iteration.span)?;
+                let (comparison, one) = match (iteration.inclusive, one) {
+                    (true, Some(one)) => (BinaryOperation::Lte, one),
+                    (false, Some(one)) => (BinaryOperation::Lt, one),
+                    _ => unreachable!("Type checking guarantees that the iteration variable is an integer."),
+                };
+
+                while let Some(Value::Boolean(true, _)) =
+                    current.clone().binary(comparison, stop.clone(), iteration.span)?
+                {
+                    self.scopes
+                        .push(IndexMap::from([(iteration.variable.name, current.clone())]));
+                    let output = self.evaluate_block(&iteration.block);
+                    self.scopes.pop();
+                    if let Some(output) = output? {
+                        return Ok(Some(output));
+                    }
+
+                    // Stop before incrementing past the end of the range, which may overflow.
+                    if values_equal(&current, &stop) {
+                        break;
+                    }
+                    current = current
+                        .binary(BinaryOperation::Add, one.clone(), iteration.span)?
+                        .expect("Integer addition can be evaluated.");
+                }
+                Ok(None)
+            }
+            Statement::Match(_) => unreachable!("Match lowering removes `match` statements before interpretation."),
+            Statement::Return(return_) => {
+                let output = self.evaluate_expression(&return_.expression)?;
+                let finalize_arguments = match &return_.finalize_arguments {
+                    Some(arguments) => Some(
+                        arguments
+                            .iter()
+                            .map(|argument| self.evaluate_expression(argument))
+                            .collect::<Result<_>>()?,
+                    ),
+                    None => None,
+                };
+                Ok(Some((output, finalize_arguments)))
+            }
+        }
+    }
+
+    /// Assigns the value to the place, which is a variable, a tuple of variables, or an element or member of one.
+    fn assign(&mut self, place: &Expression, value: Value) -> Result<()> {
+        match (place, value) {
+            (Expression::Identifier(identifier), value) => self.update_variable(identifier.name, value),
+            (Expression::Tuple(tuple), Value::Tuple(elements)) => {
+                for (place, element) in tuple.elements.iter().zip(elements) {
+                    self.assign(place, element)?;
+                }
+            }
+            // Update the element or member of the enclosing value, and then assign the enclosing value.
+            (Expression::Access(AccessExpression::Array(access)), value) => {
+                let index = self.evaluate_expression(&access.index)?;
+                match self.evaluate_expression(&access.array)? {
+                    Value::Array(mut elements) => {
+                        // Check that the index is in range.
+                        self.index_array(Value::Array(elements.clone()), &index, access.span)?;
+                        elements[index.to_string().parse::<usize>().unwrap()] = value;
+                        self.assign(&access.array, Value::Array(elements))?;
+                    }
+                    _ => unreachable!("Type checking guarantees that the inner expression is an array."),
+                }
+            }
+            (Expression::Access(AccessExpression::Member(access)), value) => {
+                match self.evaluate_expression(&access.inner)? {
+                    Value::Struct(name, mut members) => {
+                        members.insert(access.name.name, value);
+                        self.assign(&access.inner, Value::Struct(name, members))?;
+                    }
+                    _ => unreachable!("Type checking guarantees that the inner expression is a struct."),
+                }
+            }
+            (Expression::Access(AccessExpression::Tuple(access)), value) => {
+                match self.evaluate_expression(&access.tuple)? {
+                    Value::Tuple(mut elements) => {
+                        elements[access.index.to_usize()] = value;
+                        self.assign(&access.tuple, Value::Tuple(elements))?;
+                    }
+                    _ => unreachable!("Type checking guarantees that the inner expression is a tuple."),
+                }
+            }
+            _ => unreachable!("Type checking guarantees that the place is assignable."),
+        }
+        Ok(())
+    }
+
+    /// Adds the amount to, or subtracts it from, the entry of the mapping for the given key.
+    /// A missing entry is treated as zero.
+    fn update_entry(
+        &mut self,
+        mapping: Symbol,
+        key: Value,
+        op: BinaryOperation,
+        amount: Value,
+        statement: &Statement,
+    ) -> Result<()> {
+        let span = statement.span();
+        let current = match self.lookup_entry(mapping, &key) {
+            Some(value) => value.clone(),
+            None => Value::U8(0, span)
+                .cast(&Type::from(&amount), span)?
+                .ok_or_else(|| InterpreterError::unsupported_operation(statement, span))?,
+        };
+        let overflow = |_| {
+            let operation = format!("{} {op} {}", format_value(&current), format_value(&amount));
+            InterpreterError::integer_overflow(operation, span)
+        };
+        match current.clone().binary(op, amount.clone(), span).map_err(overflow)? {
+            Some(value) => {
+                self.set_entry(mapping, key, value);
+                Ok(())
+            }
+            None => Err(InterpreterError::unsupported_operation(statement, span).into()),
+        }
+    }
+}
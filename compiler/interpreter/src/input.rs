@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{GroupLiteral, IntegerType, Struct, Type, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexMap;
+
+/// Parses an input written as an Aleo value, e.g. `1u32` or `{ x: 1u8, y: 2u8 }`, as a value of the given type.
+/// Visibility suffixes, e.g. `.private`, are ignored, as is the `_nonce` of a record.
+pub fn parse_input(input: &str, type_: &Type, structs: &IndexMap<Symbol, Struct>) -> Result<Value> {
+    let mut parser = InputParser { rest: input, structs };
+    match parser.parse_value(type_) {
+        Some(value) if parser.rest.trim().is_empty() => Ok(value),
+        _ => Err(InterpreterError::invalid_input(input, type_).into()),
+    }
+}
+
+/// A recursive descent parser for Aleo values.
+struct InputParser<'a> {
+    /// The part of the input that has not been parsed yet.
+    rest: &'a str,
+    /// The structs and records declared in the program.
+    structs: &'a IndexMap<Symbol, Struct>,
+}
+
+impl InputParser<'_> {
+    /// Consumes the given punctuation, and any whitespace before it.
+    fn eat(&mut self, punctuation: char) -> bool {
+        self.rest = self.rest.trim_start();
+        match self.rest.strip_prefix(punctuation) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consumes and returns the next token, i.e. everything up to the next punctuation or whitespace.
+    fn token(&mut self) -> &str {
+        self.rest = self.rest.trim_start();
+        let end = self
+            .rest
+            .find(|c: char| c.is_whitespace() || matches!(c, ',' | ':' | '{' | '}' | '[' | ']'))
+            .unwrap_or(self.rest.len());
+        let (token, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        token
+    }
+
+    fn parse_value(&mut self, type_: &Type) -> Option<Value> {
+        match type_ {
+            Type::Identifier(identifier) => self.parse_struct(self.structs.get(&identifier.name)?),
+            Type::Array(array_type) => {
+                if !self.eat('[') {
+                    return None;
+                }
+                let mut elements = Vec::with_capacity(array_type.length());
+                for i in 0..array_type.length() {
+                    if i > 0 && !self.eat(',') {
+                        return None;
+                    }
+                    elements.push(self.parse_value(&array_type.element_type)?);
+                }
+                self.eat(']').then_some(Value::Array(elements))
+            }
+            _ => {
+                let token = self.token();
+                // Remove the visibility, if any.
+                let token = [".private", ".public", ".constant"]
+                    .iter()
+                    .find_map(|visibility| token.strip_suffix(visibility))
+                    .unwrap_or(token);
+                parse_literal(token, type_)
+            }
+        }
+    }
+
+    fn parse_struct(&mut self, struct_: &Struct) -> Option<Value> {
+        if !self.eat('{') {
+            return None;
+        }
+        let mut members = IndexMap::new();
+        while !self.eat('}') {
+            if !members.is_empty() && !self.eat(',') {
+                return None;
+            }
+            let name = Symbol::intern(self.token());
+            if !self.eat(':') {
+                return None;
+            }
+            match struct_.members.iter().find(|member| member.name() == name) {
+                Some(member) => {
+                    let value = self.parse_value(&member.type_)?;
+                    members.insert(name, value);
+                }
+                // The nonce of a record is not declared in the program.
+                None if name == sym::_nonce && struct_.is_record => {
+                    self.token();
+                }
+                None => return None,
+            }
+        }
+
+        // Order the members as they are declared, checking that none are missing.
+        let members = struct_
+            .members
+            .iter()
+            .map(|member| Some((member.name(), members.remove(&member.name())?)))
+            .collect::<Option<_>>()?;
+        Some(Value::Struct(struct_.identifier, members))
+    }
+}
+
+/// Parses a literal of the given primitive type, e.g. `1u32`.
+fn parse_literal(token: &str, type_: &Type) -> Option<Value> {
+    let span = Default::default();
+    let number = |suffix: &str| {
+        let number = token.strip_suffix(suffix)?;
+        let digits = number.strip_prefix('-').unwrap_or(number);
+        (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())).then(|| number.to_string())
+    };
+
+    Some(match type_ {
+        Type::Address if token.starts_with("aleo1") => Value::Address(token.to_string(), span),
+        Type::Boolean => Value::Boolean(token.parse().ok()?, span),
+        Type::Field => Value::Field(number("field")?, span),
+        Type::Group => Value::Group(Box::new(GroupLiteral::Single(number("group")?, span))),
+        Type::Scalar => Value::Scalar(number("scalar")?, span),
+        Type::Integer(integer_type) => {
+            let number = number(&integer_type.to_string())?;
+            macro_rules! parse {
+                ($variant:ident) => {
+                    Value::$variant(number.parse().ok()?, span)
+                };
+            }
+            match integer_type {
+                IntegerType::I8 => parse!(I8),
+                IntegerType::I16 => parse!(I16),
+                IntegerType::I32 => parse!(I32),
+                IntegerType::I64 => parse!(I64),
+                IntegerType::I128 => parse!(I128),
+                IntegerType::U8 => parse!(U8),
+                IntegerType::U16 => parse!(U16),
+                IntegerType::U32 => parse!(U32),
+                IntegerType::U64 => parse!(U64),
+                IntegerType::U128 => parse!(U128),
+            }
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{format_value, parse_input, values_equal};
+
+use leo_ast::{Block, Function, Input, Program, ProgramScope, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use std::fmt;
+
+/// The contents of a mapping, where each entry is indexed by its formatted key.
+pub type MappingContents = IndexMap<String, (Value, Value)>;
+
+/// The value produced by a `return` statement, along with the arguments to the finalize block, if any.
+pub(crate) type Return = (Value, Option<Vec<Value>>);
+
+/// A change to an entry of a mapping, made by a finalize block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingUpdate {
+    /// The name of the mapping.
+    pub mapping: Symbol,
+    /// The key of the entry.
+    pub key: Value,
+    /// The value of the entry before the finalize block was run, if the entry existed.
+    pub old: Option<Value>,
+    /// The value of the entry after the finalize block was run, if the entry exists.
+    pub new: Option<Value>,
+}
+
+impl fmt::Display for MappingUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = |value: &Option<Value>| value.as_ref().map_or_else(|| "none".to_string(), format_value);
+        write!(
+            f,
+            "{}[{}]: {} -> {}",
+            self.mapping,
+            format_value(&self.key),
+            format(&self.old),
+            format(&self.new)
+        )
+    }
+}
+
+/// The result of running a transition with the interpreter.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Execution {
+    /// The outputs of the transition.
+    pub outputs: Vec<Value>,
+    /// The changes made to the program's mappings by the finalize block.
+    pub updates: Vec<MappingUpdate>,
+}
+
+pub struct Interpreter<'a> {
+    /// The program scope being interpreted.
+    pub(crate) program: &'a ProgramScope,
+    /// The value of `self.caller`.
+    pub(crate) caller: Value,
+    /// The values of the program's constants.
+    pub(crate) constants: IndexMap<Symbol, Value>,
+    /// The contents of the program's mappings.
+    /// Note that the contents persist across runs, so that consecutive transitions can build on each other.
+    pub mappings: IndexMap<Symbol, MappingContents>,
+    /// The variables in scope in the current function, from the outermost scope to the innermost.
+    pub(crate) scopes: Vec<IndexMap<Symbol, Value>>,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Returns a new interpreter for the given type-checked program, with `caller` as the address of the caller.
+    pub fn new(program: &'a Program, caller: String) -> Result<Self> {
+        // Note that parsing guarantees that there is exactly one program scope.
+        let program = program.program_scopes.values().next().unwrap();
+
+        let mut interpreter = Self {
+            program,
+            caller: Value::Address(caller, Default::default()),
+            constants: IndexMap::new(),
+            mappings: program.mappings.keys().map(|name| (*name, IndexMap::new())).collect(),
+            scopes: Vec::new(),
+        };
+
+        // Evaluate the program's constants, in the order that they are declared.
+        for (name, declaration) in program.consts.iter() {
+            let value = interpreter.evaluate_expression(&declaration.value)?;
+            interpreter.constants.insert(*name, value);
+        }
+
+        Ok(interpreter)
+    }
+
+    /// Runs the transition `name` with the given inputs, followed by its finalize block, if it has one.
+    /// The inputs are written as Aleo values, e.g. `1u32` or `{ x: 1u8, y: 2u8 }`.
+    /// If the finalize block fails, then any changes it made to the mappings are reverted.
+    pub fn run(&mut self, name: &str, inputs: &[String]) -> Result<Execution> {
+        let function = self.lookup_function(name)?;
+
+        // Check that the number of inputs matches the function signature.
+        if function.input.len() != inputs.len() {
+            return Err(InterpreterError::incorrect_number_of_inputs(name, function.input.len(), inputs.len()).into());
+        }
+
+        // Parse the inputs, using the types in the function signature.
+        let arguments = function
+            .input
+            .iter()
+            .zip(inputs.iter())
+            .map(|(input, string)| parse_input(string, &input.type_(), &self.program.structs))
+            .collect::<Result<Vec<_>>>()?;
+
+        let (output, updates) = self.call_transition(function, arguments)?;
+        let outputs = match output {
+            Value::Tuple(elements) => elements,
+            output => vec![output],
+        };
+
+        Ok(Execution { outputs, updates })
+    }
+
+    /// Runs the `@test` function `name`, returning an error if any of its assertions fail.
+    pub fn run_test(&mut self, name: &str) -> Result<()> {
+        let function = self.lookup_function(name)?;
+        self.call_function(function, Vec::new())?;
+        Ok(())
+    }
+
+    /// Returns the function with the given name.
+    pub fn lookup_function(&self, name: &str) -> Result<&'a Function> {
+        self.program
+            .functions
+            .get(&Symbol::intern(name))
+            .ok_or_else(|| InterpreterError::unknown_function(name, self.program.program_id).into())
+    }
+
+    /// Calls the function with the given arguments, returning its output and the arguments to its finalize block.
+    /// Note that the finalize block itself is not run.
+    pub(crate) fn call_function(&mut self, function: &'a Function, arguments: Vec<Value>) -> Result<Return> {
+        self.call_block(&function.input, &function.block, arguments)
+    }
+
+    /// Calls the transition with the given arguments, followed by its finalize block, if it has one.
+    /// Returns the output of the transition and the changes made to the mappings by the finalize block.
+    /// If the finalize block fails, then any changes it made to the mappings are reverted.
+    pub(crate) fn call_transition(
+        &mut self,
+        function: &'a Function,
+        arguments: Vec<Value>,
+    ) -> Result<(Value, Vec<MappingUpdate>)> {
+        let (output, finalize_arguments) = self.call_function(function, arguments)?;
+
+        let updates = match (&function.finalize, finalize_arguments) {
+            (Some(finalize), Some(arguments)) => {
+                let mappings = self.mappings.clone();
+                if let Err(err) = self.call_block(&finalize.input, &finalize.block, arguments) {
+                    self.mappings = mappings;
+                    return Err(err);
+                }
+                self.updates_since(&mappings)
+            }
+            _ => Vec::new(),
+        };
+
+        Ok((output, updates))
+    }
+
+    /// Evaluates the body of a function or finalize block in a new scope, with the inputs bound to the arguments.
+    fn call_block(&mut self, inputs: &'a [Input], block: &'a Block, arguments: Vec<Value>) -> Result<Return> {
+        let scope = inputs
+            .iter()
+            .map(|input| input.identifier().name)
+            .zip(arguments)
+            .collect();
+        let caller_scopes = std::mem::replace(&mut self.scopes, vec![scope]);
+
+        let result = self.evaluate_block(block);
+
+        self.scopes = caller_scopes;
+
+        // A function without a return statement returns the unit value.
+        Ok(result?.unwrap_or_else(|| (Value::Tuple(Vec::new()), None)))
+    }
+
+    /// Returns the value of the variable or constant with the given name.
+    pub(crate) fn lookup_variable(&self, name: Symbol) -> &Value {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&name))
+            .or_else(|| self.constants.get(&name))
+            .expect("Type checking guarantees that variables are defined before they are used.")
+    }
+
+    /// Updates the value of the variable with the given name, in the innermost scope that defines it.
+    pub(crate) fn update_variable(&mut self, name: Symbol, value: Value) {
+        let variable = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(&name))
+            .expect("Type checking guarantees that variables are defined before they are assigned.");
+        *variable = value;
+    }
+
+    /// Returns the entry of the mapping for the given key, if it exists.
+    pub(crate) fn lookup_entry(&self, mapping: Symbol, key: &Value) -> Option<&Value> {
+        self.mappings[&mapping].get(&format_value(key)).map(|(_, value)| value)
+    }
+
+    /// Sets the entry of the mapping for the given key.
+    pub(crate) fn set_entry(&mut self, mapping: Symbol, key: Value, value: Value) {
+        self.mappings[&mapping].insert(format_value(&key), (key, value));
+    }
+
+    /// Removes the entry of the mapping for the given key.
+    pub(crate) fn remove_entry(&mut self, mapping: Symbol, key: &Value) {
+        self.mappings[&mapping].shift_remove(&format_value(key));
+    }
+
+    /// Returns the changes made to the mappings since they had the given contents.
+    fn updates_since(&self, previous: &IndexMap<Symbol, MappingContents>) -> Vec<MappingUpdate> {
+        let mut updates = Vec::new();
+        for (mapping, contents) in self.mappings.iter() {
+            let previous = &previous[mapping];
+            // Collect the entries that were added or modified.
+            for (key, (key_value, value)) in contents.iter() {
+                let old = previous.get(key).map(|(_, value)| value);
+                if !old.map_or(false, |old| values_equal(old, value)) {
+                    updates.push(MappingUpdate {
+                        mapping: *mapping,
+                        key: key_value.clone(),
+                        old: old.cloned(),
+                        new: Some(value.clone()),
+                    });
+                }
+            }
+            // Collect the entries that were removed.
+            for (key, (key_value, value)) in previous.iter() {
+                if !contents.contains_key(key) {
+                    updates.push(MappingUpdate {
+                        mapping: *mapping,
+                        key: key_value.clone(),
+                        old: Some(value.clone()),
+                        new: None,
+                    });
+                }
+            }
+        }
+        updates
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The interpreter for Leo programs.
+//!
+//! The [`Interpreter`] evaluates the transitions of a type-checked program directly from its AST.
+
+#![forbid(unsafe_code)]
+#![doc = include_str!("../README.md")]
+
+mod evaluate_expressions;
+
+mod evaluate_statements;
+
+pub mod input;
+pub use input::*;
+
+pub mod interpreter;
+pub use interpreter::*;
+
+pub mod value;
+pub use value::*;
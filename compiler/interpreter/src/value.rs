@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    BinaryExpression, BinaryOperation, CastExpression, GroupLiteral, IntegerType, Literal, Type, UnaryExpression,
+    UnaryOperation, Value,
+};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Span;
+
+use itertools::Itertools;
+use snarkvm_console::{
+    network::Testnet3,
+    program::{
+        Compare, Double, Inverse, Literal as ConsoleLiteral, LiteralType, Plaintext, Pow, Square, SquareRoot, Zero,
+    },
+};
+use std::str::FromStr;
+
+pub(crate) type Network = Testnet3;
+
+/// Formats a value as an Aleo value, e.g. `1u32` or `{ x: 1u8, y: 2u8 }`.
+/// Field, group, and scalar values are written in their canonical form, so that values of the same type are equal
+/// if and only if their formatted strings are equal. Mappings rely on this to index their entries.
+pub fn format_value(value: &Value) -> String {
+    match value {
+        Value::Struct(_, members) => format!(
+            "{{ {} }}",
+            members
+                .iter()
+                .map(|(name, member)| format!("{name}: {}", format_value(member)))
+                .join(", ")
+        ),
+        Value::Array(elements) => format!("[{}]", elements.iter().map(format_value).join(", ")),
+        Value::Tuple(elements) => format!("({})", elements.iter().map(format_value).join(", ")),
+        Value::Input(..) => unreachable!("The interpreter does not produce input values."),
+        value => match (to_literal(value), Literal::try_from(value.clone())) {
+            (Some(literal), _) => literal.to_string(),
+            (None, Ok(literal)) => literal.to_string(),
+            (None, Err(_)) => unreachable!("The values that cannot be written as literals are formatted above."),
+        },
+    }
+}
+
+/// Returns whether two values of the same type are equal.
+/// Structs, arrays, and tuples are compared member by member, and primitives are compared as snarkVM literals.
+pub fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Struct(_, left), Value::Struct(_, right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .all(|(name, left)| right.get(name).map_or(false, |right| values_equal(left, right)))
+        }
+        (Value::Array(left), Value::Array(right)) | (Value::Tuple(left), Value::Tuple(right)) => {
+            left.len() == right.len() && left.iter().zip(right).all(|(left, right)| values_equal(left, right))
+        }
+        (left, right) => match (to_literal(left), to_literal(right)) {
+            (Some(left), Some(right)) => left == right,
+            // Affine group literals are not parsed by snarkVM, so they are compared as written.
+            _ => format_value(left) == format_value(right),
+        },
+    }
+}
+
+/// Converts a primitive value to a snarkVM literal.
+/// Returns `None` for composite values, strings, and affine group literals, e.g. `(0, 1)group`.
+pub(crate) fn to_literal(value: &Value) -> Option<ConsoleLiteral<Network>> {
+    let string = match value {
+        Value::Struct(..) | Value::Array(..) | Value::Tuple(..) | Value::Input(..) | Value::String(..) => return None,
+        Value::Group(group) => match group.as_ref() {
+            GroupLiteral::Single(number, _) => format!("{}group", trim_leading_zeros(number)),
+            GroupLiteral::Tuple(_) => return None,
+        },
+        Value::Field(number, _) => format!("{}field", trim_leading_zeros(number)),
+        Value::Scalar(number, _) => format!("{}scalar", trim_leading_zeros(number)),
+        value => Literal::try_from(value.clone()).ok()?.to_string(),
+    };
+    ConsoleLiteral::from_str(&string).ok()
+}
+
+/// Removes the leading zeros of a number, which snarkVM does not parse, e.g. `-007` becomes `-7`.
+fn trim_leading_zeros(number: &str) -> String {
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number),
+    };
+    match digits.trim_start_matches('0') {
+        "" => "0".to_string(),
+        digits => format!("{sign}{digits}"),
+    }
+}
+
+/// Converts a value to a snarkVM plaintext, e.g. to hash it.
+pub(crate) fn to_plaintext(value: &Value) -> Option<Plaintext<Network>> {
+    match value {
+        Value::Struct(..) | Value::Array(..) => Plaintext::from_str(&format_value(value)).ok(),
+        value => to_literal(value).map(Plaintext::from),
+    }
+}
+
+/// Converts a snarkVM literal to a value.
+pub(crate) fn from_literal(literal: ConsoleLiteral<Network>, span: Span) -> Value {
+    let string = literal.to_string();
+    let strip = |suffix: &str| string.trim_end_matches(suffix).to_string();
+    match literal {
+        ConsoleLiteral::Address(_) => Value::Address(string, span),
+        ConsoleLiteral::Boolean(boolean) => Value::Boolean(*boolean, span),
+        ConsoleLiteral::Field(_) => Value::Field(strip("field"), span),
+        ConsoleLiteral::Group(_) => Value::Group(Box::new(GroupLiteral::Single(strip("group"), span))),
+        ConsoleLiteral::I8(integer) => Value::I8(*integer, span),
+        ConsoleLiteral::I16(integer) => Value::I16(*integer, span),
+        ConsoleLiteral::I32(integer) => Value::I32(*integer, span),
+        ConsoleLiteral::I64(integer) => Value::I64(*integer, span),
+        ConsoleLiteral::I128(integer) => Value::I128(*integer, span),
+        ConsoleLiteral::U8(integer) => Value::U8(*integer, span),
+        ConsoleLiteral::U16(integer) => Value::U16(*integer, span),
+        ConsoleLiteral::U32(integer) => Value::U32(*integer, span),
+        ConsoleLiteral::U64(integer) => Value::U64(*integer, span),
+        ConsoleLiteral::U128(integer) => Value::U128(*integer, span),
+        ConsoleLiteral::Scalar(_) => Value::Scalar(strip("scalar"), span),
+        ConsoleLiteral::String(_) => Value::String(string, span),
+        ConsoleLiteral::Signature(_) => unreachable!("Leo does not have a `signature` type."),
+    }
+}
+
+/// Returns the snarkVM literal type of a primitive type.
+pub(crate) fn literal_type(type_: &Type) -> Option<LiteralType> {
+    Some(match type_ {
+        Type::Address => LiteralType::Address,
+        Type::Boolean => LiteralType::Boolean,
+        Type::Field => LiteralType::Field,
+        Type::Group => LiteralType::Group,
+        Type::Integer(IntegerType::I8) => LiteralType::I8,
+        Type::Integer(IntegerType::I16) => LiteralType::I16,
+        Type::Integer(IntegerType::I32) => LiteralType::I32,
+        Type::Integer(IntegerType::I64) => LiteralType::I64,
+        Type::Integer(IntegerType::I128) => LiteralType::I128,
+        Type::Integer(IntegerType::U8) => LiteralType::U8,
+        Type::Integer(IntegerType::U16) => LiteralType::U16,
+        Type::Integer(IntegerType::U32) => LiteralType::U32,
+        Type::Integer(IntegerType::U64) => LiteralType::U64,
+        Type::Integer(IntegerType::U128) => LiteralType::U128,
+        Type::Scalar => LiteralType::Scalar,
+        Type::String => LiteralType::String,
+        _ => return None,
+    })
+}
+
+/// Evaluates a binary operation on `field`, `group`, or `scalar` values, as snarkVM does.
+/// Returns `None` if snarkVM does not define the operation on the values.
+pub(crate) fn field_binary(input: &BinaryExpression, left: &Value, right: &Value) -> Result<Option<Value>> {
+    use ConsoleLiteral::*;
+
+    let (left, right) = match (to_literal(left), to_literal(right)) {
+        (Some(left), Some(right)) => (left, right),
+        _ => return Ok(None),
+    };
+    let result = match (input.op, left, right) {
+        (BinaryOperation::Add, Field(left), Field(right)) => Field(left + right),
+        (BinaryOperation::Add, Group(left), Group(right)) => Group(left + right),
+        (BinaryOperation::Add, Scalar(left), Scalar(right)) => Scalar(left + right),
+        (BinaryOperation::Sub, Field(left), Field(right)) => Field(left - right),
+        (BinaryOperation::Sub, Group(left), Group(right)) => Group(left - right),
+        (BinaryOperation::Sub, Scalar(left), Scalar(right)) => Scalar(left - right),
+        (BinaryOperation::Mul, Field(left), Field(right)) => Field(left * right),
+        (BinaryOperation::Mul, Group(left), Scalar(right)) => Group(left * right),
+        (BinaryOperation::Mul, Scalar(left), Group(right)) => Group(left * right),
+        (BinaryOperation::Div, Field(_), Field(right)) if right.is_zero() => {
+            return Err(InterpreterError::operation_halted(input, "division by zero", input.span).into());
+        }
+        (BinaryOperation::Div, Field(left), Field(right)) => Field(left / right),
+        (BinaryOperation::Pow, Field(left), Field(right)) => Field(left.pow(right)),
+        (BinaryOperation::Lt, Field(left), Field(right)) => Boolean(left.is_less_than(&right)),
+        (BinaryOperation::Lt, Scalar(left), Scalar(right)) => Boolean(left.is_less_than(&right)),
+        (BinaryOperation::Lte, Field(left), Field(right)) => Boolean(left.is_less_than_or_equal(&right)),
+        (BinaryOperation::Lte, Scalar(left), Scalar(right)) => Boolean(left.is_less_than_or_equal(&right)),
+        (BinaryOperation::Gt, Field(left), Field(right)) => Boolean(left.is_greater_than(&right)),
+        (BinaryOperation::Gt, Scalar(left), Scalar(right)) => Boolean(left.is_greater_than(&right)),
+        (BinaryOperation::Gte, Field(left), Field(right)) => Boolean(left.is_greater_than_or_equal(&right)),
+        (BinaryOperation::Gte, Scalar(left), Scalar(right)) => Boolean(left.is_greater_than_or_equal(&right)),
+        _ => return Ok(None),
+    };
+    Ok(Some(from_literal(result, input.span)))
+}
+
+/// Evaluates a unary operation on a `field` or `group` value, as snarkVM does.
+/// Returns `None` if snarkVM does not define the operation on the value.
+pub(crate) fn field_unary(input: &UnaryExpression, receiver: &Value) -> Result<Option<Value>> {
+    use ConsoleLiteral::*;
+
+    let receiver = match to_literal(receiver) {
+        Some(receiver) => receiver,
+        None => return Ok(None),
+    };
+    let halt = |reason: &str| InterpreterError::operation_halted(input, reason, input.span);
+    let result = match (input.op, receiver) {
+        (UnaryOperation::Double, Field(receiver)) => Field(receiver.double()),
+        (UnaryOperation::Double, Group(receiver)) => Group(receiver.double()),
+        (UnaryOperation::Inverse, Field(receiver)) => Field(
+            receiver
+                .inverse()
+                .map_err(|_| halt("the inverse of zero is undefined"))?,
+        ),
+        (UnaryOperation::Negate, Field(receiver)) => Field(-receiver),
+        (UnaryOperation::Negate, Group(receiver)) => Group(-receiver),
+        (UnaryOperation::Square, Field(receiver)) => Field(receiver.square()),
+        (UnaryOperation::SquareRoot, Field(receiver)) => {
+            Field(receiver.square_root().map_err(|_| halt("the value is not a square"))?)
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(from_literal(result, input.span)))
+}
+
+/// Casts a value to the given type, as snarkVM does, truncating the value if the cast is lossy.
+/// Returns `None` if snarkVM does not define the cast.
+pub(crate) fn console_cast(input: &CastExpression, value: &Value) -> Result<Option<Value>> {
+    match (to_literal(value), literal_type(&input.type_)) {
+        (Some(literal), Some(literal_type)) => {
+            let result = match input.lossy.get() {
+                true => literal.cast_lossy(literal_type),
+                false => literal.cast(literal_type),
+            };
+            match result {
+                Ok(literal) => Ok(Some(from_literal(literal, input.span))),
+                Err(error) => Err(InterpreterError::operation_halted(input, error, input.span).into()),
+            }
+        }
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, LeoError};
+use leo_interpreter::{format_value, Interpreter};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+use leo_test_framework::{
+    runner::{Namespace, ParseType, Runner},
+    test::TestExpectationMode,
+    Test,
+};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// The address used as `self.caller` in the tests.
+const CALLER: &str = "aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px";
+
+struct InterpretNamespace;
+
+impl Namespace for InterpretNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let (handler, buf) = Handler::new_with_buf();
+
+        create_session_if_not_set_then(|_| {
+            handler
+                .extend_if_error(run_test(test, &handler))
+                .map_err(|()| buf.extract_errs().to_string())
+        })
+    }
+}
+
+#[derive(Deserialize, PartialEq, Eq, Serialize)]
+struct InterpretOutput {
+    pub results: BTreeMap<String, Vec<BTreeMap<String, String>>>,
+}
+
+fn run_test(test: Test, handler: &Handler) -> Result<Value, LeoError> {
+    // Check that config expectation is always pass.
+    assert_eq!(
+        test.config.expectation,
+        TestExpectationMode::Pass,
+        "Test expectation must be `Pass` for `Interpret` tests."
+    );
+
+    // Parse and type check the program.
+    let mut compiler = Compiler::new(
+        String::from("test"),
+        String::from("aleo"),
+        handler,
+        PathBuf::from("compiler-test"),
+        PathBuf::from("/tmp/output/"),
+        None,
+    );
+    compiler.parse_program_from_string(&test.content, FileName::Custom("compiler-test".into()))?;
+    compiler.front_end_passes()?;
+
+    // Extract the cases from the test config.
+    let all_cases = test
+        .config
+        .extra
+        .get("cases")
+        .expect("An `Interpret` config must have a `cases` field.")
+        .as_mapping()
+        .unwrap();
+
+    // Note that the mappings persist across the cases, so that later cases observe the updates of earlier ones.
+    let mut interpreter = Interpreter::new(&compiler.ast.ast, CALLER.to_string())?;
+
+    // Run each test case for each function.
+    let mut results = BTreeMap::new();
+    for (function_name, function_cases) in all_cases {
+        let function_name = function_name.as_str().unwrap();
+        let cases = function_cases.as_sequence().unwrap();
+        let mut function_results = Vec::with_capacity(cases.len());
+
+        for case in cases {
+            let inputs: Vec<String> = case
+                .as_mapping()
+                .unwrap()
+                .get(&Value::from("input"))
+                .unwrap()
+                .as_sequence()
+                .unwrap()
+                .iter()
+                .map(|input| input.as_str().unwrap().to_string())
+                .collect();
+
+            let mut result = BTreeMap::new();
+            result.insert("input".to_string(), format!("[{}]", inputs.iter().join(", ")));
+            match interpreter.run(function_name, &inputs) {
+                Ok(execution) => {
+                    result.insert(
+                        "output".to_string(),
+                        format!("[{}]", execution.outputs.iter().map(format_value).join(", ")),
+                    );
+                    if !execution.updates.is_empty() {
+                        result.insert("updates".to_string(), execution.updates.iter().join(", "));
+                    }
+                }
+                Err(err) => {
+                    result.insert("output".to_string(), err.to_string());
+                }
+            }
+            function_results.push(result);
+        }
+        results.insert(function_name.to_string(), function_results);
+    }
+
+    Ok(serde_yaml::to_value(InterpretOutput { results }).expect("serialization failed"))
+}
+
+struct TestRunner;
+
+impl Runner for TestRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Interpret" => Box::new(InterpretNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn interpreter_tests() {
+    leo_test_framework::run_tests(&TestRunner, "interpreter");
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter to re-emit Leo code text in a canonical style.
+//!
+//! The formatter works on the token stream produced by the tokenizer, so comments are kept exactly where they appear.
+//! The parser is run first, both to reject malformed programs and to tell struct initializer braces apart from block braces.
+
+mod struct_initializers;
+use struct_initializers::*;
+
+mod printer;
+use printer::*;
+
+use crate::{tokenizer, Token};
+
+use leo_ast::ProgramVisitor;
+use leo_errors::{emitter::Handler, ParserError, Result};
+use leo_span::span::BytePos;
+
+use std::collections::HashSet;
+
+/// Formats the given source code text, returning the canonical text of the program.
+pub fn format(handler: &Handler, source: &str, start_pos: BytePos) -> Result<String> {
+    // Parse the program, reporting any syntax errors before formatting.
+    // Note that the parser recovers from syntax errors, so the recovered errors must be checked as well.
+    let err_count = handler.err_count();
+    let program = crate::parser::parse(handler, source, start_pos)?;
+    if handler.err_count() > err_count {
+        handler.last_err().map_err(|e| *e)?;
+    }
+    let mut struct_initializers = StructInitializerCollector::default();
+    struct_initializers.visit_program(&program);
+
+    format_tokens(source, start_pos, struct_initializers.braces)
+}
+
+/// Formats the given source code text of a module, returning the canonical text of the module.
+pub fn format_module(handler: &Handler, source: &str, start_pos: BytePos) -> Result<String> {
+    // Parse the module, reporting any syntax errors before formatting.
+    let err_count = handler.err_count();
+    let module = crate::parser::parse_module(handler, source, start_pos)?;
+    if handler.err_count() > err_count {
+        handler.last_err().map_err(|e| *e)?;
+    }
+    let mut struct_initializers = StructInitializerCollector::default();
+    module
+        .consts
+        .values()
+        .for_each(|const_| struct_initializers.visit_const(const_));
+    module
+        .functions
+        .values()
+        .for_each(|function| struct_initializers.visit_function(function));
+
+    format_tokens(source, start_pos, struct_initializers.braces)
+}
+
+/// Formats the tokens of the given source code text, given the end positions of the names of its struct initializers.
+fn format_tokens(source: &str, start_pos: BytePos, braces: HashSet<BytePos>) -> Result<String> {
+    let tokens = tokenizer::tokenize(source, start_pos)?;
+
+    let formatted = Printer::new(source, start_pos, braces).print(&tokens);
+
+    // Formatting may only change whitespace, so the token streams must match.
+    let reformatted = tokenizer::tokenize(&formatted, BytePos(0))?;
+    let same_tokens = tokens.len() == reformatted.len()
+        && tokens
+            .iter()
+            .zip(reformatted.iter())
+            .all(|(left, right)| match (&left.token, &right.token) {
+                (Token::CommentLine(left), Token::CommentLine(right)) => left.trim_end() == right.trim_end(),
+                (left, right) => left == right,
+            });
+    match same_tokens {
+        true => Ok(formatted),
+        false => Err(ParserError::formatter_changed_tokens().into()),
+    }
+}
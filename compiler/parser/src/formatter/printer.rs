@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{type_::TYPE_TOKENS, SpannedToken, Token};
+
+use leo_span::span::BytePos;
+
+use std::collections::HashSet;
+
+/// The number of spaces per level of indentation.
+const INDENT_WIDTH: usize = 4;
+
+/// The kinds of delimited token groups.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Delimited {
+    /// A block, a program scope, or a struct declaration.
+    Block,
+    /// A struct initializer.
+    Initializer,
+    /// `( ... )`
+    Parenthesis,
+    /// `[ ... ]`
+    Bracket,
+}
+
+/// A delimited token group that is currently open.
+#[derive(Clone, Copy)]
+struct Frame {
+    group: Delimited,
+    /// True if the contents of the group are broken across lines, one element per line.
+    /// Blocks are always vertical, other groups are vertical if their last element has a trailing comma.
+    vertical: bool,
+    /// The number of ternary expressions in the group that are still waiting for their `:`.
+    open_ternaries: usize,
+}
+
+impl Frame {
+    fn new(group: Delimited, vertical: bool) -> Self {
+        Self {
+            group,
+            vertical,
+            open_ternaries: 0,
+        }
+    }
+}
+
+/// The whitespace emitted between two tokens.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Separator {
+    None,
+    Space,
+    Newline,
+    BlankLine,
+}
+
+/// Prints a token stream in the canonical Leo style.
+pub(crate) struct Printer<'a> {
+    /// The source code text the tokens were produced from.
+    source: &'a str,
+    /// The position of the first byte of `source`.
+    start_pos: BytePos,
+    /// The end positions of the names of struct initializers, see `StructInitializerCollector`.
+    struct_initializers: HashSet<BytePos>,
+    /// The formatted text.
+    output: String,
+    /// The open delimited groups; the first frame is the top level of the file.
+    frames: Vec<Frame>,
+    /// True if the next token must start on a new line.
+    newline_pending: bool,
+    /// True if the next token must be preceded by a blank line.
+    blank_line_pending: bool,
+    /// The last token that is not a comment.
+    last_code: Option<Token>,
+    /// The end position of `last_code`.
+    last_code_end: BytePos,
+    /// True if the last code token ends an operand, meaning that a following `-` is a binary operator.
+    after_operand: bool,
+    /// True if the last code token must be glued to the next one, e.g. a unary operator.
+    glue_next: bool,
+}
+
+impl<'a> Printer<'a> {
+    pub(crate) fn new(source: &'a str, start_pos: BytePos, struct_initializers: HashSet<BytePos>) -> Self {
+        Self {
+            source,
+            start_pos,
+            struct_initializers,
+            output: String::with_capacity(source.len()),
+            frames: vec![Frame::new(Delimited::Block, true)],
+            newline_pending: false,
+            blank_line_pending: false,
+            last_code: None,
+            last_code_end: start_pos,
+            after_operand: false,
+            glue_next: false,
+        }
+    }
+
+    /// Prints the tokens, consuming the printer and returning the formatted text.
+    pub(crate) fn print(mut self, tokens: &[SpannedToken]) -> String {
+        let trailing_commas = groups_with_trailing_comma(tokens);
+
+        let mut previous: Option<&SpannedToken> = None;
+        for (index, token) in tokens.iter().enumerate() {
+            // A closing delimiter is indented at the level of its opening delimiter.
+            let closed = match token.token {
+                Token::RightCurly | Token::RightParen | Token::RightSquare if self.frames.len() > 1 => {
+                    self.frames.pop()
+                }
+                _ => None,
+            };
+
+            // A line break that is not required by the layout continues the current statement.
+            let continuation = !self.newline_pending
+                && closed.is_none()
+                && !matches!(
+                    self.last_code,
+                    None | Some(Token::Semicolon | Token::LeftCurly | Token::RightCurly | Token::Comma)
+                );
+            let separator = match previous {
+                None => Separator::None,
+                Some(previous) => self.separator(previous, token, closed),
+            };
+            self.emit_separator(separator, continuation);
+            self.emit_token(token, closed, trailing_commas.contains(&index));
+
+            previous = Some(token);
+        }
+
+        let trimmed = self.output.trim_end().len();
+        self.output.truncate(trimmed);
+        self.output.push('\n');
+        self.output
+    }
+
+    /// Returns the source code text of a token.
+    fn text(&self, token: &SpannedToken) -> &'a str {
+        let lo = (token.span.lo.0 - self.start_pos.0) as usize;
+        let hi = (token.span.hi.0 - self.start_pos.0) as usize;
+        &self.source[lo..hi]
+    }
+
+    /// Returns the number of line breaks in the source code text between two tokens.
+    fn line_breaks_between(&self, previous: &SpannedToken, current: &SpannedToken) -> usize {
+        let lo = (previous.span.hi.0 - self.start_pos.0) as usize;
+        let hi = (current.span.lo.0 - self.start_pos.0) as usize;
+        // A line comment includes the line break that ends it.
+        let comment_line_break = matches!(&previous.token, Token::CommentLine(text) if text.ends_with('\n'));
+        self.source[lo..hi].matches('\n').count() + comment_line_break as usize
+    }
+
+    /// Returns the innermost open group.
+    fn frame(&mut self) -> &mut Frame {
+        // The top level frame is never popped, so there is always one.
+        self.frames.last_mut().unwrap()
+    }
+
+    /// Returns the whitespace to emit between the `previous` and `current` tokens.
+    fn separator(&mut self, previous: &SpannedToken, current: &SpannedToken, closed: Option<Frame>) -> Separator {
+        let line_breaks = self.line_breaks_between(previous, current);
+        let closed_vertical = closed.map_or(false, |frame| frame.vertical);
+
+        let separator = match (&previous.token, &current.token) {
+            // Comments stay on the line they were written on: either trailing the previous token or on their own line.
+            (_, Token::CommentLine(_) | Token::CommentBlock(_)) if line_breaks == 0 => return Separator::Space,
+            (_, Token::CommentLine(_) | Token::CommentBlock(_)) => Separator::Newline,
+            (Token::CommentLine(_), _) => Separator::Newline,
+            (Token::CommentBlock(_), _) if line_breaks > 0 => Separator::Newline,
+            // An empty block stays on one line.
+            (Token::LeftCurly, Token::RightCurly) => return Separator::None,
+            (Token::RightCurly, Token::Else) => {
+                self.newline_pending = false;
+                self.blank_line_pending = false;
+                return Separator::Space;
+            }
+            _ if self.newline_pending || closed_vertical => Separator::Newline,
+            // The `program` declaration is set apart from the imports before it.
+            (_, Token::Program) if self.frames.len() == 1 => Separator::BlankLine,
+            _ if self.is_spaced(previous, current) => Separator::Space,
+            _ => Separator::None,
+        };
+
+        let opened = matches!(previous.token, Token::LeftCurly | Token::LeftParen | Token::LeftSquare);
+        match separator {
+            Separator::Newline if self.blank_line_pending && !closed_vertical => Separator::BlankLine,
+            // Keep a single blank line where the source has one, except at the start or end of a group.
+            Separator::Newline if line_breaks > 1 && !closed_vertical && !opened => Separator::BlankLine,
+            separator => separator,
+        }
+    }
+
+    /// Returns `true` if two code tokens on the same line are separated by a space.
+    fn is_spaced(&mut self, previous: &SpannedToken, current: &SpannedToken) -> bool {
+        use Token::*;
+
+        if self.glue_next {
+            return false;
+        }
+        match (&previous.token, &current.token) {
+            // The colon of a ternary expression is spaced like a binary operator.
+            (_, Colon) => self.frame().open_ternaries > 0,
+            (_, Comma | Semicolon | RightParen | RightSquare | Dot | DotDot | DoubleColon) => false,
+            (LeftParen | LeftSquare | Dot | DotDot | DoubleColon | At, _) => false,
+            // Calls, e.g. `foo(a)`, `assert_eq(a, b)`, and `finalize(a)`.
+            (Identifier(_) | Assert | AssertEq | AssertNeq | Increment | Decrement | Finalize, LeftParen) => false,
+            // Indexing, e.g. `a[0u32]`.
+            (_, LeftSquare) => !self.after_operand,
+            // The type suffix of a literal, e.g. `1u8` and `(0, 1)group`.
+            (Integer(_), _) if TYPE_TOKENS.contains(&current.token) => false,
+            (RightParen, Group) => false,
+            _ => true,
+        }
+    }
+
+    /// Emits the whitespace between two tokens.
+    /// Continuation lines are indented one level deeper than the statement they continue.
+    fn emit_separator(&mut self, separator: Separator, continuation: bool) {
+        match separator {
+            Separator::None => {}
+            Separator::Space => self.output.push(' '),
+            Separator::Newline | Separator::BlankLine => {
+                let trimmed = self.output.trim_end_matches(' ').len();
+                self.output.truncate(trimmed);
+                self.output.push('\n');
+                if separator == Separator::BlankLine {
+                    self.output.push('\n');
+                }
+                let indent = (self.frames.len() - 1 + continuation as usize) * INDENT_WIDTH;
+                self.output.extend(std::iter::repeat(' ').take(indent));
+                self.newline_pending = false;
+                self.blank_line_pending = false;
+            }
+        }
+    }
+
+    /// Emits a token and updates the layout state.
+    /// `trailing_comma` is true if the token opens a group whose last element has a trailing comma.
+    fn emit_token(&mut self, token: &SpannedToken, closed: Option<Frame>, trailing_comma: bool) {
+        use Token::*;
+
+        match &token.token {
+            CommentLine(text) => {
+                self.output.push_str(text.trim_end());
+                return;
+            }
+            CommentBlock(_) => {
+                self.output.push_str(self.text(token));
+                return;
+            }
+            _ => self.output.push_str(self.text(token)),
+        }
+
+        let was_after_operand = self.after_operand;
+        let was_annotation = self.last_code == Some(At);
+        self.glue_next = false;
+        self.after_operand = match &token.token {
+            Identifier(_) | Integer(_) | True | False | AddressLit(_) | StaticString(_) | SelfLower | RightParen
+            | RightSquare => true,
+            RightCurly => closed.map_or(false, |frame| frame.group == Delimited::Initializer),
+            token => TYPE_TOKENS.contains(token),
+        };
+
+        match &token.token {
+            LeftCurly | LeftParen | LeftSquare => {
+                let group = match &token.token {
+                    LeftParen => Delimited::Parenthesis,
+                    LeftSquare => Delimited::Bracket,
+                    _ if self.struct_initializers.contains(&self.last_code_end) => Delimited::Initializer,
+                    _ => Delimited::Block,
+                };
+                let vertical = group == Delimited::Block || trailing_comma;
+                self.newline_pending = vertical;
+                self.frames.push(Frame::new(group, vertical));
+            }
+            RightCurly if closed.map_or(false, |frame| frame.group == Delimited::Block) => {
+                self.newline_pending = true;
+                // Declarations in a program scope are separated by blank lines.
+                self.blank_line_pending = self.frames.len() == 2;
+            }
+            Semicolon if self.frame().group == Delimited::Block => self.newline_pending = true,
+            Comma if self.frame().vertical => self.newline_pending = true,
+            Question => self.frame().open_ternaries += 1,
+            Colon if self.frame().open_ternaries > 0 => self.frame().open_ternaries -= 1,
+            At | Not => self.glue_next = true,
+            Sub if !was_after_operand => self.glue_next = true,
+            Identifier(_) | Program if was_annotation => self.newline_pending = true,
+            _ => {}
+        }
+
+        self.last_code = Some(token.token.clone());
+        self.last_code_end = token.span.hi;
+    }
+}
+
+/// Returns the indices of the opening delimiters whose groups end with a trailing comma.
+fn groups_with_trailing_comma(tokens: &[SpannedToken]) -> HashSet<usize> {
+    let mut open = Vec::new();
+    let mut groups = HashSet::new();
+    for (index, token) in tokens.iter().enumerate() {
+        match token.token {
+            Token::LeftCurly | Token::LeftParen | Token::LeftSquare => open.push(index),
+            Token::RightCurly | Token::RightParen | Token::RightSquare => {
+                if let Some(start) = open.pop() {
+                    let last = tokens[start + 1..index]
+                        .iter()
+                        .rev()
+                        .find(|token| !matches!(token.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+                    if matches!(last.map(|token| &token.token), Some(Token::Comma)) {
+                        groups.insert(start);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    groups
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{ExpressionVisitor, ProgramVisitor, StatementVisitor, StructExpression};
+use leo_span::span::BytePos;
+
+use std::collections::HashSet;
+
+/// Collects the positions of the struct initializer braces in a program.
+///
+/// The braces of a struct initializer stay on one line, whereas the braces of blocks and declarations are broken across lines.
+/// The opening brace of an initializer directly follows the struct name, so the end of the name identifies it.
+#[derive(Default)]
+pub(crate) struct StructInitializerCollector {
+    /// The end positions of the names of all struct initializers.
+    pub(crate) braces: HashSet<BytePos>,
+}
+
+impl<'a> ExpressionVisitor<'a> for StructInitializerCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.braces.insert(input.name.span.hi);
+        input
+            .members
+            .iter()
+            .filter_map(|member| member.expression.as_ref())
+            .for_each(|expression| self.visit_expression(expression, &()));
+    }
+}
+
+impl<'a> StatementVisitor<'a> for StructInitializerCollector {}
+
+impl<'a> ProgramVisitor<'a> for StructInitializerCollector {}
@@ -43,6 +43,34 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) allow_identifier_underscores: bool,
 }
 
+/// The tokens that start an item in a program scope, at which the parser resumes after a syntax error.
+const ITEM_TOKENS: &[Token] = &[
+    Token::At,
+    Token::Enum,
+    Token::Function,
+    Token::Inline,
+    Token::Mapping,
+    Token::Record,
+    Token::Struct,
+    Token::Transition,
+];
+
+/// The keywords that start a statement, at which the parser resumes after a statement with a missing `;`.
+/// Note that the parser of each of these statements consumes the keyword, so resuming here always makes progress.
+const STATEMENT_TOKENS: &[Token] = &[
+    Token::Assert,
+    Token::AssertEq,
+    Token::AssertNeq,
+    Token::Const,
+    Token::Decrement,
+    Token::For,
+    Token::If,
+    Token::Increment,
+    Token::Let,
+    Token::Match,
+    Token::Return,
+];
+
 /// Dummy span used to appease borrow checker.
 const DUMMY_EOF: SpannedToken = SpannedToken {
     token: Token::Eof,
@@ -131,6 +159,71 @@ impl<'a> ParserContext<'a> {
         self.handler.emit_warning(warning.into());
     }
 
+    /// Skips the tokens of a statement with a syntax error, so that parsing can resume at the next statement.
+    /// Returns `true` if the end of the statement was found, i.e. a `;`, a `}` closing a block, or the start of the next statement.
+    /// Returns `false` if the end of the file or an item was found first, since the enclosing block is unclosed.
+    pub(super) fn recover_statement(&mut self) -> bool {
+        let mut depth = 0usize;
+        loop {
+            match &self.token.token {
+                Token::Eof => return false,
+                token if depth == 0 && ITEM_TOKENS.contains(token) => return false,
+                // The `}` closes the enclosing block, so it is left for the caller.
+                Token::RightCurly if depth == 0 => return true,
+                token if depth == 0 && STATEMENT_TOKENS.contains(token) => return true,
+                Token::Semicolon if depth == 0 => {
+                    self.bump();
+                    return true;
+                }
+                // A block that was opened in the statement ends it, e.g. the body of a conditional.
+                Token::RightCurly if depth == 1 => {
+                    self.bump();
+                    self.eat(&Token::Semicolon);
+                    return true;
+                }
+                Token::LeftCurly => depth += 1,
+                Token::RightCurly => depth -= 1,
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
+    /// Skips the tokens of an item with a syntax error, so that parsing can resume at the next item.
+    /// Stops at the start of an item, at the `}` closing the program scope, or at the end of the file.
+    pub(super) fn recover_item(&mut self) {
+        let mut depth = 0usize;
+        loop {
+            match &self.token.token {
+                Token::Eof => return,
+                token if depth == 0 && ITEM_TOKENS.contains(token) => return,
+                // A `const` only starts an item at an item boundary, unlike e.g. a `const` parameter.
+                Token::Const
+                    if depth == 0
+                        && matches!(
+                            self.prev_token.token,
+                            Token::LeftCurly | Token::RightCurly | Token::Semicolon
+                        ) =>
+                {
+                    return
+                }
+                // The `}` closing the program scope is the last token in the scope.
+                Token::RightCurly
+                    if depth == 0
+                        && self.look_ahead(1, |t| matches!(t.token, Token::Eof | Token::Import | Token::Program)) =>
+                {
+                    return
+                }
+                // Otherwise, the `}` closes the item that the error occurred in.
+                Token::RightCurly if depth == 0 => {}
+                Token::LeftCurly => depth += 1,
+                Token::RightCurly => depth -= 1,
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
     /// Returns true if the next token exists.
     pub(crate) fn has_next(&self) -> bool {
         !matches!(self.token.token, Token::Eof)
@@ -142,6 +235,15 @@ impl<'a> ParserContext<'a> {
         Identifier { name, span }
     }
 
+    /// Returns the identifier of the path `module::name`, which names an item declared in a module.
+    /// Note that modules are resolved after parsing, which replaces the paths with the names of the items.
+    pub(super) fn path(module: Identifier, name: Identifier) -> Identifier {
+        Identifier {
+            name: Symbol::intern(&format!("{module}::{name}")),
+            span: module.span + name.span,
+        }
+    }
+
     /// Eats the next token if its an identifier and returns it.
     pub(super) fn eat_identifier(&mut self) -> Option<Identifier> {
         if let Token::Identifier(name) = self.token.token {
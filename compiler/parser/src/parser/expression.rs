@@ -85,6 +85,7 @@ impl ParserContext<'_> {
             op,
             left: Box::new(left),
             right: Box::new(right),
+            operand_type: Default::default(),
         })
     }
 
@@ -224,9 +225,9 @@ impl ParserContext<'_> {
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// binary exponentiation expression.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_unary_expression`].
+    /// Otherwise, tries to parse the next token using [`parse_cast_expression`].
     fn parse_exponential_expression(&mut self) -> Result<Expression> {
-        let mut expr = self.parse_unary_expression()?;
+        let mut expr = self.parse_cast_expression()?;
 
         if let Some(op) = self.eat_bin_op(&[Token::Pow]) {
             let right = self.parse_exponential_expression()?;
@@ -236,6 +237,28 @@ impl ParserContext<'_> {
         Ok(expr)
     }
 
+    /// Returns an [`Expression`] AST node if the next tokens represent a
+    /// cast expression, e.g. `x as u8`.
+    ///
+    /// Otherwise, tries to parse the next token using [`parse_unary_expression`].
+    fn parse_cast_expression(&mut self) -> Result<Expression> {
+        let mut expr = self.parse_unary_expression()?;
+
+        // Casts are left-associative, so `x as u8 as u16` is parsed as `(x as u8) as u16`.
+        while self.eat(&Token::As) {
+            let (type_, end_span) = self.parse_primitive_type()?;
+            expr = Expression::Cast(CastExpression {
+                span: expr.span() + end_span,
+                expression: Box::new(expr),
+                type_,
+                expression_type: Default::default(),
+                lossy: Default::default(),
+            });
+        }
+
+        Ok(expr)
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// unary not, negate, or bitwise not expression.
     ///
@@ -270,6 +293,7 @@ impl ParserContext<'_> {
                     span: op_span + inner.span(),
                     op,
                     receiver: Box::new(inner),
+                    operand_type: Default::default(),
                 }),
             };
         }
@@ -289,6 +313,7 @@ impl ParserContext<'_> {
                 span,
                 op,
                 receiver: Box::new(receiver),
+                operand_type: Default::default(),
             }))
         } else if let (1, Some(op)) = (args.len(), BinaryOperation::from_symbol(method.name)) {
             // Found a binary operator and the argument list contains a single argument.
@@ -297,6 +322,7 @@ impl ParserContext<'_> {
                 op,
                 left: Box::new(receiver),
                 right: Box::new(args.swap_remove(0)),
+                operand_type: Default::default(),
             }))
         } else {
             // Either an invalid unary/binary operator, or more arguments given.
@@ -318,6 +344,13 @@ impl ParserContext<'_> {
         // Parse the struct member name (can be variable or function name).
         let member_name = self.expect_identifier()?;
 
+        // Parse the initialization of a struct declared in a module, e.g. `utils::Point { x: 0u32, y: 0u32 }`.
+        if let Type::Identifier(module) = type_ {
+            if !self.disallow_struct_construction && self.check(&Token::LeftCurly) {
+                return self.parse_struct_init_expression(Self::path(module, member_name));
+            }
+        }
+
         // Check if there are arguments.
         Ok(Expression::Access(if self.check(&Token::LeftParen) {
             // Parse the arguments
@@ -398,6 +431,15 @@ impl ParserContext<'_> {
             } else if self.eat(&Token::DoubleColon) {
                 // Eat a core struct constant or core struct function call.
                 expr = self.parse_associated_access_expression(expr)?;
+            } else if self.eat(&Token::LeftSquare) {
+                // Eat an array access.
+                let index = self.parse_expression()?;
+                let span = expr.span() + self.expect(&Token::RightSquare)?;
+                expr = Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(expr),
+                    index: Box::new(index),
+                    span,
+                }));
             } else if self.check(&Token::LeftParen) {
                 // Parse a function call that's by itself.
                 let (arguments, _, span) = self.parse_paren_comma_list(|p| p.parse_expression().map(Some))?;
@@ -408,14 +450,33 @@ impl ParserContext<'_> {
                     arguments,
                 });
             }
-            // Check if next token is a dot to see if we are calling recursive method.
-            if !self.check(&Token::Dot) {
+            // Check if next token is a dot or a left bracket to see if we are calling recursive method or indexing.
+            if !self.check(&Token::Dot) && !self.check(&Token::LeftSquare) {
                 break;
             }
         }
         Ok(expr)
     }
 
+    /// Returns an [`Expression`] AST node if the next tokens represent an
+    /// array initialization expression, e.g. `[a, b, c]`.
+    fn parse_array_expression(&mut self) -> Result<Expression> {
+        let (elements, _, span) = self.parse_list(Delimiter::Bracket, Some(Token::Comma), |p| {
+            p.parse_expression().map(Some)
+        })?;
+
+        // Arrays must be non-empty.
+        if elements.is_empty() {
+            return Err(ParserError::array_must_have_at_least_one_element(span).into());
+        }
+
+        Ok(Expression::Array(ArrayExpression {
+            elements,
+            type_: Default::default(),
+            span,
+        }))
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// tuple initialization expression or an affine group literal.
     fn parse_tuple_expression(&mut self) -> Result<Expression> {
@@ -521,6 +582,32 @@ impl ParserContext<'_> {
         Ok(StructVariableInitializer { identifier, expression })
     }
 
+    /// Returns a [`MatchExpression`] AST node if the next tokens represent a match expression,
+    /// e.g. `match x { 0u8 => a, _ => b }`.
+    fn parse_match_expression(&mut self) -> Result<Expression> {
+        let start = self.expect(&Token::Match)?;
+        let prior_fuzzy_state = core::mem::replace(&mut self.disallow_struct_construction, true);
+        let expression = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = prior_fuzzy_state;
+
+        let (arms, _, end) = self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| {
+            let pattern = p.parse_match_pattern()?;
+            p.expect(&Token::BigArrow)?;
+            let expression = p.parse_expression()?;
+            Ok(Some(MatchExpressionArm {
+                span: pattern.span() + expression.span(),
+                pattern,
+                expression,
+            }))
+        })?;
+
+        Ok(Expression::Match(MatchExpression {
+            expression: Box::new(expression),
+            arms,
+            span: start + end,
+        }))
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// struct initialization expression.
     /// let foo = Foo { x: 1u8 };
@@ -546,6 +633,10 @@ impl ParserContext<'_> {
     fn parse_primary_expression(&mut self) -> Result<Expression> {
         if let Token::LeftParen = self.token.token {
             return self.parse_tuple_expression();
+        } else if let Token::LeftSquare = self.token.token {
+            return self.parse_array_expression();
+        } else if let Token::Match = self.token.token {
+            return self.parse_match_expression();
         }
 
         let SpannedToken { token, span } = self.token.clone();
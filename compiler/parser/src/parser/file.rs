@@ -15,17 +15,14 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
-use leo_errors::{CompilerError, ParserError, Result};
-use leo_span::source_map::FileName;
-use leo_span::symbol::with_session_globals;
-
-use std::fs;
+use leo_errors::{ParserError, Result};
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     pub fn parse_program(&mut self) -> Result<Program> {
         let mut imports = IndexMap::new();
+        let mut stubs = IndexMap::new();
+        let mut modules = IndexMap::new();
         let mut program_scopes = IndexMap::new();
 
         // TODO: Remove restrictions on multiple program scopes
@@ -34,8 +31,17 @@ impl ParserContext<'_> {
         while self.has_next() {
             match &self.token.token {
                 Token::Import => {
-                    let (id, import) = self.parse_import()?;
-                    imports.insert(id, import);
+                    let (id, import, is_aleo) = self.parse_import()?;
+                    if is_aleo {
+                        stubs.insert(id, import);
+                    } else {
+                        imports.insert(id, import);
+                    }
+                }
+                // Note that `mod` is not a keyword, since it is also the name of the `.mod()` operator.
+                Token::Identifier(sym::Mod) => {
+                    let (id, module) = self.parse_module_declaration()?;
+                    modules.insert(id, module);
                 }
                 Token::Program => {
                     match parsed_program_scope {
@@ -48,7 +54,13 @@ impl ParserContext<'_> {
                         }
                     }
                 }
-                _ => return Err(Self::unexpected_item(&self.token, &[Token::Import, Token::Program]).into()),
+                _ => {
+                    return Err(Self::unexpected_item(
+                        &self.token,
+                        &[Token::Import, Token::Identifier(sym::Mod), Token::Program],
+                    )
+                    .into())
+                }
             }
         }
 
@@ -59,10 +71,49 @@ impl ParserContext<'_> {
 
         Ok(Program {
             imports,
+            stubs,
+            modules,
             program_scopes,
         })
     }
 
+    /// Returns a [`Module`] AST if all tokens can be consumed and represent a valid Leo module.
+    pub fn parse_module(&mut self) -> Result<Module> {
+        let start = self.token.span;
+
+        let mut consts = IndexMap::new();
+        let mut structs = IndexMap::new();
+        let mut functions = IndexMap::new();
+
+        while self.has_next() {
+            let item = match &self.token.token {
+                Token::Const => self.parse_const_declaration().map(|(id, const_)| {
+                    consts.insert(id, const_);
+                }),
+                Token::Struct => self.parse_struct().map(|(id, struct_)| {
+                    structs.insert(id, struct_);
+                }),
+                Token::Inline => self.parse_function().map(|(id, function)| {
+                    functions.insert(id, function);
+                }),
+                _ => Err(Self::unexpected_item(&self.token, &[Token::Const, Token::Struct, Token::Inline]).into()),
+            };
+
+            // Report the error and resume at the next item, so that all syntax errors in the file are reported.
+            if let Err(error) = item {
+                self.handler.emit_err(error);
+                self.recover_item();
+            }
+        }
+
+        Ok(Module {
+            consts,
+            structs,
+            functions,
+            span: start + self.prev_token.span,
+        })
+    }
+
     fn unexpected_item(token: &SpannedToken, expected: &[Token]) -> ParserError {
         ParserError::unexpected(
             &token.token,
@@ -71,51 +122,43 @@ impl ParserContext<'_> {
         )
     }
 
-    // TODO: remove import resolution from parser.
-    /// Parses an import statement `import foo.leo;`.
-    pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span))> {
+    /// Parses an import statement `import foo.leo;` or `import foo.aleo;`.
+    /// Returns the imported program, and whether it is imported from Aleo instructions.
+    /// Note that the imported program is empty until imports are resolved.
+    pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span), bool)> {
         // Parse `import`.
         let start = self.expect(&Token::Import)?;
 
         // Parse `foo`.
         let import_name = self.expect_identifier()?;
 
-        // Parse `.leo`.
+        // Parse `.leo` or `.aleo`.
         self.expect(&Token::Dot)?;
-        if !self.eat(&Token::Leo) {
-            // Throw error for non-leo files.
-            return Err(ParserError::leo_imports_only(self.token.span).into());
-        }
+        let is_aleo = match &self.token.token {
+            Token::Leo => false,
+            Token::Identifier(network) if *network == sym::aleo => true,
+            // Throw error for imports of other files.
+            _ => return Err(ParserError::leo_imports_only(self.token.span).into()),
+        };
+        self.bump();
 
         let end = self.expect(&Token::Semicolon)?;
 
-        // Tokenize and parse import file.
-        // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
-        import_file_path.push("imports");
-        import_file_path.push(format!("{}.leo", import_name.name));
-
-        // Throw an error if the import file doesn't exist.
-        if !import_file_path.exists() {
-            return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
-        }
-
-        // Read the import file into string.
-        // Todo: protect against cyclic imports.
-        let program_string =
-            fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
+        Ok((import_name.name, (Program::default(), start + end), is_aleo))
+    }
 
-        // Create import file name.
-        let name: FileName = FileName::Real(import_file_path);
+    /// Parses a module declaration `mod foo;`.
+    /// Note that the module is empty until modules are resolved.
+    fn parse_module_declaration(&mut self) -> Result<(Symbol, (Module, Span))> {
+        // Parse `mod`.
+        let start = self.expect(&Token::Identifier(sym::Mod))?;
 
-        // Register the source (`program_string`) in the source map.
-        let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
+        // Parse `foo`.
+        let module_name = self.expect_identifier()?;
 
-        // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, &prg_sf.src, prg_sf.start_pos)?;
+        let end = self.expect(&Token::Semicolon)?;
 
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        Ok((module_name.name, (Module::default(), start + end)))
     }
 
     /// Parsers a program scope `program foo.aleo { ... }`.
@@ -142,40 +185,53 @@ impl ParserContext<'_> {
         self.expect(&Token::LeftCurly)?;
 
         // Parse the body of the program scope.
+        let mut consts = IndexMap::new();
         let mut functions = IndexMap::new();
         let mut structs = IndexMap::new();
+        let mut enums = IndexMap::new();
         let mut mappings = IndexMap::new();
 
         while self.has_next() {
-            match &self.token.token {
-                Token::Struct | Token::Record => {
-                    let (id, struct_) = self.parse_struct()?;
+            let item = match &self.token.token {
+                Token::Const => self.parse_const_declaration().map(|(id, const_)| {
+                    consts.insert(id, const_);
+                }),
+                Token::Struct | Token::Record => self.parse_struct().map(|(id, struct_)| {
                     structs.insert(id, struct_);
-                }
-                Token::Mapping => {
-                    let (id, mapping) = self.parse_mapping()?;
+                }),
+                Token::Enum => self.parse_enum().map(|(id, enum_)| {
+                    enums.insert(id, enum_);
+                }),
+                Token::Mapping => self.parse_mapping().map(|(id, mapping)| {
                     mappings.insert(id, mapping);
-                }
+                }),
                 Token::At | Token::Function | Token::Transition | Token::Inline => {
-                    let (id, function) = self.parse_function()?;
-                    functions.insert(id, function);
+                    self.parse_function().map(|(id, function)| {
+                        functions.insert(id, function);
+                    })
                 }
                 Token::RightCurly => break,
-                _ => {
-                    return Err(Self::unexpected_item(
-                        &self.token,
-                        &[
-                            Token::Struct,
-                            Token::Record,
-                            Token::Mapping,
-                            Token::At,
-                            Token::Function,
-                            Token::Transition,
-                            Token::Inline,
-                        ],
-                    )
-                    .into())
-                }
+                _ => Err(Self::unexpected_item(
+                    &self.token,
+                    &[
+                        Token::Const,
+                        Token::Struct,
+                        Token::Record,
+                        Token::Enum,
+                        Token::Mapping,
+                        Token::At,
+                        Token::Function,
+                        Token::Transition,
+                        Token::Inline,
+                    ],
+                )
+                .into()),
+            };
+
+            // Report the error and resume at the next item, so that all syntax errors in the file are reported.
+            if let Err(error) = item {
+                self.handler.emit_err(error);
+                self.recover_item();
             }
         }
 
@@ -184,8 +240,10 @@ impl ParserContext<'_> {
 
         Ok(ProgramScope {
             program_id,
+            consts,
             functions,
             structs,
+            enums,
             mappings,
             span: start + end,
         })
@@ -264,6 +322,63 @@ impl ParserContext<'_> {
         ))
     }
 
+    /// Parses an enum definition, e.g., `enum Status { Open, Filled, Cancelled }` or `enum Order { Market(u64), Limit(u64, u64) }`.
+    pub(super) fn parse_enum(&mut self) -> Result<(Symbol, Enum)> {
+        let start = self.expect(&Token::Enum)?;
+        let enum_name = self.expect_identifier()?;
+
+        let (variants, _, end) = self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| {
+            p.parse_enum_variant().map(Some)
+        })?;
+
+        Ok((
+            enum_name.name,
+            Enum {
+                identifier: enum_name,
+                variants,
+                span: start + end,
+            },
+        ))
+    }
+
+    /// Parses a variant of an enum, e.g., `Open` or `Limit(u64, u64)`.
+    fn parse_enum_variant(&mut self) -> Result<EnumVariant> {
+        let identifier = self.expect_identifier()?;
+
+        let (payload, span) = match self.peek_is_left_par() {
+            true => {
+                let (payload, _, span) =
+                    self.parse_paren_comma_list(|p| p.parse_type().map(|(type_, _)| Some(type_)))?;
+                (payload, identifier.span + span)
+            }
+            false => (Vec::new(), identifier.span),
+        };
+
+        Ok(EnumVariant {
+            identifier,
+            payload,
+            span,
+        })
+    }
+
+    /// Parses a constant declaration, e.g. `const MAX_PLAYERS: u8 = 8u8;`.
+    pub(super) fn parse_const_declaration(&mut self) -> Result<(Symbol, ConstDeclaration)> {
+        let start = self.expect(&Token::Const)?;
+        let (identifier, type_, _) = self.parse_typed_ident()?;
+        self.expect(&Token::Assign)?;
+        let value = self.parse_expression()?;
+        self.expect(&Token::Semicolon)?;
+        Ok((
+            identifier.name,
+            ConstDeclaration {
+                identifier,
+                type_,
+                span: start + value.span(),
+                value,
+            },
+        ))
+    }
+
     /// Parses a mapping declaration, e.g. `mapping balances: address => u128`.
     pub(super) fn parse_mapping(&mut self) -> Result<(Symbol, Mapping)> {
         let start = self.expect(&Token::Mapping)?;
@@ -46,6 +46,13 @@ pub fn parse(handler: &Handler, source: &str, start_pos: BytePos) -> Result<Prog
     tokens.parse_program()
 }
 
+/// Creates a new module from a given file path and source code text.
+pub fn parse_module(handler: &Handler, source: &str, start_pos: BytePos) -> Result<Module> {
+    let mut tokens = ParserContext::new(handler, crate::tokenize(source, start_pos)?);
+
+    tokens.parse_module()
+}
+
 /// Parses an input file at the given file `path` and `source` code text.
 pub fn parse_input(handler: &Handler, source: &str, start_pos: BytePos) -> Result<InputAst> {
     let mut tokens = ParserContext::new(handler, crate::tokenize(source, start_pos)?);
@@ -45,8 +45,9 @@ impl ParserContext<'_> {
             Token::Decrement => Ok(Statement::Decrement(self.parse_decrement_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
             Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
+            Token::Match => Ok(Statement::Match(self.parse_match_statement()?)),
             Token::Assert | Token::AssertEq | Token::AssertNeq => Ok(self.parse_assert_statement()?),
-            Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
+            Token::Const | Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
             Token::Console => Err(ParserError::console_statements_are_not_yet_supported(self.token.span).into()),
             Token::Finalize => Err(ParserError::finalize_statements_are_deprecated(self.token.span).into()),
@@ -126,6 +127,7 @@ impl ParserContext<'_> {
                     right: Box::new(value),
                     op,
                     span,
+                    operand_type: Default::default(),
                 }),
             };
 
@@ -142,8 +144,34 @@ impl ParserContext<'_> {
 
     /// Returns a [`Block`] AST node if the next tokens represent a block of statements.
     pub(super) fn parse_block(&mut self) -> Result<Block> {
-        self.parse_list(Delimiter::Brace, None, |p| p.parse_statement().map(Some))
-            .map(|(statements, _, span)| Block { statements, span })
+        let open_span = self.expect(&Token::LeftCurly)?;
+
+        let mut statements = Vec::new();
+        while !self.check(&Token::RightCurly) {
+            let start = self.token.span;
+            let is_return = self.check(&Token::Return);
+            match self.parse_statement() {
+                Ok(statement) => statements.push(statement),
+                // Report the error and resume at the next statement.
+                Err(error) if self.recover_statement() => {
+                    self.handler.emit_err(error);
+                    // Keep a malformed return statement, so that the function is not reported as missing one.
+                    if is_return {
+                        let span = start + self.prev_token.span;
+                        statements.push(Statement::Return(ReturnStatement {
+                            expression: Expression::Err(ErrExpression { span }),
+                            finalize_arguments: None,
+                            span,
+                        }));
+                    }
+                }
+                // The block is unclosed, so the error is reported when recovering at the next item.
+                Err(error) => return Err(error),
+            }
+        }
+
+        let span = open_span + self.expect(&Token::RightCurly)?;
+        Ok(Block { statements, span })
     }
 
     /// Returns a [`ReturnStatement`] AST node if the next tokens represent a return statement.
@@ -248,6 +276,76 @@ impl ParserContext<'_> {
         })
     }
 
+    /// Returns a [`MatchStatement`] AST node if the next tokens represent a match statement,
+    /// e.g. `match status { Status::Open => { ... } Status::Closed => { ... } }`.
+    fn parse_match_statement(&mut self) -> Result<MatchStatement> {
+        let start = self.expect(&Token::Match)?;
+        self.disallow_struct_construction = true;
+        let expression = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = false;
+
+        // The arms may be separated by commas.
+        let (arms, _, end) = self.parse_list(Delimiter::Brace, None, |p| {
+            let arm = p.parse_match_arm()?;
+            p.eat(&Token::Comma);
+            Ok(Some(arm))
+        })?;
+
+        Ok(MatchStatement {
+            expression,
+            arms,
+            span: start + end,
+        })
+    }
+
+    /// Returns a [`MatchArm`] AST node if the next tokens represent an arm of a match statement, e.g. `Order::Limit(price, amount) => { ... }`.
+    fn parse_match_arm(&mut self) -> Result<MatchArm> {
+        let pattern = self.parse_match_pattern()?;
+        self.expect(&Token::BigArrow)?;
+        let block = self.parse_block()?;
+
+        Ok(MatchArm {
+            span: pattern.span() + block.span,
+            pattern,
+            block,
+        })
+    }
+
+    /// Returns a [`MatchPattern`] AST node if the next tokens represent the pattern of a match arm,
+    /// i.e. a variant of an enum, a literal, or the wildcard `_`.
+    pub(super) fn parse_match_pattern(&mut self) -> Result<MatchPattern> {
+        if self.eat(&Token::Underscore) {
+            return Ok(MatchPattern::Wildcard(self.prev_token.span));
+        }
+        if !matches!(self.token.token, Token::Identifier(_)) {
+            // Note that a negative integer, e.g. `-1i8`, is parsed as a unary expression.
+            return match self.parse_unary_expression()? {
+                Expression::Literal(literal) => Ok(MatchPattern::Literal(literal)),
+                expression => Err(ParserError::unexpected_str(&expression, "pattern", expression.span()).into()),
+            };
+        }
+
+        let enum_name = self.expect_identifier()?;
+        self.expect(&Token::DoubleColon)?;
+        let variant = self.expect_identifier()?;
+
+        // Parse the bindings of the values carried by the variant, if any.
+        let (bindings, span) = match self.peek_is_left_par() {
+            true => {
+                let (bindings, _, span) = self.parse_paren_comma_list(|p| p.expect_identifier().map(Some))?;
+                (bindings, enum_name.span + span)
+            }
+            false => (Vec::new(), enum_name.span + variant.span),
+        };
+
+        Ok(MatchPattern::Variant(VariantPattern {
+            enum_name,
+            variant,
+            bindings,
+            span,
+        }))
+    }
+
     /// Returns an [`IterationStatement`] AST node if the next tokens represent an iteration statement.
     fn parse_loop_statement(&mut self) -> Result<IterationStatement> {
         let start_span = self.expect(&Token::For)?;
@@ -332,11 +430,11 @@ impl ParserContext<'_> {
 
     /// Returns a [`DefinitionStatement`] AST node if the next tokens represent a definition statement.
     pub(super) fn parse_definition_statement(&mut self) -> Result<DefinitionStatement> {
-        self.expect(&Token::Let)?;
+        self.expect_any(&[Token::Const, Token::Let])?;
         let decl_span = self.prev_token.span;
         let decl_type = match &self.prev_token.token {
+            Token::Const => DeclarationType::Const,
             Token::Let => DeclarationType::Let,
-            // Note: Reserving for `constant` declarations.
             _ => unreachable!("parse_definition_statement_ shouldn't produce this"),
         };
 
@@ -346,8 +444,22 @@ impl ParserContext<'_> {
         let type_ = self.parse_type()?.0;
 
         self.expect(&Token::Assign)?;
-        let value = self.parse_expression()?;
-        self.expect(&Token::Semicolon)?;
+
+        // An invalid value is replaced by an error expression, so that later uses of the variable can still be checked.
+        let start = self.token.span;
+        let value = match self.parse_expression() {
+            Ok(value) => {
+                self.expect(&Token::Semicolon)?;
+                value
+            }
+            Err(error) if self.recover_statement() => {
+                self.handler.emit_err(error);
+                Expression::Err(ErrExpression {
+                    span: start + self.prev_token.span,
+                })
+            }
+            Err(error) => return Err(error),
+        };
 
         Ok(DefinitionStatement {
             span: decl_span + value.span(),
@@ -18,7 +18,7 @@ use super::*;
 
 use leo_errors::{ParserError, Result};
 
-pub(super) const TYPE_TOKENS: &[Token] = &[
+pub(crate) const TYPE_TOKENS: &[Token] = &[
     Token::Address,
     Token::Bool,
     Token::Field,
@@ -77,6 +77,11 @@ impl ParserContext<'_> {
     /// Also returns the span of the parsed token.
     pub fn parse_type(&mut self) -> Result<(Type, Span)> {
         if let Some(ident) = self.eat_identifier() {
+            // Parse a path to a struct declared in a module, e.g. `utils::Point`.
+            let ident = match self.eat(&Token::DoubleColon) {
+                true => Self::path(ident, self.expect_identifier()?),
+                false => ident,
+            };
             Ok((Type::Identifier(ident), ident.span))
         } else if self.token.token == Token::LeftParen {
             let (types, _, span) = self.parse_paren_comma_list(|p| p.parse_type().map(Some))?;
@@ -89,6 +94,20 @@ impl ParserContext<'_> {
                 // Note: This is the only place where `Tuple` type is constructed in the parser.
                 _ => Ok((Type::Tuple(Tuple(types.into_iter().map(|t| t.0).collect())), span)),
             }
+        } else if self.eat(&Token::LeftSquare) {
+            // Parse an array type, e.g. `[u8; 32]`.
+            let lo = self.prev_token.span;
+            let (element_type, _) = self.parse_type()?;
+            self.expect(&Token::Semicolon)?;
+            let (length, _) = self.eat_integer()?;
+            let span = lo + self.expect(&Token::RightSquare)?;
+            Ok((
+                Type::Array(ArrayType {
+                    element_type: Box::new(element_type),
+                    length,
+                }),
+                span,
+            ))
         } else {
             self.parse_primitive_type()
         }
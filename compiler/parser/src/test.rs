@@ -218,6 +218,37 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct FormatNamespace;
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| {
+            let run_format = |source: &str, name: &str| {
+                let sf = s.source_map.new_source(source, FileName::Custom(name.into()));
+                let (handler, buf) = Handler::new_with_buf();
+                handler
+                    .extend_if_error(crate::format(&handler, &sf.src, sf.start_pos))
+                    .map_err(|_| buf.extract_errs().to_string())
+            };
+            let formatted = run_format(&test.content, "test")?;
+
+            // Formatting must be idempotent.
+            let reformatted = run_format(&formatted, "formatted")?;
+            if formatted != reformatted {
+                return Err(format!(
+                    "formatting is not idempotent, the second pass produced:\n{reformatted}"
+                ));
+            }
+
+            Ok(Value::String(formatted))
+        })
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -228,6 +259,7 @@ impl Runner for TestRunner {
             "ParseStatement" => Box::new(ParseStatementNamespace),
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
+            "Format" => Box::new(FormatNamespace),
             "Token" => Box::new(TokenNamespace),
             _ => return None,
         })
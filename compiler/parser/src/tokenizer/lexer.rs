@@ -402,14 +402,17 @@ impl Token {
                 match &*identifier {
                     x if x.starts_with("aleo1") => Token::AddressLit(identifier),
                     "address" => Token::Address,
+                    "as" => Token::As,
                     "assert" => Token::Assert,
                     "assert_eq" => Token::AssertEq,
                     "assert_neq" => Token::AssertNeq,
                     "bool" => Token::Bool,
                     "console" => Token::Console,
+                    "const" => Token::Const,
                     "constant" => Token::Constant,
                     "decrement" => Token::Decrement,
                     "else" => Token::Else,
+                    "enum" => Token::Enum,
                     "false" => Token::False,
                     "field" => Token::Field,
                     "finalize" => Token::Finalize,
@@ -429,6 +432,7 @@ impl Token {
                     "let" => Token::Let,
                     "leo" => Token::Leo,
                     "mapping" => Token::Mapping,
+                    "match" => Token::Match,
                     "private" => Token::Private,
                     "program" => Token::Program,
                     "public" => Token::Public,
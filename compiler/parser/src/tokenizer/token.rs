@@ -108,13 +108,16 @@ pub enum Token {
     Record,
 
     // Regular Keywords
+    As,
     Assert,
     AssertEq,
     AssertNeq,
     Console,
+    Const,
     Constant,
     Decrement,
     Else,
+    Enum,
     Finalize,
     For,
     Function,
@@ -125,6 +128,7 @@ pub enum Token {
     Inline,
     Let,
     Mapping,
+    Match,
     Private,
     Program,
     // For public inputs.
@@ -147,14 +151,17 @@ pub enum Token {
 /// because true and false are also boolean literals, which are different tokens from keywords.
 pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Address,
+    Token::As,
     Token::Assert,
     Token::AssertEq,
     Token::AssertNeq,
     Token::Bool,
     Token::Console,
+    Token::Const,
     Token::Constant,
     Token::Decrement,
     Token::Else,
+    Token::Enum,
     Token::False,
     Token::Field,
     Token::Finalize,
@@ -173,6 +180,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Inline,
     Token::Let,
     Token::Mapping,
+    Token::Match,
     Token::Private,
     Token::Program,
     Token::Public,
@@ -202,14 +210,17 @@ impl Token {
     pub fn keyword_to_symbol(&self) -> Option<Symbol> {
         Some(match self {
             Token::Address => sym::address,
+            Token::As => sym::As,
             Token::Assert => sym::assert,
             Token::AssertEq => sym::assert_eq,
             Token::AssertNeq => sym::assert_neq,
             Token::Bool => sym::bool,
             Token::Console => sym::console,
+            Token::Const => sym::Const,
             Token::Constant => sym::constant,
             Token::Decrement => sym::decrement,
             Token::Else => sym::Else,
+            Token::Enum => sym::Enum,
             Token::False => sym::False,
             Token::Field => sym::field,
             Token::Finalize => sym::finalize,
@@ -229,6 +240,7 @@ impl Token {
             Token::Let => sym::Let,
             Token::Leo => sym::leo,
             Token::Mapping => sym::mapping,
+            Token::Match => sym::Match,
             Token::Private => sym::private,
             Token::Program => sym::program,
             Token::Public => sym::public,
@@ -335,13 +347,16 @@ impl fmt::Display for Token {
             U128 => write!(f, "u128"),
             Record => write!(f, "record"),
 
+            As => write!(f, "as"),
             Assert => write!(f, "assert"),
             AssertEq => write!(f, "assert_eq"),
             AssertNeq => write!(f, "assert_neq"),
             Console => write!(f, "console"),
+            Const => write!(f, "const"),
             Constant => write!(f, "constant"),
             Decrement => write!(f, "decrement"),
             Else => write!(f, "else"),
+            Enum => write!(f, "enum"),
             Finalize => write!(f, "finalize"),
             For => write!(f, "for"),
             Function => write!(f, "function"),
@@ -352,6 +367,7 @@ impl fmt::Display for Token {
             Inline => write!(f, "inline"),
             Let => write!(f, "let"),
             Mapping => write!(f, "mapping"),
+            Match => write!(f, "match"),
             Private => write!(f, "private"),
             Program => write!(f, "program"),
             Public => write!(f, "public"),
@@ -373,6 +389,8 @@ pub enum Delimiter {
     Parenthesis,
     /// `{ ... }`
     Brace,
+    /// `[ ... ]`
+    Bracket,
 }
 
 impl Delimiter {
@@ -381,6 +399,7 @@ impl Delimiter {
         match self {
             Self::Parenthesis => (Token::LeftParen, Token::RightParen),
             Self::Brace => (Token::LeftCurly, Token::RightCurly),
+            Self::Bracket => (Token::LeftSquare, Token::RightSquare),
         }
     }
 }
@@ -15,12 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::SymbolTable;
-use crate::{CallGraph, StructGraph};
+use crate::{AleoOperand, AleoOutput, AleoRegister, CallGraph, StructGraph, UnsupportedFeature};
 
-use leo_ast::Function;
+use leo_ast::{ArrayType, Function, ProgramId};
 use leo_span::Symbol;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 pub struct CodeGenerator<'a> {
     /// The symbol table for the program.
@@ -33,16 +33,26 @@ pub struct CodeGenerator<'a> {
     pub(crate) next_register: u64,
     /// Reference to the current function.
     pub(crate) current_function: Option<&'a Function>,
-    /// Mapping of variables to registers.
-    pub(crate) variable_mapping: IndexMap<&'a Symbol, String>,
+    /// Mapping of variables to operands.
+    pub(crate) variable_mapping: IndexMap<&'a Symbol, AleoOperand>,
     /// Mapping of composite names to a tuple containing metadata associated with the name.
     /// The first element of the tuple indicate whether the composite is a record or not.
     /// The second element of the tuple is a string modifier used for code generation.
     pub(crate) composite_mapping: IndexMap<&'a Symbol, (bool, String)>,
+    /// The array types that have been used, but whose corresponding structs have not yet been declared.
+    pub(crate) array_types: IndexSet<ArrayType>,
+    /// The array types whose corresponding structs have been declared.
+    pub(crate) declared_array_types: IndexSet<ArrayType>,
     /// Are we traversing a transition function?
     pub(crate) is_transition_function: bool,
     /// Are we traversing a finalize block?
     pub(crate) in_finalize: bool,
+    /// The id of the program, e.g. `foo.aleo`, which is set in `visit_program`.
+    pub(crate) program_id: Option<ProgramId>,
+    /// The outputs of the current function or finalize block, set by its return statement.
+    pub(crate) outputs: Vec<AleoOutput>,
+    /// The features used so far that snarkVM does not support yet.
+    pub(crate) unsupported: Vec<UnsupportedFeature>,
 }
 
 impl<'a> CodeGenerator<'a> {
@@ -57,8 +67,20 @@ impl<'a> CodeGenerator<'a> {
             current_function: None,
             variable_mapping: IndexMap::new(),
             composite_mapping: IndexMap::new(),
+            array_types: IndexSet::new(),
+            declared_array_types: IndexSet::new(),
             is_transition_function: false,
             in_finalize: false,
+            program_id: None,
+            outputs: Vec::new(),
+            unsupported: Vec::new(),
         }
     }
+
+    /// Returns the next available register.
+    pub(crate) fn next_register(&mut self) -> AleoRegister {
+        let register = AleoRegister(self.next_register);
+        self.next_register += 1;
+        register
+    }
 }
@@ -0,0 +1,822 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A typed representation of Aleo programs.
+//! Code generation constructs it from the Leo AST, and it is printed into Aleo instructions at the end.
+
+use crate::{AleoSourceMap, InstructionLocation};
+
+use leo_ast::{BinaryOperation, Literal, Mode, ProgramId, Type, UnaryOperation};
+use leo_core::CoreInstruction;
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use itertools::Itertools;
+use std::fmt;
+
+/// A register, e.g. `r0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AleoRegister(pub u64);
+
+impl fmt::Display for AleoRegister {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "r{}", self.0)
+    }
+}
+
+/// An operand of an instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AleoOperand {
+    /// The caller of the transition, `self.caller`.
+    Caller,
+    /// A literal, e.g. `1u8`.
+    Literal(Literal),
+    /// A member of a struct or record, e.g. `r0.owner`.
+    Member(Box<AleoOperand>, Symbol),
+    /// A register, e.g. `r0`.
+    Register(AleoRegister),
+}
+
+impl fmt::Display for AleoOperand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Caller => write!(f, "self.caller"),
+            Self::Literal(literal) => write!(f, "{literal}"),
+            Self::Member(inner, member) => write!(f, "{inner}.{member}"),
+            Self::Register(register) => write!(f, "{register}"),
+        }
+    }
+}
+
+/// The type of a register, a struct or record member, or a mapping key or value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AleoType {
+    /// The type of a literal, e.g. `u8` or `field`.
+    Literal(Type),
+    /// A struct, e.g. `point`, including the structs that arrays are lowered to, e.g. `array2__u8`.
+    Struct(Symbol),
+    /// A record of the program, e.g. `token.record`.
+    Record(Symbol),
+    /// A record of another program, e.g. `token.aleo/token.record`.
+    ExternalRecord { program: Symbol, record: Symbol },
+    /// The future of a call to a finalize block, e.g. `test.aleo/main.future`.
+    Future { program: ProgramId, function: Symbol },
+}
+
+impl AleoType {
+    /// Returns the type along with the given visibility, e.g. `u8.private`.
+    /// Note that records and futures do not have a visibility, and neither do the types without one, e.g. of closure inputs.
+    fn with_visibility(&self, mode: Mode) -> String {
+        match (self, mode) {
+            (Self::Record(_) | Self::ExternalRecord { .. } | Self::Future { .. }, _) | (_, Mode::None) => {
+                self.to_string()
+            }
+            (_, mode) => format!("{self}.{mode}"),
+        }
+    }
+}
+
+impl fmt::Display for AleoType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Literal(type_) => write!(f, "{type_}"),
+            Self::Struct(name) => write!(f, "{name}"),
+            Self::Record(name) => write!(f, "{name}.record"),
+            Self::ExternalRecord { program, record } => write!(f, "{program}.aleo/{record}.record"),
+            Self::Future { program, function } => write!(f, "{program}/{function}.future"),
+        }
+    }
+}
+
+/// The opcode of an Aleo instruction or command, e.g. `add` or `hash.bhp256`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AleoOpcode {
+    Abs,
+    AbsWrapped,
+    Add,
+    AddWrapped,
+    And,
+    AssertEq,
+    AssertNeq,
+    Async,
+    Call,
+    Cast,
+    Contains,
+    Core(CoreInstruction),
+    Div,
+    DivWrapped,
+    Double,
+    Get,
+    GetOrUse,
+    Gt,
+    Gte,
+    Inv,
+    IsEq,
+    IsNeq,
+    Lt,
+    Lte,
+    Mod,
+    Mul,
+    MulWrapped,
+    Nand,
+    Neg,
+    Nor,
+    Not,
+    Or,
+    Pow,
+    PowWrapped,
+    Rem,
+    RemWrapped,
+    Remove,
+    Set,
+    Shl,
+    ShlWrapped,
+    Shr,
+    ShrWrapped,
+    Sqrt,
+    Square,
+    Sub,
+    SubWrapped,
+    Ternary,
+    Xor,
+}
+
+impl From<BinaryOperation> for AleoOpcode {
+    fn from(op: BinaryOperation) -> Self {
+        match op {
+            BinaryOperation::Add => Self::Add,
+            BinaryOperation::AddWrapped => Self::AddWrapped,
+            BinaryOperation::And | BinaryOperation::BitwiseAnd => Self::And,
+            BinaryOperation::Div => Self::Div,
+            BinaryOperation::DivWrapped => Self::DivWrapped,
+            BinaryOperation::Eq => Self::IsEq,
+            BinaryOperation::Gte => Self::Gte,
+            BinaryOperation::Gt => Self::Gt,
+            BinaryOperation::Lte => Self::Lte,
+            BinaryOperation::Lt => Self::Lt,
+            BinaryOperation::Mod => Self::Mod,
+            BinaryOperation::Mul => Self::Mul,
+            BinaryOperation::MulWrapped => Self::MulWrapped,
+            BinaryOperation::Nand => Self::Nand,
+            BinaryOperation::Neq => Self::IsNeq,
+            BinaryOperation::Nor => Self::Nor,
+            BinaryOperation::Or | BinaryOperation::BitwiseOr => Self::Or,
+            BinaryOperation::Pow => Self::Pow,
+            BinaryOperation::PowWrapped => Self::PowWrapped,
+            BinaryOperation::Rem => Self::Rem,
+            BinaryOperation::RemWrapped => Self::RemWrapped,
+            BinaryOperation::Shl => Self::Shl,
+            BinaryOperation::ShlWrapped => Self::ShlWrapped,
+            BinaryOperation::Shr => Self::Shr,
+            BinaryOperation::ShrWrapped => Self::ShrWrapped,
+            BinaryOperation::Sub => Self::Sub,
+            BinaryOperation::SubWrapped => Self::SubWrapped,
+            BinaryOperation::Xor => Self::Xor,
+        }
+    }
+}
+
+impl From<UnaryOperation> for AleoOpcode {
+    fn from(op: UnaryOperation) -> Self {
+        match op {
+            UnaryOperation::Abs => Self::Abs,
+            UnaryOperation::AbsWrapped => Self::AbsWrapped,
+            UnaryOperation::Double => Self::Double,
+            UnaryOperation::Inverse => Self::Inv,
+            UnaryOperation::Not => Self::Not,
+            UnaryOperation::Negate => Self::Neg,
+            UnaryOperation::Square => Self::Square,
+            UnaryOperation::SquareRoot => Self::Sqrt,
+        }
+    }
+}
+
+impl fmt::Display for AleoOpcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opcode = match self {
+            Self::Abs => "abs",
+            Self::AbsWrapped => "abs.w",
+            Self::Add => "add",
+            Self::AddWrapped => "add.w",
+            Self::And => "and",
+            Self::AssertEq => "assert.eq",
+            Self::AssertNeq => "assert.neq",
+            Self::Async => "async",
+            Self::Call => "call",
+            Self::Cast => "cast",
+            Self::Contains => "contains",
+            Self::Core(instruction) => match instruction {
+                CoreInstruction::BHP256Commit => "commit.bhp256",
+                CoreInstruction::BHP256Hash => "hash.bhp256",
+                CoreInstruction::BHP512Commit => "commit.bhp512",
+                CoreInstruction::BHP512Hash => "hash.bhp512",
+                CoreInstruction::BHP768Commit => "commit.bhp768",
+                CoreInstruction::BHP768Hash => "hash.bhp768",
+                CoreInstruction::BHP1024Commit => "commit.bhp1024",
+                CoreInstruction::BHP1024Hash => "hash.bhp1024",
+                CoreInstruction::Pedersen64Commit => "commit.ped64",
+                CoreInstruction::Pedersen64Hash => "hash.ped64",
+                CoreInstruction::Pedersen128Commit => "commit.ped128",
+                CoreInstruction::Pedersen128Hash => "hash.ped128",
+                CoreInstruction::Poseidon2Hash => "hash.psd2",
+                CoreInstruction::Poseidon4Hash => "hash.psd4",
+                CoreInstruction::Poseidon8Hash => "hash.psd8",
+            },
+            Self::Div => "div",
+            Self::DivWrapped => "div.w",
+            Self::Double => "double",
+            Self::Get => "get",
+            Self::GetOrUse => "get.or_use",
+            Self::Gt => "gt",
+            Self::Gte => "gte",
+            Self::Inv => "inv",
+            Self::IsEq => "is.eq",
+            Self::IsNeq => "is.neq",
+            Self::Lt => "lt",
+            Self::Lte => "lte",
+            Self::Mod => "mod",
+            Self::Mul => "mul",
+            Self::MulWrapped => "mul.w",
+            Self::Nand => "nand",
+            Self::Neg => "neg",
+            Self::Nor => "nor",
+            Self::Not => "not",
+            Self::Or => "or",
+            Self::Pow => "pow",
+            Self::PowWrapped => "pow.w",
+            Self::Rem => "rem",
+            Self::RemWrapped => "rem.w",
+            Self::Remove => "remove",
+            Self::Set => "set",
+            Self::Shl => "shl",
+            Self::ShlWrapped => "shl.w",
+            Self::Shr => "shr",
+            Self::ShrWrapped => "shr.w",
+            Self::Sqrt => "sqrt",
+            Self::Square => "square",
+            Self::Sub => "sub",
+            Self::SubWrapped => "sub.w",
+            Self::Ternary => "ternary",
+            Self::Xor => "xor",
+        };
+        write!(f, "{opcode}")
+    }
+}
+
+/// An Aleo instruction or command, without its destination registers in the case of a call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AleoOperation {
+    /// An equality assertion, e.g. `assert.eq r0 r1`.
+    AssertEq { left: AleoOperand, right: AleoOperand },
+    /// An inequality assertion, e.g. `assert.neq r0 r1`.
+    AssertNeq { left: AleoOperand, right: AleoOperand },
+    /// A call to the finalize block of the function, e.g. `async foo r0 r1 into r2`.
+    Async {
+        function: Symbol,
+        arguments: Vec<AleoOperand>,
+        destination: AleoRegister,
+    },
+    /// A binary operation, e.g. `add r0 r1 into r2`, along with the type of its left operand if it is known.
+    Binary {
+        op: BinaryOperation,
+        left: AleoOperand,
+        right: AleoOperand,
+        destination: AleoRegister,
+        operand_type: Option<Type>,
+    },
+    /// A call to a closure, or to a function of another program if `program` is set,
+    /// e.g. `call foo r0 into r1 r2` or `call bar.aleo/foo r0 into r1`.
+    Call {
+        program: Option<Symbol>,
+        function: Symbol,
+        arguments: Vec<AleoOperand>,
+        destinations: Vec<AleoRegister>,
+    },
+    /// A cast, which converts a literal or constructs a struct, record or array, e.g. `cast r0 r1 into r2 as foo`.
+    Cast {
+        operands: Vec<AleoOperand>,
+        destination: AleoRegister,
+        type_: AleoType,
+    },
+    /// A check whether a mapping contains a key, e.g. `contains balances[r0] into r1`.
+    Contains {
+        mapping: Symbol,
+        key: AleoOperand,
+        destination: AleoRegister,
+    },
+    /// A hash or commitment, e.g. `hash.bhp256 r0 into r1 as field`, whose type is the return type of the core instruction.
+    Core {
+        instruction: CoreInstruction,
+        operands: Vec<AleoOperand>,
+        destination: AleoRegister,
+    },
+    /// A lookup of a mapping value, e.g. `get balances[r0] into r1`.
+    Get {
+        mapping: Symbol,
+        key: AleoOperand,
+        destination: AleoRegister,
+    },
+    /// A lookup of a mapping value with a default, e.g. `get.or_use balances[r0] 0u64 into r1`.
+    GetOrUse {
+        mapping: Symbol,
+        key: AleoOperand,
+        default: AleoOperand,
+        destination: AleoRegister,
+    },
+    /// A removal of a mapping entry, e.g. `remove balances[r0]`.
+    Remove { mapping: Symbol, key: AleoOperand },
+    /// An update of a mapping value, e.g. `set r1 into balances[r0]`.
+    Set {
+        mapping: Symbol,
+        key: AleoOperand,
+        value: AleoOperand,
+    },
+    /// A ternary operation, e.g. `ternary r0 r1 r2 into r3`.
+    Ternary {
+        condition: AleoOperand,
+        if_true: AleoOperand,
+        if_false: AleoOperand,
+        destination: AleoRegister,
+    },
+    /// A unary operation, e.g. `not r0 into r1`, along with the type of its operand if it is known.
+    Unary {
+        op: UnaryOperation,
+        operand: AleoOperand,
+        destination: AleoRegister,
+        operand_type: Option<Type>,
+    },
+}
+
+impl AleoOperation {
+    /// Returns the opcode of the operation, e.g. `add` or `get.or_use`.
+    pub fn opcode(&self) -> AleoOpcode {
+        match self {
+            Self::AssertEq { .. } => AleoOpcode::AssertEq,
+            Self::AssertNeq { .. } => AleoOpcode::AssertNeq,
+            Self::Async { .. } => AleoOpcode::Async,
+            Self::Binary { op, .. } => AleoOpcode::from(*op),
+            Self::Call { .. } => AleoOpcode::Call,
+            Self::Cast { .. } => AleoOpcode::Cast,
+            Self::Contains { .. } => AleoOpcode::Contains,
+            Self::Core { instruction, .. } => AleoOpcode::Core(*instruction),
+            Self::Get { .. } => AleoOpcode::Get,
+            Self::GetOrUse { .. } => AleoOpcode::GetOrUse,
+            Self::Remove { .. } => AleoOpcode::Remove,
+            Self::Set { .. } => AleoOpcode::Set,
+            Self::Ternary { .. } => AleoOpcode::Ternary,
+            Self::Unary { op, .. } => AleoOpcode::from(*op),
+        }
+    }
+}
+
+impl fmt::Display for AleoOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opcode = self.opcode();
+        match self {
+            Self::AssertEq { left, right } | Self::AssertNeq { left, right } => write!(f, "{opcode} {left} {right};"),
+            Self::Async {
+                function,
+                arguments,
+                destination,
+            } => {
+                write!(f, "{opcode} {function}")?;
+                for argument in arguments.iter() {
+                    write!(f, " {argument}")?;
+                }
+                write!(f, " into {destination};")
+            }
+            Self::Binary {
+                left,
+                right,
+                destination,
+                ..
+            } => write!(f, "{opcode} {left} {right} into {destination};"),
+            Self::Call {
+                program,
+                function,
+                arguments,
+                destinations,
+            } => {
+                match program {
+                    Some(program) => write!(f, "{opcode} {program}.aleo/{function}")?,
+                    None => write!(f, "{opcode} {function}")?,
+                }
+                for argument in arguments.iter() {
+                    write!(f, " {argument}")?;
+                }
+                if !destinations.is_empty() {
+                    write!(f, " into {}", destinations.iter().join(" "))?;
+                }
+                write!(f, ";")
+            }
+            Self::Cast {
+                operands,
+                destination,
+                type_,
+            } => write!(
+                f,
+                "{opcode} {} into {destination} as {type_};",
+                operands.iter().join(" ")
+            ),
+            Self::Contains {
+                mapping,
+                key,
+                destination,
+            }
+            | Self::Get {
+                mapping,
+                key,
+                destination,
+            } => write!(f, "{opcode} {mapping}[{key}] into {destination};"),
+            Self::Core {
+                instruction,
+                operands,
+                destination,
+            } => write!(
+                f,
+                "{opcode} {} into {destination} as {};",
+                operands.iter().join(" "),
+                instruction.return_type()
+            ),
+            Self::GetOrUse {
+                mapping,
+                key,
+                default,
+                destination,
+            } => write!(f, "{opcode} {mapping}[{key}] {default} into {destination};"),
+            Self::Remove { mapping, key } => write!(f, "{opcode} {mapping}[{key}];"),
+            Self::Set { mapping, key, value } => write!(f, "{opcode} {value} into {mapping}[{key}];"),
+            Self::Ternary {
+                condition,
+                if_true,
+                if_false,
+                destination,
+            } => write!(f, "{opcode} {condition} {if_true} {if_false} into {destination};"),
+            Self::Unary {
+                operand, destination, ..
+            } => write!(f, "{opcode} {operand} into {destination};"),
+        }
+    }
+}
+
+/// An instruction, along with the span of the Leo expression or statement that it was generated from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoInstruction {
+    /// The operation performed by the instruction.
+    pub operation: AleoOperation,
+    /// The span of the Leo expression or statement that the instruction was generated from.
+    pub span: Span,
+}
+
+/// An input of a closure, function or finalize block, e.g. `input r0 as u8.private;`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoInput {
+    /// The register that holds the input.
+    pub register: AleoRegister,
+    /// The type of the input.
+    pub type_: AleoType,
+    /// The visibility of the input, which is `Mode::None` for the inputs of closures.
+    pub mode: Mode,
+}
+
+/// An output of a closure, function or finalize block, e.g. `output r1 as u8.private;`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoOutput {
+    /// The operand that is output.
+    pub operand: AleoOperand,
+    /// The type of the output.
+    pub type_: AleoType,
+    /// The visibility of the output, which is `Mode::None` for the outputs of closures.
+    pub mode: Mode,
+}
+
+/// The finalize block of a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoFinalize {
+    /// The name of the finalize block.
+    pub name: Symbol,
+    /// The inputs of the finalize block.
+    pub inputs: Vec<AleoInput>,
+    /// The commands of the finalize block.
+    pub instructions: Vec<AleoInstruction>,
+    /// The outputs of the finalize block.
+    pub outputs: Vec<AleoOutput>,
+}
+
+/// Whether a function is an Aleo closure or an Aleo function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AleoFunctionVariant {
+    /// A closure, generated from a standard Leo function.
+    Closure,
+    /// A function, generated from a Leo transition.
+    Function,
+}
+
+/// An Aleo closure or function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoFunction {
+    /// Whether the function is a closure or a function.
+    pub variant: AleoFunctionVariant,
+    /// The name of the function.
+    pub name: Symbol,
+    /// The inputs of the function.
+    pub inputs: Vec<AleoInput>,
+    /// The instructions of the function.
+    pub instructions: Vec<AleoInstruction>,
+    /// The outputs of the function, ending with the future of its finalize block if it has one.
+    pub outputs: Vec<AleoOutput>,
+    /// The finalize block of the function, if it has one.
+    pub finalize: Option<AleoFinalize>,
+}
+
+/// An Aleo struct or record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoComposite {
+    /// Whether the composite is a record.
+    pub is_record: bool,
+    /// The name of the composite.
+    pub name: Symbol,
+    /// The names, types and visibilities of the members, where only the members of records have a visibility.
+    pub members: Vec<(Symbol, AleoType, Mode)>,
+}
+
+impl fmt::Display for AleoComposite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.is_record {
+            true => writeln!(f, "record {}:", self.name)?,
+            false => writeln!(f, "struct {}:", self.name)?,
+        }
+        for (member, type_, mode) in self.members.iter() {
+            writeln!(f, "    {member} as {};", type_.with_visibility(*mode))?;
+        }
+        Ok(())
+    }
+}
+
+/// An Aleo mapping.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoMapping {
+    /// The name of the mapping.
+    pub name: Symbol,
+    /// The type of the keys, which are public.
+    pub key_type: AleoType,
+    /// The type of the values, which are public.
+    pub value_type: AleoType,
+}
+
+impl fmt::Display for AleoMapping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "mapping {}:", self.name)?;
+        writeln!(f, "\tkey as {};", self.key_type.with_visibility(Mode::Public))?;
+        writeln!(f, "\tvalue as {};", self.value_type.with_visibility(Mode::Public))
+    }
+}
+
+/// An Aleo program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoProgram {
+    /// The names of the imported programs.
+    pub imports: Vec<Symbol>,
+    /// The program id, e.g. `foo.aleo`.
+    pub id: ProgramId,
+    /// The structs and records, where each precedes its first use.
+    pub composites: Vec<AleoComposite>,
+    /// The mappings.
+    pub mappings: Vec<AleoMapping>,
+    /// The closures and functions, where each callee precedes its callers.
+    pub functions: Vec<AleoFunction>,
+    /// The features used by the program that snarkVM does not support yet, in the order in which they appear.
+    pub unsupported: Vec<UnsupportedFeature>,
+}
+
+/// A Leo feature whose Aleo instructions are generated, but rejected by snarkVM, e.g. a finalize block that returns a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsupportedFeature {
+    /// The feature, e.g. `returning values from finalize blocks`.
+    pub feature: &'static str,
+    /// The Leo code that uses the feature.
+    pub span: Span,
+}
+
+impl AleoProgram {
+    /// Prints the Aleo instructions of the program, along with a map from each instruction to the Leo code it was generated from.
+    pub fn print(&self) -> (String, AleoSourceMap) {
+        let mut printer = Printer::default();
+
+        for import in self.imports.iter() {
+            printer.line(format!("import {import}.aleo;"));
+        }
+        printer.line(format!("program {};", self.id));
+        printer.line("");
+        printer.push(self.composites.iter().join("\n"));
+        printer.line("");
+        printer.push(self.mappings.iter().join("\n"));
+
+        for (i, function) in self.functions.iter().enumerate() {
+            if i > 0 {
+                printer.line("");
+            }
+            let (keyword, name) = match function.variant {
+                AleoFunctionVariant::Closure => ("closure", function.name),
+                AleoFunctionVariant::Function => ("function", function.name),
+            };
+            printer.line(format!("{keyword} {name}:"));
+            printer.block(name, false, &function.inputs, &function.instructions, &function.outputs);
+
+            if let Some(finalize) = &function.finalize {
+                printer.line("");
+                printer.line(format!("finalize {}:", finalize.name));
+                printer.block(name, true, &finalize.inputs, &finalize.instructions, &finalize.outputs);
+            }
+        }
+
+        (printer.bytecode, printer.source_map)
+    }
+}
+
+impl fmt::Display for AleoProgram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.print().0)
+    }
+}
+
+/// Accumulates the printed Aleo instructions, along with their locations in the Leo code.
+#[derive(Default)]
+struct Printer {
+    /// The Aleo instructions printed so far.
+    bytecode: String,
+    /// The number of lines printed so far.
+    lines: usize,
+    /// The locations of the instructions printed so far.
+    source_map: AleoSourceMap,
+}
+
+impl Printer {
+    /// Prints `text`, which is empty or ends with a newline.
+    fn push(&mut self, text: impl AsRef<str>) {
+        let text = text.as_ref();
+        self.lines += text.matches('\n').count();
+        self.bytecode.push_str(text);
+    }
+
+    /// Prints `line`, followed by a newline.
+    fn line(&mut self, line: impl AsRef<str>) {
+        self.bytecode.push_str(line.as_ref());
+        self.bytecode.push('\n');
+        self.lines += 1;
+    }
+
+    /// Prints the inputs, instructions and outputs of a closure, function or finalize block.
+    fn block(
+        &mut self,
+        function: Symbol,
+        finalize: bool,
+        inputs: &[AleoInput],
+        instructions: &[AleoInstruction],
+        outputs: &[AleoOutput],
+    ) {
+        for input in inputs.iter() {
+            self.line(format!(
+                "    input {} as {};",
+                input.register,
+                input.type_.with_visibility(input.mode)
+            ));
+        }
+        for (index, instruction) in instructions.iter().enumerate() {
+            let text = instruction.operation.to_string();
+            let location = match instruction.span.is_dummy() {
+                true => None,
+                false => with_session_globals(|s| s.source_map.span_to_location(instruction.span)),
+            };
+            if let Some(location) = location {
+                self.source_map.instructions.push(InstructionLocation {
+                    function: function.to_string(),
+                    finalize,
+                    index,
+                    instruction: text.clone(),
+                    aleo_line: self.lines + 1,
+                    file: location.source_file.name.to_string(),
+                    line: location.line_start,
+                    column: location.col_start,
+                    span: instruction.span,
+                });
+            }
+            self.line(format!("    {text}"));
+        }
+        for output in outputs.iter() {
+            self.line(format!(
+                "    output {} as {};",
+                output.operand,
+                output.type_.with_visibility(output.mode)
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_ast::{Identifier, IntegerType};
+    use leo_span::{source_map::FileName, span::BytePos, symbol::create_session_if_not_set_then};
+
+    #[test]
+    fn test_print_and_annotate() {
+        create_session_if_not_set_then(|s| {
+            let source = "let a = 1u8 + b;\nlet c = a * a;\n";
+            let file = s
+                .source_map
+                .new_source(source, FileName::Custom("main.leo".to_string()));
+            let span = |lo: u32, hi: u32| Span::new(file.start_pos + BytePos(lo), file.start_pos + BytePos(hi));
+
+            let program = AleoProgram {
+                imports: Vec::new(),
+                id: ProgramId {
+                    name: Identifier::new(Symbol::intern("test")),
+                    network: Identifier::new(Symbol::intern("aleo")),
+                },
+                composites: Vec::new(),
+                mappings: Vec::new(),
+                functions: vec![AleoFunction {
+                    variant: AleoFunctionVariant::Function,
+                    name: Symbol::intern("main"),
+                    inputs: vec![AleoInput {
+                        register: AleoRegister(0),
+                        type_: AleoType::Literal(Type::Integer(IntegerType::U8)),
+                        mode: Mode::Private,
+                    }],
+                    instructions: vec![
+                        AleoInstruction {
+                            operation: AleoOperation::Binary {
+                                op: BinaryOperation::Add,
+                                left: AleoOperand::Literal(Literal::Integer(
+                                    IntegerType::U8,
+                                    "1".to_string(),
+                                    Default::default(),
+                                )),
+                                right: AleoOperand::Register(AleoRegister(0)),
+                                destination: AleoRegister(1),
+                                operand_type: None,
+                            },
+                            span: span(8, 15),
+                        },
+                        AleoInstruction {
+                            operation: AleoOperation::Binary {
+                                op: BinaryOperation::Mul,
+                                left: AleoOperand::Register(AleoRegister(1)),
+                                right: AleoOperand::Register(AleoRegister(1)),
+                                destination: AleoRegister(2),
+                                operand_type: None,
+                            },
+                            span: span(25, 30),
+                        },
+                    ],
+                    outputs: vec![AleoOutput {
+                        operand: AleoOperand::Register(AleoRegister(2)),
+                        type_: AleoType::Literal(Type::Integer(IntegerType::U8)),
+                        mode: Mode::Private,
+                    }],
+                    finalize: None,
+                }],
+                unsupported: Vec::new(),
+            };
+            let (bytecode, source_map) = program.print();
+
+            assert_eq!(
+                bytecode,
+                "program test.aleo;\n\n\nfunction main:\n    input r0 as u8.private;\n    add 1u8 r0 into r1;\n    mul r1 r1 into r2;\n    output r2 as u8.private;\n"
+            );
+            let locations: Vec<_> = source_map
+                .instructions
+                .iter()
+                .map(|location| {
+                    (
+                        location.function.as_str(),
+                        location.index,
+                        location.aleo_line,
+                        location.line,
+                        location.column,
+                    )
+                })
+                .collect();
+            assert_eq!(locations, vec![("main", 0, 6, 1, 9), ("main", 1, 7, 2, 9)]);
+
+            assert_eq!(
+                source_map.annotate(&bytecode),
+                "program test.aleo;\n\n\nfunction main:\n    input r0 as u8.private;\n    // main.leo:1\n    add 1u8 r0 into r1;\n    // main.leo:2\n    mul r1 r1 into r2;\n    output r2 as u8.private;\n"
+            );
+        });
+    }
+}
@@ -17,6 +17,15 @@
 pub mod generator;
 pub use generator::*;
 
+pub mod ir;
+pub use ir::*;
+
+pub mod source_map;
+pub use source_map::*;
+
+pub mod stats;
+pub use stats::*;
+
 mod visit_expressions;
 
 mod visit_program;
@@ -32,13 +41,15 @@ use leo_ast::Ast;
 use leo_errors::Result;
 
 impl<'a> Pass for CodeGenerator<'a> {
+    const NAME: &'static str = "codegen";
+
     type Input = (&'a Ast, &'a SymbolTable, &'a StructGraph, &'a CallGraph);
-    type Output = Result<String>;
+    type Output = Result<AleoProgram>;
 
     fn do_pass((ast, symbol_table, struct_graph, call_graph): Self::Input) -> Self::Output {
         let mut generator = Self::new(symbol_table, struct_graph, call_graph);
-        let bytecode = generator.visit_program(ast.as_repr());
+        let program = generator.visit_program(ast.as_repr());
 
-        Ok(bytecode)
+        Ok(program)
     }
 }
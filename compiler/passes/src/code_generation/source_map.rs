@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Span;
+
+use serde::Serialize;
+use std::{fmt::Write as _, path::Path};
+
+/// The Leo source location of an Aleo instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct InstructionLocation {
+    /// The closure or function that contains the instruction.
+    pub function: String,
+    /// Whether the instruction is in the finalize block of the function.
+    pub finalize: bool,
+    /// The index of the instruction in its closure, function or finalize block.
+    pub index: usize,
+    /// The instruction.
+    pub instruction: String,
+    /// The 1-based line of the instruction in the Aleo program.
+    pub aleo_line: usize,
+    /// The Leo file that the instruction was generated from.
+    pub file: String,
+    /// The 1-based line in the Leo file.
+    pub line: usize,
+    /// The 1-based column in the Leo file.
+    pub column: usize,
+    /// The span of the Leo expression or statement that the instruction was generated from.
+    #[serde(skip)]
+    pub span: Span,
+}
+
+/// Maps the instructions of an Aleo program to the Leo code they were generated from.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct AleoSourceMap {
+    /// The locations of the instructions, in the order in which they appear in the Aleo program.
+    pub instructions: Vec<InstructionLocation>,
+}
+
+impl AleoSourceMap {
+    /// Makes the file names relative to `base`, if they are inside of it.
+    pub fn strip_prefix(&mut self, base: &Path) {
+        for location in self.instructions.iter_mut() {
+            if let Ok(file) = Path::new(&location.file).strip_prefix(base) {
+                location.file = file.display().to_string();
+            }
+        }
+    }
+
+    /// Returns the Aleo program with a `// file:line` comment before each instruction generated from a new Leo line.
+    pub fn annotate(&self, bytecode: &str) -> String {
+        let mut locations = self.instructions.iter().peekable();
+        let mut previous = None;
+        let mut annotated = String::with_capacity(bytecode.len());
+
+        for (line_index, line) in bytecode.split_inclusive('\n').enumerate() {
+            if let Some(location) = locations.next_if(|location| location.aleo_line == line_index + 1) {
+                let source = (&location.file, location.line);
+                if previous != Some(source) {
+                    let indentation = &line[..line.len() - line.trim_start().len()];
+                    writeln!(annotated, "{indentation}// {}:{}", location.file, location.line)
+                        .expect("failed to write to string");
+                    previous = Some(source);
+                }
+            } else if !line.starts_with(' ') {
+                // A new block starts, so the next instruction is annotated regardless of the previous one.
+                previous = None;
+            }
+            annotated.push_str(line);
+        }
+
+        annotated
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AleoFunctionVariant, AleoInstruction, AleoOperation, AleoProgram, AleoType};
+
+use leo_core::{estimated_cast_constraints, estimated_constraints};
+use leo_span::Symbol;
+
+use serde::Serialize;
+use std::{collections::BTreeMap, fmt};
+
+/// The cost of the instructions with the same opcode in a closure, function or finalize block.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct OpcodeStats {
+    /// The number of instructions with the opcode.
+    pub count: usize,
+    /// The estimated number of constraints of the instructions, or `None` if they are not proven.
+    pub constraints: Option<u64>,
+}
+
+/// The cost of a closure, function or finalize block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BlockStats {
+    /// The name of the closure or function, or of the function that the finalize block belongs to.
+    pub name: String,
+    /// Either `closure`, `function` or `finalize`.
+    pub kind: &'static str,
+    /// The number of instructions.
+    pub instructions: usize,
+    /// The estimated number of constraints, or `None` for a finalize block, which is not proven.
+    pub constraints: Option<u64>,
+    /// The number and cost of the instructions with each opcode.
+    pub opcodes: BTreeMap<String, OpcodeStats>,
+}
+
+impl BlockStats {
+    /// Counts the instructions of a block, where `closures` holds the estimated constraints of the closures it may call,
+    /// or is `None` if the block is not proven.
+    fn new(
+        name: String,
+        kind: &'static str,
+        instructions: &[AleoInstruction],
+        closures: Option<&BTreeMap<Symbol, u64>>,
+    ) -> Self {
+        let mut opcodes = BTreeMap::<String, OpcodeStats>::new();
+        for instruction in instructions.iter() {
+            let opcode = instruction.operation.opcode().to_string();
+            let constraints = closures.map(|closures| match &instruction.operation {
+                // A call to a closure is proven along with the caller, whereas a call to a function of another program is proven on its own.
+                AleoOperation::Call {
+                    program: None,
+                    function,
+                    ..
+                } => closures.get(function).copied().unwrap_or(0),
+                AleoOperation::Call { .. } => 0,
+                AleoOperation::Cast {
+                    type_: AleoType::Literal(type_),
+                    ..
+                } => estimated_cast_constraints(type_),
+                AleoOperation::Cast { .. } => 0,
+                AleoOperation::Binary { operand_type, .. } | AleoOperation::Unary { operand_type, .. } => {
+                    estimated_constraints(&opcode, operand_type.as_ref()).unwrap_or(0)
+                }
+                _ => estimated_constraints(&opcode, None).unwrap_or(0),
+            });
+
+            let stats = opcodes.entry(opcode).or_default();
+            stats.count += 1;
+            stats.constraints = constraints.map(|constraints| stats.constraints.unwrap_or(0) + constraints);
+        }
+
+        Self {
+            name,
+            kind,
+            instructions: instructions.len(),
+            constraints: closures.map(|_| opcodes.values().filter_map(|stats| stats.constraints).sum()),
+            opcodes,
+        }
+    }
+}
+
+/// The cost of each closure, function and finalize block of a program, in the order they are declared.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ProgramStats {
+    /// The program id, e.g. `foo.aleo`.
+    pub program: String,
+    /// The cost of each closure, function and finalize block.
+    pub blocks: Vec<BlockStats>,
+}
+
+impl AleoProgram {
+    /// Returns the number of instructions by opcode and the estimated number of constraints of each block of the program.
+    pub fn stats(&self) -> ProgramStats {
+        // The estimated constraints of the closures declared so far.
+        // Note that callees precede their callers, so each called closure is already known.
+        let mut closures = BTreeMap::new();
+        let mut blocks = Vec::new();
+
+        for function in self.functions.iter() {
+            let kind = match function.variant {
+                AleoFunctionVariant::Closure => "closure",
+                AleoFunctionVariant::Function => "function",
+            };
+            let stats = BlockStats::new(function.name.to_string(), kind, &function.instructions, Some(&closures));
+            if let (AleoFunctionVariant::Closure, Some(constraints)) = (function.variant, stats.constraints) {
+                closures.insert(function.name, constraints);
+            }
+            blocks.push(stats);
+
+            if let Some(finalize) = &function.finalize {
+                blocks.push(BlockStats::new(
+                    finalize.name.to_string(),
+                    "finalize",
+                    &finalize.instructions,
+                    None,
+                ));
+            }
+        }
+
+        ProgramStats {
+            program: self.id.to_string(),
+            blocks,
+        }
+    }
+}
+
+impl fmt::Display for ProgramStats {
+    /// Prints a table with a row for each block, followed by a row for each of its opcodes.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let constraints = |constraints: Option<u64>| constraints.map_or_else(|| "-".to_string(), |c| c.to_string());
+        let width = self
+            .blocks
+            .iter()
+            .flat_map(|block| {
+                std::iter::once(block.name.len()).chain(block.opcodes.keys().map(|opcode| opcode.len() + 2))
+            })
+            .fold("block".len(), usize::max);
+
+        writeln!(
+            f,
+            "{:<width$}  {:<8}  {:>12}  {:>11}",
+            "block", "kind", "instructions", "constraints"
+        )?;
+        for block in self.blocks.iter() {
+            writeln!(
+                f,
+                "{:<width$}  {:<8}  {:>12}  {:>11}",
+                block.name,
+                block.kind,
+                block.instructions,
+                constraints(block.constraints)
+            )?;
+            for (opcode, stats) in block.opcodes.iter() {
+                writeln!(
+                    f,
+                    "{:<width$}  {:<8}  {:>12}  {:>11}",
+                    format!("  {opcode}"),
+                    "",
+                    stats.count,
+                    constraints(stats.constraints)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AleoFinalize, AleoFunction, AleoOperand, AleoRegister};
+
+    use leo_ast::{BinaryOperation, Identifier, IntegerType, ProgramId, Type};
+    use leo_span::{symbol::create_session_if_not_set_then, Span};
+
+    fn instruction(operation: AleoOperation) -> AleoInstruction {
+        AleoInstruction {
+            operation,
+            span: Span::default(),
+        }
+    }
+
+    fn register(index: u64) -> AleoOperand {
+        AleoOperand::Register(AleoRegister(index))
+    }
+
+    fn binary(op: BinaryOperation, operand_type: Type) -> AleoInstruction {
+        instruction(AleoOperation::Binary {
+            op,
+            left: register(0),
+            right: register(0),
+            destination: AleoRegister(1),
+            operand_type: Some(operand_type),
+        })
+    }
+
+    fn call(program: Option<&str>, function: &str) -> AleoInstruction {
+        instruction(AleoOperation::Call {
+            program: program.map(Symbol::intern),
+            function: Symbol::intern(function),
+            arguments: vec![register(0)],
+            destinations: vec![AleoRegister(1)],
+        })
+    }
+
+    fn cast(type_: AleoType) -> AleoInstruction {
+        instruction(AleoOperation::Cast {
+            operands: vec![register(0)],
+            destination: AleoRegister(1),
+            type_,
+        })
+    }
+
+    fn function(variant: AleoFunctionVariant, name: &str, instructions: Vec<AleoInstruction>) -> AleoFunction {
+        AleoFunction {
+            variant,
+            name: Symbol::intern(name),
+            inputs: Vec::new(),
+            instructions,
+            outputs: Vec::new(),
+            finalize: None,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        create_session_if_not_set_then(|_| {
+            let mut main = function(
+                AleoFunctionVariant::Function,
+                "main",
+                vec![
+                    binary(BinaryOperation::Add, Type::Integer(IntegerType::U8)),
+                    call(None, "helper"),
+                    binary(BinaryOperation::Add, Type::Integer(IntegerType::U128)),
+                    call(Some("other"), "transfer"),
+                    cast(AleoType::Literal(Type::Integer(IntegerType::U8))),
+                    cast(AleoType::Struct(Symbol::intern("point"))),
+                ],
+            );
+            main.finalize = Some(AleoFinalize {
+                name: Symbol::intern("main"),
+                inputs: Vec::new(),
+                instructions: vec![binary(BinaryOperation::Add, Type::Integer(IntegerType::U64))],
+                outputs: Vec::new(),
+            });
+            let program = AleoProgram {
+                imports: vec![Symbol::intern("other")],
+                id: ProgramId {
+                    name: Identifier::new(Symbol::intern("test")),
+                    network: Identifier::new(Symbol::intern("aleo")),
+                },
+                composites: Vec::new(),
+                mappings: Vec::new(),
+                functions: vec![
+                    function(
+                        AleoFunctionVariant::Closure,
+                        "helper",
+                        vec![
+                            binary(BinaryOperation::Mul, Type::Field),
+                            binary(BinaryOperation::Mul, Type::Field),
+                        ],
+                    ),
+                    main,
+                ],
+                unsupported: Vec::new(),
+            };
+            let stats = program.stats();
+
+            let blocks: Vec<_> = stats
+                .blocks
+                .iter()
+                .map(|block| (block.name.as_str(), block.kind, block.instructions, block.constraints))
+                .collect();
+            assert_eq!(
+                blocks,
+                vec![
+                    ("helper", "closure", 2, Some(2)),
+                    ("main", "function", 6, Some(150)),
+                    ("main", "finalize", 1, None),
+                ]
+            );
+            assert_eq!(
+                stats.blocks[1].opcodes["cast"],
+                OpcodeStats {
+                    count: 2,
+                    constraints: Some(8)
+                }
+            );
+            assert_eq!(
+                stats.blocks[1].opcodes["call"],
+                OpcodeStats {
+                    count: 2,
+                    constraints: Some(2)
+                }
+            );
+
+            assert_eq!(
+                stats.to_string(),
+                "\
+block   kind      instructions  constraints
+helper  closure              2            2
+  mul                        2            2
+main    function             6          150
+  add                        2          140
+  call                       2            2
+  cast                       2            8
+main    finalize             1            -
+  add                        1            -
+"
+            );
+        });
+    }
+}
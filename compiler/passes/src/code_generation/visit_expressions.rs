@@ -14,253 +14,371 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{AleoInstruction, AleoOperand, AleoOperation, AleoType, CodeGenerator, UnsupportedFeature};
 use leo_ast::{
-    AccessExpression, AssociatedFunction, BinaryExpression, BinaryOperation, CallExpression, ErrExpression, Expression,
-    Identifier, Literal, MemberAccess, StructExpression, TernaryExpression, TupleExpression, Type, UnaryExpression,
-    UnaryOperation, UnitExpression,
+    AccessExpression, ArrayAccess, ArrayExpression, AssociatedFunction, BinaryExpression, CallExpression,
+    CastExpression, ErrExpression, Expression, Identifier, Literal, MemberAccess, StructExpression, TernaryExpression,
+    TupleExpression, Type, UnaryExpression, UnitExpression,
 };
-use leo_span::sym;
-use std::borrow::Borrow;
+use leo_core::CoreInstruction;
+use leo_span::{sym, Symbol};
 
-use std::fmt::Write as _;
+use itertools::Itertools;
+use std::borrow::Borrow;
 
 /// Implement the necessary methods to visit nodes in the AST.
 // Note: We opt for this option instead of using `Visitor` and `Director` because this pass requires
 // a post-order traversal of the AST. This is sufficient since this implementation is intended to be
 // a prototype. The production implementation will require a redesign of `Director`.
 impl<'a> CodeGenerator<'a> {
-    pub(crate) fn visit_expression(&mut self, input: &'a Expression) -> (String, String) {
+    /// Returns the operands holding the value of the expression, along with the instructions that compute them.
+    /// Note that tuples and calls to functions with multiple outputs have multiple operands, and unit expressions have none.
+    pub(crate) fn visit_expression(&mut self, input: &'a Expression) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
+        let single = |(operand, instructions)| (vec![operand], instructions);
         match input {
             Expression::Access(expr) => self.visit_access(expr),
-            Expression::Binary(expr) => self.visit_binary(expr),
+            Expression::Array(expr) => single(self.visit_array(expr)),
+            Expression::Binary(expr) => single(self.visit_binary(expr)),
             Expression::Call(expr) => self.visit_call(expr),
-            Expression::Struct(expr) => self.visit_struct_init(expr),
+            Expression::Cast(expr) => single(self.visit_cast(expr)),
+            Expression::Struct(expr) => single(self.visit_struct_init(expr)),
             Expression::Err(expr) => self.visit_err(expr),
-            Expression::Identifier(expr) => self.visit_identifier(expr),
-            Expression::Literal(expr) => self.visit_value(expr),
-            Expression::Ternary(expr) => self.visit_ternary(expr),
+            Expression::Identifier(expr) => single(self.visit_identifier(expr)),
+            Expression::Literal(expr) => single(self.visit_value(expr)),
+            Expression::Match(_) => {
+                unreachable!("`MatchExpression`s should not be in the AST at this phase of compilation.")
+            }
+            Expression::Ternary(expr) => single(self.visit_ternary(expr)),
             Expression::Tuple(expr) => self.visit_tuple(expr),
-            Expression::Unary(expr) => self.visit_unary(expr),
+            Expression::Unary(expr) => single(self.visit_unary(expr)),
             Expression::Unit(expr) => self.visit_unit(expr),
         }
     }
 
-    fn visit_identifier(&mut self, input: &'a Identifier) -> (String, String) {
-        (self.variable_mapping.get(&input.name).unwrap().clone(), String::new())
+    /// Returns the single operand holding the value of the expression, along with the instructions that compute it.
+    pub(crate) fn visit_operand(&mut self, input: &'a Expression) -> (AleoOperand, Vec<AleoInstruction>) {
+        let (operands, instructions) = self.visit_expression(input);
+        match operands.into_iter().exactly_one() {
+            Ok(operand) => (operand, instructions),
+            Err(_) => unreachable!("Type checking guarantees that the expression has a single value."),
+        }
     }
 
-    fn visit_err(&mut self, _input: &'a ErrExpression) -> (String, String) {
+    fn visit_identifier(&mut self, input: &'a Identifier) -> (AleoOperand, Vec<AleoInstruction>) {
+        (self.variable_mapping.get(&input.name).unwrap().clone(), Vec::new())
+    }
+
+    fn visit_err(&mut self, _input: &'a ErrExpression) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
         unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
     }
 
-    fn visit_value(&mut self, input: &'a Literal) -> (String, String) {
-        (format!("{input}"), String::new())
+    fn visit_value(&mut self, input: &'a Literal) -> (AleoOperand, Vec<AleoInstruction>) {
+        (AleoOperand::Literal(input.clone()), Vec::new())
     }
 
-    fn visit_binary(&mut self, input: &'a BinaryExpression) -> (String, String) {
-        let (left_operand, left_instructions) = self.visit_expression(&input.left);
-        let (right_operand, right_instructions) = self.visit_expression(&input.right);
-
-        let opcode = match input.op {
-            BinaryOperation::Add => String::from("add"),
-            BinaryOperation::AddWrapped => String::from("add.w"),
-            BinaryOperation::And => String::from("and"),
-            BinaryOperation::BitwiseAnd => String::from("and"),
-            BinaryOperation::Div => String::from("div"),
-            BinaryOperation::DivWrapped => String::from("div.w"),
-            BinaryOperation::Eq => String::from("is.eq"),
-            BinaryOperation::Gte => String::from("gte"),
-            BinaryOperation::Gt => String::from("gt"),
-            BinaryOperation::Lte => String::from("lte"),
-            BinaryOperation::Lt => String::from("lt"),
-            BinaryOperation::Mod => String::from("mod"),
-            BinaryOperation::Mul => String::from("mul"),
-            BinaryOperation::MulWrapped => String::from("mul.w"),
-            BinaryOperation::Nand => String::from("nand"),
-            BinaryOperation::Neq => String::from("is.neq"),
-            BinaryOperation::Nor => String::from("nor"),
-            BinaryOperation::Or => String::from("or"),
-            BinaryOperation::BitwiseOr => String::from("or"),
-            BinaryOperation::Pow => String::from("pow"),
-            BinaryOperation::PowWrapped => String::from("pow.w"),
-            BinaryOperation::Rem => String::from("rem"),
-            BinaryOperation::RemWrapped => String::from("rem.w"),
-            BinaryOperation::Shl => String::from("shl"),
-            BinaryOperation::ShlWrapped => String::from("shl.w"),
-            BinaryOperation::Shr => String::from("shr"),
-            BinaryOperation::ShrWrapped => String::from("shr.w"),
-            BinaryOperation::Sub => String::from("sub"),
-            BinaryOperation::SubWrapped => String::from("sub.w"),
-            BinaryOperation::Xor => String::from("xor"),
+    fn visit_array(&mut self, input: &'a ArrayExpression) -> (AleoOperand, Vec<AleoInstruction>) {
+        // Note that this unwrap is safe since type checking determines the type of each array expression.
+        let array_type = Type::Array(input.type_.borrow().clone().unwrap());
+
+        // Visit each array element and accumulate instructions from expressions.
+        let mut instructions = Vec::new();
+        let mut operands = Vec::with_capacity(input.elements.len());
+        for element in input.elements.iter() {
+            let (element_operand, element_instructions) = self.visit_operand(element);
+            instructions.extend(element_instructions);
+            operands.push(element_operand);
+        }
+
+        let destination = self.next_register();
+        let operation = AleoOperation::Cast {
+            operands,
+            destination,
+            type_: self.visit_type(&array_type),
         };
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
 
-        let destination_register = format!("r{}", self.next_register);
-        let binary_instruction = format!("    {opcode} {left_operand} {right_operand} into {destination_register};\n",);
+        (AleoOperand::Register(destination), instructions)
+    }
 
-        // Increment the register counter.
-        self.next_register += 1;
+    fn visit_binary(&mut self, input: &'a BinaryExpression) -> (AleoOperand, Vec<AleoInstruction>) {
+        let (left, left_instructions) = self.visit_operand(&input.left);
+        let (right, right_instructions) = self.visit_operand(&input.right);
+
+        let destination = self.next_register();
+        let operation = AleoOperation::Binary {
+            op: input.op,
+            left,
+            right,
+            destination,
+            operand_type: input.operand_type.borrow().clone(),
+        };
 
         // Concatenate the instructions.
         let mut instructions = left_instructions;
-        instructions.push_str(&right_instructions);
-        instructions.push_str(&binary_instruction);
+        instructions.extend(right_instructions);
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
 
-        (destination_register, instructions)
+        (AleoOperand::Register(destination), instructions)
     }
 
-    fn visit_unary(&mut self, input: &'a UnaryExpression) -> (String, String) {
-        let (expression_operand, expression_instructions) = self.visit_expression(&input.receiver);
-
-        let opcode = match input.op {
-            UnaryOperation::Abs => String::from("abs"),
-            UnaryOperation::AbsWrapped => String::from("abs.w"),
-            UnaryOperation::Double => String::from("double"),
-            UnaryOperation::Inverse => String::from("inv"),
-            UnaryOperation::Not => String::from("not"),
-            UnaryOperation::Negate => String::from("neg"),
-            UnaryOperation::Square => String::from("square"),
-            UnaryOperation::SquareRoot => String::from("sqrt"),
+    fn visit_cast(&mut self, input: &'a CastExpression) -> (AleoOperand, Vec<AleoInstruction>) {
+        let (operand, mut instructions) = self.visit_operand(&input.expression);
+
+        let destination = self.next_register();
+        let operation = AleoOperation::Cast {
+            operands: vec![operand],
+            destination,
+            type_: self.visit_type(&input.type_),
         };
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
 
-        let destination_register = format!("r{}", self.next_register);
-        let unary_instruction = format!("    {opcode} {expression_operand} into {destination_register};\n");
+        (AleoOperand::Register(destination), instructions)
+    }
 
-        // Increment the register counter.
-        self.next_register += 1;
+    fn visit_unary(&mut self, input: &'a UnaryExpression) -> (AleoOperand, Vec<AleoInstruction>) {
+        let (operand, mut instructions) = self.visit_operand(&input.receiver);
 
-        // Concatenate the instructions.
-        let mut instructions = expression_instructions;
-        instructions.push_str(&unary_instruction);
+        let destination = self.next_register();
+        let operation = AleoOperation::Unary {
+            op: input.op,
+            operand,
+            destination,
+            operand_type: input.operand_type.borrow().clone(),
+        };
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
 
-        (destination_register, instructions)
+        (AleoOperand::Register(destination), instructions)
     }
 
-    fn visit_ternary(&mut self, input: &'a TernaryExpression) -> (String, String) {
-        let (condition_operand, condition_instructions) = self.visit_expression(&input.condition);
-        let (if_true_operand, if_true_instructions) = self.visit_expression(&input.if_true);
-        let (if_false_operand, if_false_instructions) = self.visit_expression(&input.if_false);
-
-        let destination_register = format!("r{}", self.next_register);
-        let ternary_instruction = format!(
-            "    ternary {condition_operand} {if_true_operand} {if_false_operand} into {destination_register};\n",
-        );
-
-        // Increment the register counter.
-        self.next_register += 1;
+    fn visit_ternary(&mut self, input: &'a TernaryExpression) -> (AleoOperand, Vec<AleoInstruction>) {
+        let (condition, condition_instructions) = self.visit_operand(&input.condition);
+        let (if_true, if_true_instructions) = self.visit_operand(&input.if_true);
+        let (if_false, if_false_instructions) = self.visit_operand(&input.if_false);
+
+        let destination = self.next_register();
+        let operation = AleoOperation::Ternary {
+            condition,
+            if_true,
+            if_false,
+            destination,
+        };
 
         // Concatenate the instructions.
         let mut instructions = condition_instructions;
-        instructions.push_str(&if_true_instructions);
-        instructions.push_str(&if_false_instructions);
-        instructions.push_str(&ternary_instruction);
-
-        (destination_register, instructions)
+        instructions.extend(if_true_instructions);
+        instructions.extend(if_false_instructions);
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
+
+        (AleoOperand::Register(destination), instructions)
     }
 
-    fn visit_struct_init(&mut self, input: &'a StructExpression) -> (String, String) {
+    fn visit_struct_init(&mut self, input: &'a StructExpression) -> (AleoOperand, Vec<AleoInstruction>) {
         // Lookup struct or record.
-        let name = if let Some((is_record, type_)) = self.composite_mapping.get(&input.name.name) {
+        let type_ = if let Some((is_record, _)) = self.composite_mapping.get(&input.name.name) {
             if *is_record {
-                // record.private;
-                format!("{}.{type_}", input.name)
+                AleoType::Record(input.name.name)
             } else {
-                // foo; // no visibility for structs
-                input.name.to_string()
+                AleoType::Struct(input.name.name)
             }
         } else {
             unreachable!("All composite types should be known at this phase of compilation")
         };
 
-        // Initialize instruction builder strings.
-        let mut instructions = String::new();
-        let mut struct_init_instruction = String::from("    cast ");
-
         // Visit each struct member and accumulate instructions from expressions.
+        let mut instructions = Vec::new();
+        let mut operands = Vec::with_capacity(input.members.len());
         for member in input.members.iter() {
-            let operand = if let Some(expr) = member.expression.as_ref() {
+            let (operand, member_instructions) = match member.expression.as_ref() {
                 // Visit variable expression.
-                let (variable_operand, variable_instructions) = self.visit_expression(expr);
-                instructions.push_str(&variable_instructions);
-
-                variable_operand
-            } else {
-                // Push operand identifier.
-                let (ident_operand, ident_instructions) = self.visit_identifier(&member.identifier);
-                instructions.push_str(&ident_instructions);
-
-                ident_operand
+                Some(expr) => self.visit_operand(expr),
+                // Visit operand identifier.
+                None => self.visit_identifier(&member.identifier),
             };
-
-            // Push operand name to struct init instruction.
-            write!(struct_init_instruction, "{operand} ").expect("failed to write to string");
+            instructions.extend(member_instructions);
+            operands.push(operand);
         }
 
-        // Push destination register to struct init instruction.
-        let destination_register = format!("r{}", self.next_register);
-        writeln!(struct_init_instruction, "into {destination_register} as {name};",)
-            .expect("failed to write to string");
+        let destination = self.next_register();
+        let operation = AleoOperation::Cast {
+            operands,
+            destination,
+            type_,
+        };
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
 
-        instructions.push_str(&struct_init_instruction);
+        (AleoOperand::Register(destination), instructions)
+    }
 
-        // Increment the register counter.
-        self.next_register += 1;
+    fn visit_member_access(&mut self, input: &'a MemberAccess) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
+        // Note that the only member of `self` is `caller`.
+        let operand = match input.inner.as_ref() {
+            Expression::Identifier(identifier) if identifier.name == sym::SelfLower => {
+                if self.in_finalize {
+                    self.unsupported.push(UnsupportedFeature {
+                        feature: "`self.caller` in finalize blocks",
+                        span: input.span,
+                    });
+                }
+                AleoOperand::Caller
+            }
+            inner => {
+                let (inner, _inner_instructions) = self.visit_operand(inner);
+                AleoOperand::Member(Box::new(inner), input.name.name)
+            }
+        };
 
-        (destination_register, instructions)
+        (vec![operand], Vec::new())
     }
 
-    fn visit_member_access(&mut self, input: &'a MemberAccess) -> (String, String) {
-        let (inner_struct, _inner_instructions) = self.visit_expression(&input.inner);
-        let member_access_instruction = format!("{inner_struct}.{}", input.name);
+    // Arrays are lowered to structs whose members are named `e0`, `e1`, and so on.
+    fn visit_array_access(&mut self, input: &'a ArrayAccess) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
+        let (inner, _inner_instructions) = self.visit_operand(&input.array);
+        let member = match input.index.as_ref() {
+            Expression::Literal(Literal::Integer(_, index, _)) => Symbol::intern(&format!("e{index}")),
+            _ => unreachable!("Loop unrolling guarantees that array indices are literals."),
+        };
 
-        (member_access_instruction, String::new())
+        (vec![AleoOperand::Member(Box::new(inner), member)], Vec::new())
     }
 
     // Pedersen64::hash() -> hash.ped64
-    fn visit_associated_function(&mut self, input: &'a AssociatedFunction) -> (String, String) {
-        // Write identifier as opcode. `Pedersen64` -> `ped64`.
-        let symbol: &str = if let Type::Identifier(identifier) = input.ty {
-            match identifier.name {
-                sym::BHP256 => "bhp256",
-                sym::BHP512 => "bhp512",
-                sym::BHP768 => "bhp768",
-                sym::BHP1024 => "bhp1024",
-                sym::Pedersen64 => "ped64",
-                sym::Pedersen128 => "ped128",
-                sym::Poseidon2 => "psd2",
-                sym::Poseidon4 => "psd4",
-                sym::Poseidon8 => "psd8",
-                _ => unreachable!("All core function calls should be known at this time."),
+    fn visit_associated_function(&mut self, input: &'a AssociatedFunction) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
+        let module = match input.ty {
+            Type::Identifier(identifier) if identifier.name == sym::Mapping => {
+                return self.visit_mapping_operation(input);
             }
-        } else {
-            unreachable!("All core function should be known at this time.")
+            Type::Identifier(identifier) => identifier.name,
+            _ => unreachable!("All core function should be known at this time."),
+        };
+        let instruction = match CoreInstruction::from_symbols(module, input.name.name) {
+            Some(instruction) => instruction,
+            None => unreachable!("All core function calls should be known at this time."),
         };
-
-        // Construct associated function call.
-        let mut associated_function_call = format!("    {}.{symbol} ", input.name);
-        let mut instructions = String::new();
 
         // Visit each function argument and accumulate instructions from expressions.
+        let mut instructions = Vec::new();
+        let mut operands = Vec::with_capacity(input.args.len());
         for arg in input.args.iter() {
-            let (arg_string, arg_instructions) = self.visit_expression(arg);
-            write!(associated_function_call, "{arg_string} ").expect("failed to write associated function argument");
-            instructions.push_str(&arg_instructions);
+            let (operand, arg_instructions) = self.visit_operand(arg);
+            instructions.extend(arg_instructions);
+            operands.push(operand);
         }
 
-        // Push destination register to associated function call instruction.
-        let destination_register = format!("r{}", self.next_register);
-        writeln!(associated_function_call, "into {destination_register};")
-            .expect("failed to write dest register for associated function");
-        instructions.push_str(&associated_function_call);
+        let destination = self.next_register();
+        let operation = AleoOperation::Core {
+            instruction,
+            operands,
+            destination,
+        };
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
+
+        (vec![AleoOperand::Register(destination)], instructions)
+    }
 
-        // Increment the register counter.
-        self.next_register += 1;
+    /// Generates the command for a mapping operation, e.g. `get balances[r0] into r1;`.
+    /// Note that `remove` and `set` do not have a destination register.
+    fn visit_mapping_operation(&mut self, input: &'a AssociatedFunction) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
+        let mapping = match &input.args[0] {
+            Expression::Identifier(identifier) => identifier.name,
+            _ => unreachable!("Type checking guarantees that the first argument is a mapping."),
+        };
 
-        (destination_register, instructions)
+        // Visit the key and the value, accumulating instructions from expressions.
+        let mut instructions = Vec::new();
+        let mut operands = Vec::with_capacity(input.args.len() - 1);
+        for arg in input.args[1..].iter() {
+            let (operand, arg_instructions) = self.visit_operand(arg);
+            instructions.extend(arg_instructions);
+            operands.push(operand);
+        }
+        let mut operands = operands.into_iter();
+        let key = operands.next().unwrap();
+
+        let (operation, destination) = match input.name.name {
+            sym::contains => {
+                let destination = self.next_register();
+                (
+                    AleoOperation::Contains {
+                        mapping,
+                        key,
+                        destination,
+                    },
+                    Some(destination),
+                )
+            }
+            sym::get => {
+                let destination = self.next_register();
+                (
+                    AleoOperation::Get {
+                        mapping,
+                        key,
+                        destination,
+                    },
+                    Some(destination),
+                )
+            }
+            // Aleo instructions do not store the default, so it is written back, which leaves an existing value unchanged.
+            sym::get_or_init => {
+                let destination = self.next_register();
+                let default = operands.next().unwrap();
+                instructions.push(AleoInstruction {
+                    operation: AleoOperation::GetOrUse {
+                        mapping,
+                        key: key.clone(),
+                        default,
+                        destination,
+                    },
+                    span: input.span,
+                });
+                let operation = AleoOperation::Set {
+                    mapping,
+                    key,
+                    value: AleoOperand::Register(destination),
+                };
+                (operation, Some(destination))
+            }
+            sym::remove => (AleoOperation::Remove { mapping, key }, None),
+            sym::set => {
+                let value = operands.next().unwrap();
+                (AleoOperation::Set { mapping, key, value }, None)
+            }
+            _ => unreachable!("Type checking guarantees that the mapping operation is valid."),
+        };
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
+
+        (
+            destination.into_iter().map(AleoOperand::Register).collect(),
+            instructions,
+        )
     }
 
-    fn visit_access(&mut self, input: &'a AccessExpression) -> (String, String) {
+    fn visit_access(&mut self, input: &'a AccessExpression) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
         match input {
+            AccessExpression::Array(access) => self.visit_array_access(access),
             AccessExpression::Member(access) => self.visit_member_access(access),
             AccessExpression::AssociatedConstant(_) => todo!(), // Associated constants are not supported in AVM yet.
             AccessExpression::AssociatedFunction(function) => self.visit_associated_function(function),
@@ -268,18 +386,20 @@ impl<'a> CodeGenerator<'a> {
         }
     }
 
-    // TODO: Cleanup
-    fn visit_call(&mut self, input: &'a CallExpression) -> (String, String) {
-        let mut call_instruction = match &input.external {
-            Some(external) => format!("    call {external}.aleo/{}", input.function),
-            None => format!("    call {}", input.function),
+    fn visit_call(&mut self, input: &'a CallExpression) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
+        let program = match input.external.as_deref() {
+            Some(Expression::Identifier(external)) => Some(external.name),
+            Some(_) => unreachable!("Parsing guarantees that `input.external` is always an identifier."),
+            None => None,
         };
-        let mut instructions = String::new();
 
+        // Visit each argument and accumulate instructions from expressions.
+        let mut instructions = Vec::new();
+        let mut arguments = Vec::with_capacity(input.arguments.len());
         for argument in input.arguments.iter() {
-            let (argument, argument_instructions) = self.visit_expression(argument);
-            write!(call_instruction, " {argument}").expect("failed to write to string");
-            instructions.push_str(&argument_instructions);
+            let (operand, argument_instructions) = self.visit_operand(argument);
+            instructions.extend(argument_instructions);
+            arguments.push(operand);
         }
 
         // Lookup the function return type.
@@ -294,60 +414,48 @@ impl<'a> CodeGenerator<'a> {
             .get(&function_name)
             .unwrap()
             .output_type;
-        match return_type {
-            Type::Unit => {
-                call_instruction.push(';');
-                instructions.push_str(&call_instruction);
-                (String::new(), instructions)
-            } // Do nothing
+
+        // Allocate a destination register for each output of the function.
+        let num_destinations = match return_type {
+            Type::Unit => 0,
             Type::Tuple(tuple) => match tuple.len() {
                 0 | 1 => unreachable!("Parsing guarantees that a tuple type has at least two elements"),
-                len => {
-                    let mut destinations = Vec::new();
-                    for _ in 0..len {
-                        let destination_register = format!("r{}", self.next_register);
-                        destinations.push(destination_register);
-                        self.next_register += 1;
-                    }
-                    let destinations = destinations.join(" ");
-                    writeln!(call_instruction, " into {destinations};").expect("failed to write to string");
-                    instructions.push_str(&call_instruction);
-
-                    (destinations, instructions)
-                }
+                len => len,
             },
-            _ => {
-                // Push destination register to call instruction.
-                let destination_register = format!("r{}", self.next_register);
-                writeln!(call_instruction, " into {destination_register};").expect("failed to write to string");
-                instructions.push_str(&call_instruction);
-
-                // Increment the register counter.
-                self.next_register += 1;
+            _ => 1,
+        };
+        let destinations = (0..num_destinations).map(|_| self.next_register()).collect::<Vec<_>>();
+
+        let operands = destinations.iter().copied().map(AleoOperand::Register).collect();
+        let operation = AleoOperation::Call {
+            program,
+            function: function_name,
+            arguments,
+            destinations,
+        };
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
 
-                (destination_register, instructions)
-            }
-        }
+        (operands, instructions)
     }
 
-    fn visit_tuple(&mut self, input: &'a TupleExpression) -> (String, String) {
-        // Need to return a single string here so we will join the tuple elements with ' '
-        // and split them after this method is called.
-        let mut tuple_elements = Vec::with_capacity(input.elements.len());
-        let mut instructions = String::new();
+    fn visit_tuple(&mut self, input: &'a TupleExpression) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
+        let mut operands = Vec::with_capacity(input.elements.len());
+        let mut instructions = Vec::new();
 
         // Visit each tuple element and accumulate instructions from expressions.
         for element in input.elements.iter() {
-            let (element, element_instructions) = self.visit_expression(element);
-            tuple_elements.push(element);
-            instructions.push_str(&element_instructions);
+            let (operand, element_instructions) = self.visit_operand(element);
+            operands.push(operand);
+            instructions.extend(element_instructions);
         }
 
-        // CAUTION: does not return the destination_register.
-        (tuple_elements.join(" "), instructions)
+        (operands, instructions)
     }
 
-    fn visit_unit(&mut self, _input: &'a UnitExpression) -> (String, String) {
+    fn visit_unit(&mut self, _input: &'a UnitExpression) -> (Vec<AleoOperand>, Vec<AleoInstruction>) {
         unreachable!("`UnitExpression`s should not be visited during code generation.")
     }
 }
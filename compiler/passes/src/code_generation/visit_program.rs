@@ -14,284 +14,286 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{
+    AleoComposite, AleoFinalize, AleoFunction, AleoFunctionVariant, AleoInput, AleoMapping, AleoOperand, AleoProgram,
+    AleoType, CodeGenerator, UnsupportedFeature,
+};
 
-use leo_ast::{functions, Function, Mapping, Mode, Program, ProgramScope, Struct, Type, Variant};
+use leo_ast::{functions, Function, Mapping, Mode, Node, Program, ProgramScope, Struct, Type, Variant};
 
 use indexmap::IndexMap;
-use itertools::Itertools;
-use leo_span::{sym, Symbol};
-use std::fmt::Write as _;
+use leo_span::Symbol;
 
 impl<'a> CodeGenerator<'a> {
-    pub(crate) fn visit_program(&mut self, input: &'a Program) -> String {
-        // Accumulate instructions into a program string.
-        let mut program_string = String::new();
-
-        if !input.imports.is_empty() {
-            // Visit each import statement and produce a Aleo import instruction.
-            program_string.push_str(
-                &input
-                    .imports
-                    .iter()
-                    .map(|(identifier, (imported_program, _))| self.visit_import(identifier, imported_program))
-                    .join("\n"),
-            );
-
-            // Newline separator.
-            program_string.push('\n');
-        }
+    pub(crate) fn visit_program(&mut self, input: &'a Program) -> AleoProgram {
+        // Visit each import statement and produce an Aleo import.
+        // Note that the programs imported from Aleo instructions precede the Leo programs, which may import them.
+        let mut imports: Vec<Symbol> = input
+            .stubs
+            .iter()
+            .map(|(identifier, (stub, _))| self.visit_stub(identifier, stub))
+            .collect();
+        imports.extend(
+            input
+                .imports
+                .iter()
+                .map(|(identifier, (imported_program, _))| self.visit_import(identifier, imported_program)),
+        );
 
         // Retrieve the program scope.
         // Note that type checking guarantees that there is exactly one program scope.
         let program_scope: &ProgramScope = input.program_scopes.values().next().unwrap();
-
-        // Print the program id.
-        writeln!(program_string, "program {};", program_scope.program_id)
-            .expect("Failed to write program id to string.");
-
-        // Newline separator.
-        program_string.push('\n');
+        self.program_id = Some(program_scope.program_id);
 
         // Get the post-order ordering of the composite data types.
         // Note that the unwrap is safe since type checking guarantees that the struct dependency graph is acyclic.
         let order = self.struct_graph.post_order().unwrap();
 
         // Visit each `Struct` or `Record` in the post-ordering and produce an Aleo struct or record.
-        program_string.push_str(
-            &order
-                .into_iter()
-                .map(|name| {
-                    match program_scope.structs.get(&name) {
-                        // If the struct is found, it is a local struct.
-                        Some(struct_) => self.visit_struct_or_record(struct_),
-                        // If the struct is not found, it is an imported struct.
-                        None => String::new(),
-                    }
-                })
-                .join("\n"),
-        );
-
-        // Newline separator.
-        program_string.push('\n');
-
-        // Visit each mapping in the Leo AST and produce an Aleo mapping declaration.
-        program_string.push_str(
-            &program_scope
-                .mappings
-                .values()
-                .map(|mapping| self.visit_mapping(mapping))
-                .join("\n"),
-        );
+        // If the struct is not found, it is an imported struct, which is declared by the imported program.
+        let mut composites: Vec<AleoComposite> = order
+            .into_iter()
+            .filter_map(|name| program_scope.structs.get(&name))
+            .flat_map(|struct_| self.visit_struct_or_record(struct_))
+            .collect();
 
         // Visit each function in the program scope and produce an Aleo function.
         // Note that in the function inlining pass, we reorder the functions such that they are in post-order.
         // In other words, a callee function precedes its caller function in the program scope.
-        program_string.push_str(
-            &program_scope
-                .functions
-                .values()
-                .map(|function| {
-                    // Set the `is_transition_function` flag.
-                    self.is_transition_function = matches!(function.variant, Variant::Transition);
-
-                    let function_string = self.visit_function(function);
-
-                    // Unset the `is_transition_function` flag.
-                    self.is_transition_function = false;
-
-                    function_string
-                })
-                .join("\n"),
-        );
+        // Note that test functions are only run by `leo test`, so they are not compiled.
+        // Similarly, inline functions have been inlined into their callers, so they are not compiled.
+        let functions = program_scope
+            .functions
+            .values()
+            .filter(|function| !function.is_test() && !matches!(function.variant, Variant::Inline))
+            .map(|function| {
+                // Set the `is_transition_function` flag.
+                self.is_transition_function = matches!(function.variant, Variant::Transition);
+
+                let function = self.visit_function(function);
+
+                // Unset the `is_transition_function` flag.
+                self.is_transition_function = false;
 
-        program_string
+                function
+            })
+            .collect();
+
+        // Declare the structs for the remaining array types used in the functions.
+        composites.extend(self.visit_array_types());
+
+        // Visit each mapping in the Leo AST and produce an Aleo mapping declaration.
+        let mappings = program_scope
+            .mappings
+            .values()
+            .map(|mapping| self.visit_mapping(mapping))
+            .collect();
+
+        AleoProgram {
+            imports,
+            id: program_scope.program_id,
+            composites,
+            mappings,
+            functions,
+            unsupported: std::mem::take(&mut self.unsupported),
+        }
     }
 
-    fn visit_import(&mut self, import_name: &'a Symbol, import_program: &'a Program) -> String {
+    fn visit_import(&mut self, import_name: &'a Symbol, import_program: &'a Program) -> Symbol {
         // Load symbols into composite mapping.
-        let _import_program_string = self.visit_program(import_program);
-        // todo: We do not need the import program string because we generate instructions for imports separately during leo build.
+        // Note that the array types of the imported program are not declared in this program.
+        let array_types = std::mem::take(&mut self.array_types);
+        let declared_array_types = std::mem::take(&mut self.declared_array_types);
+        let _import_program = self.visit_program(import_program);
+        self.array_types = array_types;
+        self.declared_array_types = declared_array_types;
+        // todo: We do not need the import program because we generate instructions for imports separately during leo build.
+
+        *import_name
+    }
+
+    fn visit_stub(&mut self, stub_name: &'a Symbol, stub: &'a Program) -> Symbol {
+        // Load the symbols of the declared structs and records into the composite mapping.
+        // Note that the instructions of the stub are already deployed, so it is not compiled.
+        for struct_ in stub.program_scopes.values().flat_map(|scope| scope.structs.values()) {
+            self.visit_struct_or_record(struct_);
+        }
 
-        // Generate string for import statement.
-        format!("import {import_name}.aleo;")
+        *stub_name
     }
 
-    fn visit_struct_or_record(&mut self, struct_: &'a Struct) -> String {
-        if struct_.is_record {
+    fn visit_struct_or_record(&mut self, struct_: &'a Struct) -> Vec<AleoComposite> {
+        let composite = if struct_.is_record {
             self.visit_record(struct_)
         } else {
             self.visit_struct(struct_)
-        }
+        };
+
+        // Declare the structs for any array types used by the members, which must precede their first use.
+        let mut composites = self.visit_array_types();
+        composites.push(composite);
+        composites
     }
 
-    fn visit_struct(&mut self, struct_: &'a Struct) -> String {
+    fn visit_struct(&mut self, struct_: &'a Struct) -> AleoComposite {
         // Add private symbol to composite types.
         self.composite_mapping
             .insert(&struct_.identifier.name, (false, String::from("private"))); // todo: private by default here.
 
-        let mut output_string = format!("struct {}:\n", struct_.identifier); // todo: check if this is safe from name conflicts.
-
-        // Construct and append the record variables.
-        for var in struct_.members.iter() {
-            writeln!(output_string, "    {} as {};", var.identifier, var.type_,).expect("failed to write to string");
+        // Construct the struct members.
+        let members = struct_
+            .members
+            .iter()
+            .map(|var| (var.identifier.name, self.visit_type(&var.type_), Mode::None))
+            .collect();
+
+        // todo: check if this is safe from name conflicts.
+        AleoComposite {
+            is_record: false,
+            name: struct_.identifier.name,
+            members,
         }
-
-        output_string
     }
 
-    fn visit_record(&mut self, record: &'a Struct) -> String {
+    fn visit_record(&mut self, record: &'a Struct) -> AleoComposite {
         // Add record symbol to composite types.
-        let mut output_string = String::from("record");
         self.composite_mapping
-            .insert(&record.identifier.name, (true, output_string.clone()));
-        writeln!(output_string, " {}:", record.identifier).expect("failed to write to string"); // todo: check if this is safe from name conflicts.
-
-        // Construct and append the record variables.
-        for var in record.members.iter() {
-            let mode = match var.mode {
-                Mode::Constant => "constant",
-                Mode::Public => "public",
-                Mode::None | Mode::Private => "private",
-            };
-            writeln!(
-                output_string,
-                "    {} as {}.{mode};", // todo: CAUTION private record variables only.
-                var.identifier, var.type_
-            )
-            .expect("failed to write to string");
+            .insert(&record.identifier.name, (true, String::from("record")));
+
+        // Construct the record members.
+        let members = record
+            .members
+            .iter()
+            .map(|var| {
+                let mode = match var.mode {
+                    Mode::None => Mode::Private,
+                    mode => mode,
+                };
+                // todo: CAUTION private record variables only.
+                (var.identifier.name, self.visit_type(&var.type_), mode)
+            })
+            .collect();
+
+        // todo: check if this is safe from name conflicts.
+        AleoComposite {
+            is_record: true,
+            name: record.identifier.name,
+            members,
         }
-
-        output_string
     }
 
-    fn visit_function(&mut self, function: &'a Function) -> String {
+    fn visit_function(&mut self, function: &'a Function) -> AleoFunction {
         // Initialize the state of `self` with the appropriate values before visiting `function`.
         self.next_register = 0;
         self.variable_mapping = IndexMap::new();
-        // TODO: Figure out a better way to initialize.
-        self.variable_mapping.insert(&sym::SelfLower, "self".to_string());
         self.current_function = Some(function);
 
-        // Construct the header of the function.
         // If a function is a program function, generate an Aleo `function`,
-        // if it is a standard function generate an Aleo `closure`,
-        // otherwise, it is an inline function, in which case a function should not be generated.
-        let mut function_string = match function.variant {
-            Variant::Transition => format!("function {}:\n", function.identifier),
-            Variant::Standard => format!("closure {}:\n", function.identifier),
-            Variant::Inline => return String::from("\n"),
+        // otherwise, it is a standard function, in which case generate an Aleo `closure`.
+        // Note that inline functions are not visited.
+        let variant = match function.variant {
+            Variant::Transition => AleoFunctionVariant::Function,
+            Variant::Standard => AleoFunctionVariant::Closure,
+            Variant::Inline => unreachable!("Inline functions are not compiled."),
         };
 
-        // Construct and append the input declarations of the function.
-        for input in function.input.iter() {
-            let register_string = format!("r{}", self.next_register);
-            self.next_register += 1;
-
-            let type_string = match input {
-                functions::Input::Internal(input) => {
-                    self.variable_mapping
-                        .insert(&input.identifier.name, register_string.clone());
-                    let visibility = match (self.is_transition_function, input.mode) {
-                        (true, Mode::None) => Mode::Private,
-                        _ => input.mode,
-                    };
-                    self.visit_type_with_visibility(&input.type_, visibility)
-                }
-                functions::Input::External(input) => {
-                    self.variable_mapping
-                        .insert(&input.identifier.name, register_string.clone());
-                    format!("{}.aleo/{}.record", input.program_name, input.record)
-                }
-            };
-
-            writeln!(function_string, "    input {register_string} as {type_string};",)
-                .expect("failed to write to string");
-        }
+        // Construct the input declarations of the function.
+        // Transition function inputs are private by default.
+        let inputs = self.visit_inputs(&function.input, Mode::Private);
 
-        //  Construct and append the function body.
-        let block_string = self.visit_block(&function.block);
-        function_string.push_str(&block_string);
+        // Construct the function body.
+        let instructions = self.visit_block(&function.block);
+        let outputs = std::mem::take(&mut self.outputs);
 
         // If the finalize block exists, generate the appropriate bytecode.
-        if let Some(finalize) = &function.finalize {
-            // Clear the register count.
+        let finalize = function.finalize.as_ref().map(|finalize| {
+            // Clear the register count and the variable mapping.
             self.next_register = 0;
+            self.variable_mapping = IndexMap::new();
             self.in_finalize = true;
 
-            // Clear the variable mapping.
-            // TODO: Figure out a better way to initialize.
-            self.variable_mapping = IndexMap::new();
-            self.variable_mapping.insert(&sym::SelfLower, "self".to_string());
+            // Construct the input declarations of the finalize block.
+            // Finalize inputs are public by default.
+            let inputs = self.visit_inputs(&finalize.input, Mode::Public);
 
-            function_string.push_str(&format!("\nfinalize {}:\n", finalize.identifier));
+            if let Some(output) = finalize.output.first() {
+                self.unsupported.push(UnsupportedFeature {
+                    feature: "returning values from finalize blocks",
+                    span: output.span(),
+                });
+            }
+
+            // Construct the finalize block body.
+            let instructions = self.visit_block(&finalize.block);
+            let outputs = std::mem::take(&mut self.outputs);
 
-            // Construct and append the input declarations of the finalize block.
-            for input in finalize.input.iter() {
-                let register_string = format!("r{}", self.next_register);
-                self.next_register += 1;
+            self.in_finalize = false;
 
-                // TODO: Dedup code.
-                let type_string = match input {
+            AleoFinalize {
+                name: finalize.identifier.name,
+                inputs,
+                instructions,
+                outputs,
+            }
+        });
+
+        AleoFunction {
+            variant,
+            name: function.identifier.name,
+            inputs,
+            instructions,
+            outputs,
+            finalize,
+        }
+    }
+
+    /// Assigns a register to each input, using `default` as the visibility of transition inputs without one.
+    fn visit_inputs(&mut self, inputs: &'a [functions::Input], default: Mode) -> Vec<AleoInput> {
+        inputs
+            .iter()
+            .map(|input| {
+                let register = self.next_register();
+                let (type_, mode) = match input {
                     functions::Input::Internal(input) => {
                         self.variable_mapping
-                            .insert(&input.identifier.name, register_string.clone());
-
+                            .insert(&input.identifier.name, AleoOperand::Register(register));
                         let visibility = match (self.is_transition_function, input.mode) {
-                            (true, Mode::None) => Mode::Public,
+                            (true, Mode::None) => default,
                             _ => input.mode,
                         };
-                        self.visit_type_with_visibility(&input.type_, visibility)
+                        (self.visit_type(&input.type_), visibility)
                     }
                     functions::Input::External(input) => {
                         self.variable_mapping
-                            .insert(&input.program_name.name, register_string.clone());
-                        format!("{}.aleo/{}.record", input.program_name, input.record)
+                            .insert(&input.identifier.name, AleoOperand::Register(register));
+                        let type_ = AleoType::ExternalRecord {
+                            program: input.program_name.name,
+                            record: input.record.name,
+                        };
+                        (type_, Mode::None)
                     }
                 };
-
-                writeln!(function_string, "    input {register_string} as {type_string};",)
-                    .expect("failed to write to string");
-            }
-
-            // Construct and append the finalize block body.
-            function_string.push_str(&self.visit_block(&finalize.block));
-
-            self.in_finalize = false;
-        }
-
-        function_string
+                AleoInput { register, type_, mode }
+            })
+            .collect()
     }
 
-    fn visit_mapping(&mut self, mapping: &'a Mapping) -> String {
-        // Create the prefix of the mapping string, e.g. `mapping foo:`.
-        let mut mapping_string = format!("mapping {}:\n", mapping.identifier);
-
-        // Helper to construct the string associated with the type.
-        let create_type = |type_: &Type| {
-            match type_ {
-                Type::Mapping(_) | Type::Tuple(_) => unreachable!("Mappings cannot contain mappings or tuples."),
-                Type::Identifier(identifier) => {
-                    // Lookup the type in the composite mapping.
-                    // Note that this unwrap is safe since all struct and records have been added to the composite mapping.
-                    let (is_record, _) = self.composite_mapping.get(&identifier.name).unwrap();
-                    match is_record {
-                        // If the type is a record, then declare the type as is.
-                        true => format!("{identifier}.record"),
-                        // If the type is a struct, then add the public modifier.
-                        false => format!("{identifier}.public"),
-                    }
-                }
-                type_ => format!("{type_}.public"),
-            }
+    fn visit_mapping(&mut self, mapping: &'a Mapping) -> AleoMapping {
+        let is_record = |type_: &Type| match type_ {
+            Type::Identifier(identifier) => matches!(self.composite_mapping.get(&identifier.name), Some((true, _))),
+            _ => false,
         };
+        if is_record(&mapping.key_type) || is_record(&mapping.value_type) {
+            self.unsupported.push(UnsupportedFeature {
+                feature: "records as mapping keys or values",
+                span: mapping.span,
+            });
+        }
 
-        // Create the key string, e.g. `    key as address.public`.
-        mapping_string.push_str(&format!("\tkey left as {};\n", create_type(&mapping.key_type)));
-
-        // Create the value string, e.g. `    value as address.public`.
-        mapping_string.push_str(&format!("\tvalue right as {};\n", create_type(&mapping.value_type)));
-
-        mapping_string
+        AleoMapping {
+            name: mapping.identifier.name,
+            key_type: self.visit_type(&mapping.key_type),
+            value_type: self.visit_type(&mapping.value_type),
+        }
     }
 }
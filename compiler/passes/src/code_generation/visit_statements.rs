@@ -14,19 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{AleoInstruction, AleoOperand, AleoOperation, AleoOutput, AleoType, CodeGenerator, VariableSymbol};
 
 use leo_ast::{
-    AssertStatement, AssertVariant, AssignStatement, Block, ConditionalStatement, ConsoleStatement, DecrementStatement,
-    DefinitionStatement, Expression, ExpressionStatement, IncrementStatement, IterationStatement, Mode, Output,
-    ReturnStatement, Statement,
+    AccessExpression, AssertStatement, AssertVariant, AssignStatement, BinaryOperation, Block, ConditionalStatement,
+    ConsoleStatement, DecrementStatement, DefinitionStatement, Expression, ExpressionStatement, GroupLiteral,
+    Identifier, IncrementStatement, IterationStatement, Literal, Mode, Output, ReturnStatement, Statement, Type,
 };
+use leo_span::Span;
 
 use itertools::Itertools;
-use std::fmt::Write as _;
 
 impl<'a> CodeGenerator<'a> {
-    fn visit_statement(&mut self, input: &'a Statement) -> String {
+    fn visit_statement(&mut self, input: &'a Statement) -> Vec<AleoInstruction> {
         match input {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
@@ -38,42 +38,49 @@ impl<'a> CodeGenerator<'a> {
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Increment(stmt) => self.visit_increment(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
 
-    fn visit_assert(&mut self, input: &'a AssertStatement) -> String {
-        let mut generate_assert_instruction = |name: &str, left: &'a Expression, right: &'a Expression| {
-            let (left_operand, left_instructions) = self.visit_expression(left);
-            let (right_operand, right_instructions) = self.visit_expression(right);
-            let assert_instruction = format!("    {name} {left_operand} {right_operand};\n");
-
-            // Concatenate the instructions.
-            let mut instructions = left_instructions;
-            instructions.push_str(&right_instructions);
-            instructions.push_str(&assert_instruction);
-
-            instructions
-        };
-        match &input.variant {
+    fn visit_assert(&mut self, input: &'a AssertStatement) -> Vec<AleoInstruction> {
+        let (operation, mut instructions) = match &input.variant {
             AssertVariant::Assert(expr) => {
-                let (operand, mut instructions) = self.visit_expression(expr);
-                let assert_instruction = format!("    assert.eq {operand} true;\n");
-
-                instructions.push_str(&assert_instruction);
-                instructions
+                let (operand, instructions) = self.visit_operand(expr);
+                let operation = AleoOperation::AssertEq {
+                    left: operand,
+                    right: AleoOperand::Literal(Literal::Boolean(true, Default::default())),
+                };
+                (operation, instructions)
             }
-            AssertVariant::AssertEq(left, right) => generate_assert_instruction("assert.eq", left, right),
-            AssertVariant::AssertNeq(left, right) => generate_assert_instruction("assert.neq", left, right),
-        }
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                let (left, mut instructions) = self.visit_operand(left);
+                let (right, right_instructions) = self.visit_operand(right);
+                instructions.extend(right_instructions);
+                let operation = match &input.variant {
+                    AssertVariant::AssertEq(..) => AleoOperation::AssertEq { left, right },
+                    _ => AleoOperation::AssertNeq { left, right },
+                };
+                (operation, instructions)
+            }
+        };
+
+        instructions.push(AleoInstruction {
+            operation,
+            span: input.span,
+        });
+
+        instructions
     }
 
-    fn visit_return(&mut self, input: &'a ReturnStatement) -> String {
+    fn visit_return(&mut self, input: &'a ReturnStatement) -> Vec<AleoInstruction> {
         let mut instructions = match input.expression {
             // Skip empty return statements.
-            Expression::Unit(_) => String::new(),
+            Expression::Unit(_) => Vec::new(),
             _ => {
-                let (operand, mut expression_instructions) = self.visit_expression(&input.expression);
+                let (operands, expression_instructions) = self.visit_expression(&input.expression);
                 // Get the output type of the function.
                 let output = if self.in_finalize {
                     // Note that the first unwrap is safe, since `current_function` is set in `visit_function`.
@@ -82,11 +89,11 @@ impl<'a> CodeGenerator<'a> {
                     // Note that this unwrap is safe, since `current_function` is set in `visit_function`.
                     self.current_function.unwrap().output.iter()
                 };
-                let instructions = operand
-                    .split(' ')
+                self.outputs = operands
+                    .into_iter()
                     .zip_eq(output)
                     .map(|(operand, output)| {
-                        match output {
+                        let (type_, mode) = match output {
                             Output::Internal(output) => {
                                 let visibility = if self.is_transition_function {
                                     match self.in_finalize {
@@ -105,99 +112,171 @@ impl<'a> CodeGenerator<'a> {
                                     // Only program functions have visibilities associated with their outputs.
                                     Mode::None
                                 };
-                                format!(
-                                    "    output {} as {};\n",
-                                    operand,
-                                    self.visit_type_with_visibility(&output.type_, visibility)
-                                )
+                                (self.visit_type(&output.type_), visibility)
                             }
                             Output::External(output) => {
-                                format!(
-                                    "    output {} as {}.aleo/{}.record;\n",
-                                    operand, output.program_name, output.record,
-                                )
+                                let type_ = AleoType::ExternalRecord {
+                                    program: output.program_name.name,
+                                    record: output.record.name,
+                                };
+                                (type_, Mode::None)
                             }
-                        }
+                        };
+                        AleoOutput { operand, type_, mode }
                     })
-                    .join("");
-
-                expression_instructions.push_str(&instructions);
+                    .collect();
 
                 expression_instructions
             }
         };
 
-        // Output a finalize instruction if needed.
-        // TODO: Check formatting.
+        // Call the finalize block if needed, and output the resulting future last.
         if let Some(arguments) = &input.finalize_arguments {
-            let mut finalize_instruction = "\n    finalize".to_string();
-
+            let mut finalize_arguments = Vec::with_capacity(arguments.len());
             for argument in arguments.iter() {
-                let (argument, argument_instructions) = self.visit_expression(argument);
-                write!(finalize_instruction, " {argument}").expect("failed to write to string");
-                instructions.push_str(&argument_instructions);
+                let (argument, argument_instructions) = self.visit_operand(argument);
+                instructions.extend(argument_instructions);
+                finalize_arguments.push(argument);
             }
-            writeln!(finalize_instruction, ";").expect("failed to write to string");
 
-            instructions.push_str(&finalize_instruction);
+            // Note that this unwrap is safe, since `current_function` is set in `visit_function`.
+            let function = self.current_function.unwrap().identifier.name;
+            let destination = self.next_register();
+            instructions.push(AleoInstruction {
+                operation: AleoOperation::Async {
+                    function,
+                    arguments: finalize_arguments,
+                    destination,
+                },
+                span: input.span,
+            });
+            self.outputs.push(AleoOutput {
+                operand: AleoOperand::Register(destination),
+                // Note that this unwrap is safe, since `program_id` is set in `visit_program`.
+                type_: AleoType::Future {
+                    program: self.program_id.unwrap(),
+                    function,
+                },
+                mode: Mode::None,
+            });
         }
 
         instructions
     }
 
-    fn visit_definition(&mut self, _input: &'a DefinitionStatement) -> String {
+    fn visit_definition(&mut self, _input: &'a DefinitionStatement) -> Vec<AleoInstruction> {
         // TODO: If SSA is made optional, then conditionally enable codegen for DefinitionStatement
-        // let (operand, expression_instructions) = self.visit_expression(&input.value);
+        // let (operand, expression_instructions) = self.visit_operand(&input.value);
         // self.variable_mapping.insert(&input.variable_name.name, operand);
         // expression_instructions
         unreachable!("DefinitionStatement's should not exist in SSA form.")
     }
 
-    fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) -> String {
+    fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) -> Vec<AleoInstruction> {
         match input.expression {
-            Expression::Call(_) => {
-                // Note that codegen for CallExpression in an expression statement does not return any destination registers.
+            // Note that codegen for CallExpression in an expression statement does not return any destination registers.
+            // Similarly, mapping operations in an expression statement are `remove` or `set`, or have their output discarded.
+            Expression::Call(_) | Expression::Access(AccessExpression::AssociatedFunction(_)) => {
                 self.visit_expression(&input.expression).1
             }
-            _ => unreachable!("ExpressionStatement's can only contain CallExpression's."),
+            _ => unreachable!("ExpressionStatement's can only contain CallExpression's or mapping operations."),
         }
     }
 
-    fn visit_increment(&mut self, input: &'a IncrementStatement) -> String {
-        let (index, mut instructions) = self.visit_expression(&input.index);
-        let (amount, amount_instructions) = self.visit_expression(&input.amount);
-        instructions.push_str(&amount_instructions);
-        instructions.push_str(&format!("    increment {}[{index}] by {amount};\n", input.mapping));
+    fn visit_increment(&mut self, input: &'a IncrementStatement) -> Vec<AleoInstruction> {
+        self.visit_increment_or_decrement(
+            BinaryOperation::Add,
+            &input.mapping,
+            &input.index,
+            &input.amount,
+            input.span,
+        )
+    }
 
-        instructions
+    fn visit_decrement(&mut self, input: &'a DecrementStatement) -> Vec<AleoInstruction> {
+        self.visit_increment_or_decrement(
+            BinaryOperation::Sub,
+            &input.mapping,
+            &input.index,
+            &input.amount,
+            input.span,
+        )
     }
 
-    fn visit_decrement(&mut self, input: &'a DecrementStatement) -> String {
-        let (index, mut instructions) = self.visit_expression(&input.index);
-        let (amount, amount_instructions) = self.visit_expression(&input.amount);
-        instructions.push_str(&amount_instructions);
-        instructions.push_str(&format!("    decrement {}[{index}] by {amount};\n", input.mapping));
+    /// Aleo instructions do not have `increment` and `decrement` commands, so the value is read with a default of zero,
+    /// updated with `op`, and written back, e.g. `get.or_use balances[r0] 0u64 into r2; add r2 r1 into r3; set r3 into balances[r0];`.
+    fn visit_increment_or_decrement(
+        &mut self,
+        op: BinaryOperation,
+        mapping: &Identifier,
+        index: &'a Expression,
+        amount: &'a Expression,
+        span: Span,
+    ) -> Vec<AleoInstruction> {
+        let (key, mut instructions) = self.visit_operand(index);
+        let (amount, amount_instructions) = self.visit_operand(amount);
+        instructions.extend(amount_instructions);
+
+        // Note that type checking guarantees that the mapping exists and that its values are numeric literals.
+        let value_type = match self.symbol_table.lookup_variable(mapping.name) {
+            Some(VariableSymbol {
+                type_: Type::Mapping(mapping_type),
+                ..
+            }) => *mapping_type.value.clone(),
+            _ => unreachable!("Type checking guarantees that `{mapping}` is a mapping."),
+        };
+        let default = AleoOperand::Literal(match value_type {
+            Type::Field => Literal::Field("0".to_string(), Default::default()),
+            Type::Group => Literal::Group(Box::new(GroupLiteral::Single("0".to_string(), Default::default()))),
+            Type::Integer(integer_type) => Literal::Integer(integer_type, "0".to_string(), Default::default()),
+            Type::Scalar => Literal::Scalar("0".to_string(), Default::default()),
+            _ => unreachable!("Type checking guarantees that the values of `{mapping}` are numeric."),
+        });
+
+        let current = self.next_register();
+        let updated = self.next_register();
+        let operations = [
+            AleoOperation::GetOrUse {
+                mapping: mapping.name,
+                key: key.clone(),
+                default,
+                destination: current,
+            },
+            AleoOperation::Binary {
+                op,
+                left: AleoOperand::Register(current),
+                right: amount,
+                destination: updated,
+                operand_type: Some(value_type),
+            },
+            AleoOperation::Set {
+                mapping: mapping.name,
+                key,
+                value: AleoOperand::Register(updated),
+            },
+        ];
+        instructions.extend(
+            operations
+                .into_iter()
+                .map(|operation| AleoInstruction { operation, span }),
+        );
 
         instructions
     }
 
-    fn visit_assign(&mut self, input: &'a AssignStatement) -> String {
+    fn visit_assign(&mut self, input: &'a AssignStatement) -> Vec<AleoInstruction> {
         match (&input.place, &input.value) {
             (Expression::Identifier(identifier), _) => {
-                let (operand, expression_instructions) = self.visit_expression(&input.value);
+                let (operand, expression_instructions) = self.visit_operand(&input.value);
                 self.variable_mapping.insert(&identifier.name, operand);
                 expression_instructions
             }
             (Expression::Tuple(tuple), Expression::Call(_)) => {
-                let (operand, expression_instructions) = self.visit_expression(&input.value);
-                // Split out the destinations from the tuple.
-                let operands = operand.split(' ').collect::<Vec<_>>();
+                let (operands, expression_instructions) = self.visit_expression(&input.value);
                 // Add the destinations to the variable mapping.
                 tuple.elements.iter().zip_eq(operands).for_each(|(element, operand)| {
                     match element {
-                        Expression::Identifier(identifier) => {
-                            self.variable_mapping.insert(&identifier.name, operand.to_string())
-                        }
+                        Expression::Identifier(identifier) => self.variable_mapping.insert(&identifier.name, operand),
                         _ => {
                             unreachable!("Type checking ensures that tuple elements on the lhs are always identifiers.")
                         }
@@ -205,28 +284,34 @@ impl<'a> CodeGenerator<'a> {
                 });
                 expression_instructions
             }
-            _ => unimplemented!(
-                "Code generation for the left-hand side of an assignment is only implemented for `Identifier`s."
+            // Note that static single assignment rewrites assignments to array elements, struct members, and tuple elements
+            // into assignments to variables, and flattening only introduces tuples on the left-hand side of calls.
+            _ => unreachable!(
+                "Static single assignment guarantees that the left-hand side of an assignment is a variable."
             ),
         }
     }
 
-    fn visit_conditional(&mut self, _input: &'a ConditionalStatement) -> String {
+    fn visit_conditional(&mut self, _input: &'a ConditionalStatement) -> Vec<AleoInstruction> {
         // TODO: Once SSA is made optional, create a Leo error informing the user to enable the SSA pass.
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    fn visit_iteration(&mut self, _input: &'a IterationStatement) -> String {
+    fn visit_iteration(&mut self, _input: &'a IterationStatement) -> Vec<AleoInstruction> {
         // TODO: Once loop unrolling is made optional, create a Leo error informing the user to enable the loop unrolling pass..
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
-    fn visit_console(&mut self, _: &'a ConsoleStatement) -> String {
+    fn visit_console(&mut self, _: &'a ConsoleStatement) -> Vec<AleoInstruction> {
         unreachable!("Parsing guarantees that `ConsoleStatement`s are not present in the AST.")
     }
 
-    pub(crate) fn visit_block(&mut self, input: &'a Block) -> String {
+    pub(crate) fn visit_block(&mut self, input: &'a Block) -> Vec<AleoInstruction> {
         // For each statement in the block, visit it and add its instructions to the list.
-        input.statements.iter().map(|stmt| self.visit_statement(stmt)).join("")
+        input
+            .statements
+            .iter()
+            .flat_map(|stmt| self.visit_statement(stmt))
+            .collect()
     }
 }
@@ -14,12 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{AleoComposite, AleoType, CodeGenerator};
 
-use leo_ast::{Mode, Type};
+use leo_ast::{ArrayType, Mode, Type};
+use leo_span::Symbol;
 
 impl<'a> CodeGenerator<'a> {
-    fn visit_type(&mut self, input: &'a Type) -> String {
+    pub(crate) fn visit_type(&mut self, input: &Type) -> AleoType {
         match input {
             Type::Address
             | Type::Boolean
@@ -27,8 +28,23 @@ impl<'a> CodeGenerator<'a> {
             | Type::Group
             | Type::Scalar
             | Type::String
-            | Type::Integer(..) => format!("{input}"),
-            Type::Identifier(ident) => format!("{ident}"),
+            | Type::Integer(..) => AleoType::Literal(input.clone()),
+            Type::Identifier(identifier) => match self.composite_mapping.get(&identifier.name) {
+                Some((true, _)) => AleoType::Record(identifier.name),
+                _ => AleoType::Struct(identifier.name),
+            },
+            Type::Array(array_type) => {
+                // Arrays are lowered to structs, which are declared before the program's functions.
+                let name = Symbol::intern(&format!(
+                    "array{}__{}",
+                    array_type.length,
+                    self.visit_type(&array_type.element_type)
+                ));
+                if !self.declared_array_types.contains(array_type) {
+                    self.array_types.insert(array_type.clone());
+                }
+                AleoType::Struct(name)
+            }
             Type::Mapping(_) => {
                 unreachable!("Mapping types are not supported at this phase of compilation")
             }
@@ -40,17 +56,37 @@ impl<'a> CodeGenerator<'a> {
         }
     }
 
-    pub(crate) fn visit_type_with_visibility(&mut self, type_: &'a Type, visibility: Mode) -> String {
-        match type_ {
-            // When the type is a record.
-            // Note that this unwrap is safe because all composite types have been added to the mapping.
-            Type::Identifier(identifier) if self.composite_mapping.get(&identifier.name).unwrap().0 => {
-                format!("{identifier}.record")
-            }
-            _ => match visibility {
-                Mode::None => self.visit_type(type_),
-                _ => format!("{}.{visibility}", self.visit_type(type_)),
-            },
+    /// Declares the structs corresponding to the array types that have been used, but not yet declared.
+    /// For example, the array type `[u8; 2]` is declared as:
+    /// ```text
+    /// struct array2__u8:
+    ///     e0 as u8;
+    ///     e1 as u8;
+    /// ```
+    pub(crate) fn visit_array_types(&mut self) -> Vec<AleoComposite> {
+        let mut declarations = Vec::new();
+
+        // Note that element types are always used, and therefore declared, before the array types that contain them.
+        while let Some(array_type) = self.array_types.shift_remove_index(0) {
+            self.declared_array_types.insert(array_type.clone());
+            declarations.push(self.visit_array_type(&array_type));
+        }
+
+        declarations
+    }
+
+    fn visit_array_type(&mut self, array_type: &ArrayType) -> AleoComposite {
+        let name = match self.visit_type(&Type::Array(array_type.clone())) {
+            AleoType::Struct(name) => name,
+            _ => unreachable!("Arrays are lowered to structs."),
+        };
+        let element_type = self.visit_type(&array_type.element_type);
+        AleoComposite {
+            is_record: false,
+            name,
+            members: (0..array_type.length())
+                .map(|i| (Symbol::intern(&format!("e{i}")), element_type.clone(), Mode::None))
+                .collect(),
         }
     }
 }
@@ -76,6 +76,23 @@ impl<N: Node> DiGraph<N> {
         self.nodes.contains(&node)
     }
 
+    /// Returns the set of nodes that are reachable from the given nodes, including the given nodes themselves.
+    pub fn reachable_from(&self, nodes: impl IntoIterator<Item = N>) -> IndexSet<N> {
+        let mut reachable = IndexSet::new();
+        let mut stack: Vec<N> = nodes.into_iter().collect();
+
+        // Perform a depth-first search of the graph, starting from each of the given nodes.
+        while let Some(node) = stack.pop() {
+            if reachable.insert(node) {
+                if let Some(children) = self.edges.get(&node) {
+                    stack.extend(children.iter().filter(|child| !reachable.contains(*child)));
+                }
+            }
+        }
+
+        reachable
+    }
+
     /// Returns the post-order ordering of the graph.
     /// Detects if there is a cycle in the graph.
     pub fn post_order(&self) -> Result<IndexSet<N>, DiGraphError<N>> {
@@ -212,6 +229,25 @@ mod test {
         assert_eq!(cycle, expected);
     }
 
+    #[test]
+    fn test_reachable_from() {
+        let mut graph = DiGraph::<u32>::new(IndexSet::from([6]));
+
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 1);
+        graph.add_edge(4, 3);
+        graph.add_edge(5, 4);
+
+        let mut reachable: Vec<u32> = graph.reachable_from([2]).into_iter().collect();
+        reachable.sort();
+        assert_eq!(reachable, [1, 2, 3]);
+
+        let mut reachable: Vec<u32> = graph.reachable_from([4, 6]).into_iter().collect();
+        reachable.sort();
+        assert_eq!(reachable, [1, 2, 3, 4, 6]);
+    }
+
     #[test]
     fn test_unconnected_graph() {
         let graph = DiGraph::<u32>::new(IndexSet::from([1, 2, 3, 4, 5]));
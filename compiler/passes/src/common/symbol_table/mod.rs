@@ -22,7 +22,7 @@ pub use variable_symbol::*;
 
 use std::cell::RefCell;
 
-use leo_ast::{Function, Struct};
+use leo_ast::{Enum, Function, Struct};
 use leo_errors::{AstError, Result};
 use leo_span::{Span, Symbol};
 
@@ -39,6 +39,9 @@ pub struct SymbolTable {
     /// Maps struct names to struct definitions.
     /// This field is populated at a first pass.
     pub structs: IndexMap<Symbol, Struct>,
+    /// Maps enum names to enum definitions.
+    /// This field is populated at a first pass.
+    pub enums: IndexMap<Symbol, Enum>,
     /// The variables defined in a scope.
     /// This field is populated as necessary.
     pub(crate) variables: IndexMap<Symbol, VariableSymbol>,
@@ -61,6 +64,8 @@ impl SymbolTable {
                 true => Err(AstError::shadowed_record(symbol, span).into()),
                 false => Err(AstError::shadowed_struct(symbol, span).into()),
             }
+        } else if self.enums.contains_key(&symbol) {
+            Err(AstError::shadowed_enum(symbol, span).into())
         } else if let Some(parent) = self.parent.as_ref() {
             parent.check_shadowing(symbol, span)
         } else {
@@ -92,6 +97,13 @@ impl SymbolTable {
         Ok(())
     }
 
+    /// Inserts an enum into the symbol table.
+    pub fn insert_enum(&mut self, symbol: Symbol, insert: &Enum) -> Result<()> {
+        self.check_shadowing(symbol, insert.span)?;
+        self.enums.insert(symbol, insert.clone());
+        Ok(())
+    }
+
     /// Inserts a variable into the symbol table.
     pub fn insert_variable(&mut self, symbol: Symbol, insert: VariableSymbol) -> Result<()> {
         self.check_shadowing(symbol, insert.span)?;
@@ -99,6 +111,11 @@ impl SymbolTable {
         Ok(())
     }
 
+    /// Removes a variable from the current scope, e.g. a variable bound by the arm of a `match` expression.
+    pub fn remove_variable(&mut self, symbol: Symbol) {
+        self.variables.shift_remove(&symbol);
+    }
+
     /// Creates a new scope for the block and stores it in the symbol table.
     pub fn insert_block(&mut self) -> usize {
         self.scopes.push(RefCell::new(Default::default()));
@@ -127,6 +144,17 @@ impl SymbolTable {
         }
     }
 
+    /// Attempts to lookup an enum in the symbol table.
+    pub fn lookup_enum(&self, symbol: Symbol) -> Option<&Enum> {
+        if let Some(enum_) = self.enums.get(&symbol) {
+            Some(enum_)
+        } else if let Some(parent) = self.parent.as_ref() {
+            parent.lookup_enum(symbol)
+        } else {
+            None
+        }
+    }
+
     /// Attempts to lookup a variable in the symbol table.
     pub fn lookup_variable(&self, symbol: Symbol) -> Option<&VariableSymbol> {
         if let Some(var) = self.variables.get(&symbol) {
@@ -176,4 +204,17 @@ impl SymbolTable {
     pub fn lookup_scope_by_index(&self, index: usize) -> Option<&RefCell<Self>> {
         self.scopes.get(index)
     }
+
+    /// Returns the variables defined in this scope and in all of its sub-scopes.
+    pub fn all_variables(&self) -> Vec<(Symbol, VariableSymbol)> {
+        let mut variables: Vec<_> = self
+            .variables
+            .iter()
+            .map(|(symbol, var)| (*symbol, var.clone()))
+            .collect();
+        for scope in self.scopes.iter() {
+            variables.extend(scope.borrow().all_variables());
+        }
+        variables
+    }
 }
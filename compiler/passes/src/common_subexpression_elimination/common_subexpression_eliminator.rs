@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{AccessExpression, BinaryOperation, Expression, Identifier, Type, UnaryOperation};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexMap;
+
+/// An operand of an expression in SSA form.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Operand {
+    /// A variable, which is assigned exactly once.
+    Variable(Symbol),
+    /// A literal, represented by its string, e.g. `1u8`.
+    Literal(String),
+}
+
+/// A pure expression whose operands are variables or literals.
+/// Note that spans are not included, so that identical expressions at different locations are equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum ExpressionKey {
+    AssociatedFunction(Symbol, Symbol, Vec<Operand>),
+    Binary(BinaryOperation, Operand, Operand),
+    Cast(Operand, Type),
+    Member(Operand, Symbol),
+    Ternary(Operand, Operand, Operand),
+    Unary(UnaryOperation, Operand),
+}
+
+#[derive(Default)]
+pub struct CommonSubexpressionEliminator {
+    /// A mapping from the pure expressions computed so far to the variables holding their results.
+    pub(crate) expressions: IndexMap<ExpressionKey, Identifier>,
+    /// A mapping from variables to the variables that they are replaced with.
+    pub(crate) substitutions: IndexMap<Symbol, Identifier>,
+}
+
+impl CommonSubexpressionEliminator {
+    /// Returns the key for the expression, if it is pure and its operands are variables or literals.
+    pub(crate) fn key(expression: &Expression) -> Option<ExpressionKey> {
+        let operand = |expression: &Expression| match expression {
+            Expression::Identifier(identifier) => Some(Operand::Variable(identifier.name)),
+            Expression::Literal(literal) => Some(Operand::Literal(literal.to_string())),
+            _ => None,
+        };
+
+        Some(match expression {
+            // Mapping operations read or modify on-chain state.
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => match function.ty {
+                Type::Identifier(Identifier { name: sym::Mapping, .. }) => return None,
+                Type::Identifier(core) => ExpressionKey::AssociatedFunction(
+                    core.name,
+                    function.name.name,
+                    function.args.iter().map(operand).collect::<Option<_>>()?,
+                ),
+                _ => return None,
+            },
+            Expression::Access(AccessExpression::Member(access)) => {
+                ExpressionKey::Member(operand(&access.inner)?, access.name.name)
+            }
+            Expression::Binary(binary) => {
+                ExpressionKey::Binary(binary.op, operand(&binary.left)?, operand(&binary.right)?)
+            }
+            Expression::Cast(cast) => ExpressionKey::Cast(operand(&cast.expression)?, cast.type_.clone()),
+            Expression::Ternary(ternary) => ExpressionKey::Ternary(
+                operand(&ternary.condition)?,
+                operand(&ternary.if_true)?,
+                operand(&ternary.if_false)?,
+            ),
+            Expression::Unary(unary) => ExpressionKey::Unary(unary.op, operand(&unary.receiver)?),
+            _ => return None,
+        })
+    }
+}
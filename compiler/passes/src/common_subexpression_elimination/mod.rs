@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The common subexpression elimination pass traverses the AST after the function inlining pass and reuses the results of identical computations.
+//! See https://en.wikipedia.org/wiki/Common_subexpression_elimination for more information.
+//! The pass replaces an assignment of a pure expression that was already computed, with an assignment of the variable that holds its result.
+//! Pure expressions are binary, unary, and cast expressions, ternaries, member accesses, and calls to core functions, e.g. `BHP256::hash`.
+//! Calls to functions and mapping operations are never eliminated.
+//! The pass also propagates assignments of variables, so that identical expressions refer to the same operands.
+//!
+//! Consider the following Leo code, output by the function inlining pass.
+//! ```leo
+//! function main(flag: bool, x: field) -> field {
+//!     $var$0 = BHP256::hash(x);
+//!     y$1 = $var$0;
+//!     $var$2 = BHP256::hash(x);
+//!     y$3 = $var$2;
+//!     $var$4 = y$3 + 1field;
+//!     y$5 = $var$4;
+//!     y$6 = flag ? y$1 : y$5;
+//!     return y$6;
+//! }
+//! ```
+//!
+//! The common subexpression elimination pass produces the following code.
+//! ```leo
+//! function main(flag: bool, x: field) -> field {
+//!     $var$0 = BHP256::hash(x);
+//!     y$1 = $var$0;
+//!     $var$2 = $var$0;
+//!     y$3 = $var$0;
+//!     $var$4 = $var$0 + 1field;
+//!     y$5 = $var$4;
+//!     y$6 = flag ? $var$0 : $var$4;
+//!     return y$6;
+//! }
+//! ```
+//! Note that the redundant assignments are removed by the dead code elimination pass.
+
+mod cse_expression;
+
+mod cse_program;
+
+mod cse_statement;
+
+pub mod common_subexpression_eliminator;
+pub use common_subexpression_eliminator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for CommonSubexpressionEliminator {
+    const NAME: &'static str = "cse";
+
+    type Input = Ast;
+    type Output = Result<Ast>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut reconstructor = CommonSubexpressionEliminator::default();
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Expression, Literal, Value};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct ConstantFolder<'a> {
+    /// An error handler used for any errors found during constant folding.
+    pub(crate) handler: &'a Handler,
+    /// A mapping from SSA variables to the literals assigned to them.
+    pub(crate) constants: IndexMap<Symbol, Literal>,
+}
+
+impl<'a> ConstantFolder<'a> {
+    pub(crate) fn new(handler: &'a Handler) -> Self {
+        Self {
+            handler,
+            constants: IndexMap::new(),
+        }
+    }
+
+    /// Replaces `expression` with the result of evaluating it, if the evaluation succeeded.
+    /// If the evaluation failed, for example, due to an overflow, the error is emitted and `expression` is kept.
+    pub(crate) fn fold(&self, expression: Expression, result: Result<Option<Value>>) -> Expression {
+        match result {
+            Ok(Some(value)) => match Literal::try_from(value) {
+                Ok(literal) => Expression::Literal(literal),
+                // Note that a value that cannot be written as a literal, e.g. an array, is not folded.
+                Err(_) => expression,
+            },
+            Ok(None) => expression,
+            Err(err) => {
+                self.handler.emit_err(err);
+                expression
+            }
+        }
+    }
+}
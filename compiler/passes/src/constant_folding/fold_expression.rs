@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for ConstantFolder<'_> {
+    type AdditionalOutput = ();
+
+    /// Folds a binary expression whose operands are both literals.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        let result = match (&left, &right) {
+            (Expression::Literal(left), Expression::Literal(right)) => {
+                Value::from(left).binary(input.op, Value::from(right), input.span)
+            }
+            _ => Ok(None),
+        };
+
+        let expression = Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op: input.op,
+            span: input.span,
+            operand_type: input.operand_type,
+        });
+
+        (self.fold(expression, result), Default::default())
+    }
+
+    /// Folds a cast of a literal.
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        let inner = self.reconstruct_expression(*input.expression).0;
+
+        let result = match &inner {
+            Expression::Literal(literal) => Value::from(literal).cast(&input.type_, input.span),
+            _ => Ok(None),
+        };
+
+        let expression = Expression::Cast(CastExpression {
+            expression: Box::new(inner),
+            type_: input.type_,
+            expression_type: input.expression_type,
+            lossy: input.lossy,
+            span: input.span,
+        });
+
+        (self.fold(expression, result), Default::default())
+    }
+
+    /// Replaces a variable that was assigned a literal with the literal.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        match self.constants.get(&input.name) {
+            Some(literal) => (Expression::Literal(literal.clone()), Default::default()),
+            None => (Expression::Identifier(input), Default::default()),
+        }
+    }
+
+    /// Folds the members of a struct initialization expression.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: member
+                            .expression
+                            .map(|expression| self.reconstruct_expression(expression).0),
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Replaces a ternary expression whose condition is a literal with the selected branch.
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(*input.condition).0;
+        let if_true = self.reconstruct_expression(*input.if_true).0;
+        let if_false = self.reconstruct_expression(*input.if_false).0;
+
+        let expression = match condition {
+            Expression::Literal(Literal::Boolean(true, _)) => if_true,
+            Expression::Literal(Literal::Boolean(false, _)) => if_false,
+            condition => Expression::Ternary(TernaryExpression {
+                condition: Box::new(condition),
+                if_true: Box::new(if_true),
+                if_false: Box::new(if_false),
+                span: input.span,
+            }),
+        };
+
+        (expression, Default::default())
+    }
+
+    /// Folds a unary expression whose operand is a literal.
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let receiver = self.reconstruct_expression(*input.receiver).0;
+
+        let result = match &receiver {
+            Expression::Literal(literal) => Value::from(literal).unary(input.op, input.span),
+            _ => Ok(None),
+        };
+
+        let expression = Expression::Unary(UnaryExpression {
+            receiver: Box::new(receiver),
+            op: input.op,
+            operand_type: input.operand_type,
+            span: input.span,
+        });
+
+        (self.fold(expression, result), Default::default())
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::*;
+
+impl ProgramReconstructor for ConstantFolder<'_> {
+    /// Folds the function body and the finalize block, if it exists.
+    /// Note that the propagated constants are local to the block in which they are assigned.
+    fn reconstruct_function(&mut self, function: Function) -> Function {
+        self.constants.clear();
+        let block = self.reconstruct_block(function.block).0;
+
+        let finalize = function.finalize.map(|finalize| {
+            self.constants.clear();
+            Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+            }
+        });
+
+        Function {
+            annotations: function.annotations,
+            variant: function.variant,
+            identifier: function.identifier,
+            input: function.input,
+            output: function.output,
+            output_type: function.output_type,
+            block,
+            finalize,
+            span: function.span,
+        }
+    }
+}
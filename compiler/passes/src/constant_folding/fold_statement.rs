@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::*;
+
+impl StatementReconstructor for ConstantFolder<'_> {
+    /// Folds the value of an assignment.
+    /// If the value folds into a literal, the literal is propagated to the uses of the assigned variable.
+    /// Note that the assignment is kept, since it does not produce any instructions.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+
+        if let (Expression::Identifier(identifier), Expression::Literal(literal)) = (&input.place, &value) {
+            self.constants.insert(identifier.name, literal.clone());
+        }
+
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: input.place,
+                value,
+                span: input.span,
+            })),
+            Default::default(),
+        )
+    }
+
+    /// Folds the index and amount of a decrement statement.
+    fn reconstruct_decrement(&mut self, input: DecrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Decrement(DecrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Folds the index and amount of an increment statement.
+    fn reconstruct_increment(&mut self, input: IncrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Increment(IncrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The constant folding pass traverses the AST after the SSA pass and evaluates expressions whose operands are known at compile time.
+//! The pass folds unary, binary, and cast expressions over literals into a single literal.
+//! The pass propagates literals through SSA assignments, replacing each use of the assigned variable with the literal.
+//! The pass replaces ternary expressions whose condition is a literal with the selected branch.
+//! Operations that overflow at compile time are reported as errors.
+//!
+//! Consider the following Leo code, output by the SSA pass.
+//! ```leo
+//! function main(flag: bool, value: u8) -> u8 {
+//!     $var$0 = 1u8 + 2u8;
+//!     x$1 = $var$0;
+//!     $var$2 = x$1 * 2u8;
+//!     $var$3 = true ? $var$2 : value;
+//!     $var$4 = value + $var$3;
+//!     return $var$4;
+//! }
+//! ```
+//!
+//! The constant folding pass produces the following code.
+//! ```leo
+//! function main(flag: bool, value: u8) -> u8 {
+//!     $var$0 = 3u8;
+//!     x$1 = 3u8;
+//!     $var$2 = 6u8;
+//!     $var$3 = 6u8;
+//!     $var$4 = value + 6u8;
+//!     return $var$4;
+//! }
+//! ```
+//! Note that assignments of literals have no effect on the bytecode generated by the compiler.
+
+mod fold_expression;
+
+mod fold_program;
+
+mod fold_statement;
+
+pub mod constant_folder;
+pub use constant_folder::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstantFolder<'a> {
+    const NAME: &'static str = "const_fold";
+
+    type Input = (Ast, &'a Handler);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut reconstructor = Self::new(handler);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CallGraph;
+
+use leo_ast::{AccessExpression, BinaryOperation, Expression, Identifier, Type, UnaryOperation};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexSet;
+
+pub struct DeadCodeEliminator<'a> {
+    /// The call graph for the program.
+    pub(crate) call_graph: &'a CallGraph,
+    /// The variables that are used by the statements visited so far.
+    /// Note that statements are visited in reverse order, so these are the uses after the current statement.
+    pub(crate) used_variables: IndexSet<Symbol>,
+}
+
+impl<'a> DeadCodeEliminator<'a> {
+    /// Initializes a new `DeadCodeEliminator`.
+    pub fn new(call_graph: &'a CallGraph) -> Self {
+        Self {
+            call_graph,
+            used_variables: Default::default(),
+        }
+    }
+
+    /// Returns `true` if evaluating the expression has no side effects, so that it can be removed if its result is unused.
+    pub(crate) fn is_pure(expression: &Expression) -> bool {
+        match expression {
+            // Mapping operations read or modify on-chain state.
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                !matches!(function.ty, Type::Identifier(Identifier { name: sym::Mapping, .. }))
+                    && function.args.iter().all(Self::is_pure)
+            }
+            Expression::Access(AccessExpression::Array(access)) => {
+                Self::is_pure(&access.array) && Self::is_pure(&access.index)
+            }
+            Expression::Access(AccessExpression::Member(access)) => Self::is_pure(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => Self::is_pure(&access.tuple),
+            Expression::Access(AccessExpression::AssociatedConstant(_)) => true,
+            Expression::Array(array) => array.elements.iter().all(Self::is_pure),
+            // Checked integer arithmetic halts on overflow, and division and remainder halt on a zero divisor.
+            // Field, group and scalar addition, subtraction and multiplication wrap around the modulus, so they never halt.
+            // Note that the left operand of a multiplication of a group by a scalar may be either.
+            Expression::Binary(binary) => {
+                let wraps_around = matches!(
+                    *binary.operand_type.borrow(),
+                    Some(Type::Field | Type::Group | Type::Scalar)
+                );
+                let may_halt = match binary.op {
+                    BinaryOperation::Add | BinaryOperation::Mul | BinaryOperation::Sub => !wraps_around,
+                    op => matches!(
+                        op,
+                        BinaryOperation::Div
+                            | BinaryOperation::DivWrapped
+                            | BinaryOperation::Mod
+                            | BinaryOperation::Pow
+                            | BinaryOperation::Rem
+                            | BinaryOperation::RemWrapped
+                            | BinaryOperation::Shl
+                            | BinaryOperation::Shr
+                    ),
+                };
+                !may_halt && Self::is_pure(&binary.left) && Self::is_pure(&binary.right)
+            }
+            // Calls may produce records, invoke other programs, or fail an assertion in the callee.
+            Expression::Call(_) => false,
+            // Only casts to `field` are lossless for every source type; the others halt if the value does not fit.
+            Expression::Cast(cast) => cast.type_ == Type::Field && Self::is_pure(&cast.expression),
+            Expression::Err(_) | Expression::Match(_) => false,
+            Expression::Identifier(_) | Expression::Literal(_) | Expression::Unit(_) => true,
+            Expression::Struct(struct_) => struct_
+                .members
+                .iter()
+                .all(|member| member.expression.as_ref().map_or(true, Self::is_pure)),
+            Expression::Ternary(ternary) => {
+                Self::is_pure(&ternary.condition) && Self::is_pure(&ternary.if_true) && Self::is_pure(&ternary.if_false)
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().all(Self::is_pure),
+            // `abs` and `neg` halt on the minimum signed integer, `inv` on zero, and `square_root` on a non-square.
+            Expression::Unary(unary) => {
+                !matches!(
+                    unary.op,
+                    UnaryOperation::Abs | UnaryOperation::Inverse | UnaryOperation::Negate | UnaryOperation::SquareRoot
+                ) && Self::is_pure(&unary.receiver)
+            }
+        }
+    }
+}
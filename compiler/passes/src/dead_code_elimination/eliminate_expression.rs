@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DeadCodeEliminator;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for DeadCodeEliminator<'_> {
+    type AdditionalOutput = ();
+
+    /// Marks the variable as used.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        self.used_variables.insert(input.name);
+        (Expression::Identifier(input), Default::default())
+    }
+
+    /// Marks the variables in the member initializers as used.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| {
+                        // A member without an initializer refers to the variable with the same name.
+                        let expression = match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            None => {
+                                self.used_variables.insert(member.identifier.name);
+                                None
+                            }
+                        };
+                        StructVariableInitializer {
+                            identifier: member.identifier,
+                            expression,
+                        }
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+}
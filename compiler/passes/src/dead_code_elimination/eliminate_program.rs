@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DeadCodeEliminator;
+
+use leo_ast::*;
+
+impl ProgramReconstructor for DeadCodeEliminator<'_> {
+    /// Removes the standard functions that are not reachable from a transition.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        let transitions = input
+            .functions
+            .values()
+            .filter(|function| matches!(function.variant, Variant::Transition))
+            .map(|function| function.name());
+        let reachable = self.call_graph.reachable_from(transitions);
+
+        ProgramScope {
+            program_id: input.program_id,
+            consts: input.consts,
+            structs: input.structs,
+            enums: input.enums,
+            mappings: input.mappings,
+            functions: input
+                .functions
+                .into_iter()
+                .filter(|(name, function)| !matches!(function.variant, Variant::Standard) || reachable.contains(name))
+                .map(|(name, function)| (name, self.reconstruct_function(function)))
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    /// Eliminates dead code in the function body and the finalize block, if it exists.
+    /// Note that the function body and the finalize block do not share variables.
+    fn reconstruct_function(&mut self, function: Function) -> Function {
+        self.used_variables.clear();
+        let block = self.reconstruct_block(function.block).0;
+
+        let finalize = function.finalize.map(|finalize| {
+            self.used_variables.clear();
+            Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+            }
+        });
+
+        Function {
+            annotations: function.annotations,
+            variant: function.variant,
+            identifier: function.identifier,
+            input: function.input,
+            output: function.output,
+            output_type: function.output_type,
+            block,
+            finalize,
+            span: function.span,
+        }
+    }
+
+    /// Imported programs are compiled separately, so they are left unchanged.
+    fn reconstruct_import(&mut self, input: Program) -> Program {
+        input
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DeadCodeEliminator;
+
+use leo_ast::*;
+
+impl StatementReconstructor for DeadCodeEliminator<'_> {
+    /// Reconstructs the value of an assignment, marking the variables it uses.
+    /// Note that the variables being assigned are not marked as used.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: input.place,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+            })),
+            Default::default(),
+        )
+    }
+
+    /// Visits the statements of the block in reverse order, removing assignments whose results are never used.
+    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
+        let mut statements = Vec::with_capacity(block.statements.len());
+
+        for statement in block.statements.into_iter().rev() {
+            if let Statement::Assign(assign) = &statement {
+                // Check whether any of the variables being assigned are used by a later statement.
+                let is_used = match &assign.place {
+                    Expression::Identifier(identifier) => self.used_variables.contains(&identifier.name),
+                    Expression::Tuple(tuple) => tuple.elements.iter().any(|element| match element {
+                        Expression::Identifier(identifier) => self.used_variables.contains(&identifier.name),
+                        _ => true,
+                    }),
+                    _ => true,
+                };
+                if !is_used && Self::is_pure(&assign.value) {
+                    continue;
+                }
+            }
+            statements.push(self.reconstruct_statement(statement).0);
+        }
+        statements.reverse();
+
+        (
+            Block {
+                statements,
+                span: block.span,
+            },
+            Default::default(),
+        )
+    }
+
+    /// Marks the variables in the index and amount of a decrement statement as used.
+    fn reconstruct_decrement(&mut self, input: DecrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Decrement(DecrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Marks the variables in the index and amount of an increment statement as used.
+    fn reconstruct_increment(&mut self, input: IncrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Increment(IncrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+}
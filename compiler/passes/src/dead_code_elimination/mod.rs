@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The dead code elimination pass traverses the AST after the function inlining pass and removes code that has no effect on the program.
+//! The pass removes `AssignStatement`s whose variables are never used and whose values are free of side effects.
+//! Calls, mapping operations, and operations that may halt the program, such as checked arithmetic or lossy casts, are always kept.
+//! The pass also removes standard functions that are not reachable from a transition in the call graph.
+//!
+//! Consider the following Leo code, output by the function inlining pass.
+//! ```leo
+//! function foo(x: u8) -> u8 {
+//!     return x;
+//! }
+//!
+//! transition main(flag: bool, value: u8) -> u8 {
+//!     $var$0 = value * value;
+//!     $var$1 = value.add_wrapped(1u8);
+//!     $var$2 = flag ? $var$0 : value;
+//!     return $var$2;
+//! }
+//! ```
+//!
+//! The dead code elimination pass produces the following code.
+//! ```leo
+//! transition main(flag: bool, value: u8) -> u8 {
+//!     $var$0 = value * value;
+//!     $var$2 = flag ? $var$0 : value;
+//!     return $var$2;
+//! }
+//! ```
+//! Note that `value * value` would be kept even if `$var$0` were unused, since the multiplication may overflow.
+
+mod eliminate_expression;
+
+mod eliminate_program;
+
+mod eliminate_statement;
+
+pub mod dead_code_eliminator;
+pub use dead_code_eliminator::*;
+
+use crate::{CallGraph, Pass};
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for DeadCodeEliminator<'a> {
+    const NAME: &'static str = "dce";
+
+    type Input = (Ast, &'a CallGraph);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, call_graph): Self::Input) -> Self::Output {
+        let mut reconstructor = DeadCodeEliminator::new(call_graph);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Enum, Identifier};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// A reconstructor that replaces enums with the structs they are lowered to.
+pub struct EnumLowerer {
+    /// The enums of the program.
+    pub(crate) enums: IndexMap<Symbol, Enum>,
+}
+
+impl EnumLowerer {
+    /// Initializes a new `EnumLowerer`, given the enums of the program.
+    pub(crate) fn new(enums: IndexMap<Symbol, Enum>) -> Self {
+        Self { enums }
+    }
+
+    /// Returns the definition of the enum named by `identifier`, if it is an enum.
+    pub(crate) fn lookup_enum(&self, identifier: &Identifier) -> Option<&Enum> {
+        self.enums.get(&identifier.name)
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for EnumLowerer {
+    type AdditionalOutput = ();
+
+    /// Replaces the construction of enum values, e.g. `Status::Open` and `Order::Limit(amount, price)`,
+    /// with the initialization of the structs they are lowered to.
+    fn reconstruct_access(&mut self, input: AccessExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            match input {
+                AccessExpression::AssociatedFunction(AssociatedFunction {
+                    ty: Type::Identifier(enum_name),
+                    name,
+                    args,
+                    span,
+                }) if self.lookup_enum(&enum_name).is_some() => {
+                    let args = args.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect();
+                    self.lookup_enum(&enum_name)
+                        .and_then(|enum_| enum_.construct(name.name, args, span))
+                        .expect("Type checking guarantees that the enum value is valid.")
+                }
+                AccessExpression::AssociatedConstant(AssociatedConstant {
+                    ty: Type::Identifier(enum_name),
+                    name,
+                    span,
+                }) if self.lookup_enum(&enum_name).is_some() => self
+                    .lookup_enum(&enum_name)
+                    .and_then(|enum_| enum_.construct(name.name, Vec::new(), span))
+                    .expect("Type checking guarantees that the enum value is valid."),
+                AccessExpression::Array(array) => Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.reconstruct_expression(*array.array).0),
+                    index: Box::new(self.reconstruct_expression(*array.index).0),
+                    span: array.span,
+                })),
+                AccessExpression::AssociatedFunction(function) => {
+                    Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                        ty: function.ty,
+                        name: function.name,
+                        args: function
+                            .args
+                            .into_iter()
+                            .map(|arg| self.reconstruct_expression(arg).0)
+                            .collect(),
+                        span: function.span,
+                    }))
+                }
+                AccessExpression::Member(member) => Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.reconstruct_expression(*member.inner).0),
+                    name: member.name,
+                    span: member.span,
+                })),
+                AccessExpression::Tuple(tuple) => Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: Box::new(self.reconstruct_expression(*tuple.tuple).0),
+                    index: tuple.index,
+                    span: tuple.span,
+                })),
+                expr => Expression::Access(expr),
+            },
+            Default::default(),
+        )
+    }
+
+    /// Lowers the enum values in the member initializers.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: member
+                            .expression
+                            .map(|expression| self.reconstruct_expression(expression).0),
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::*;
+
+impl ProgramReconstructor for EnumLowerer {
+    /// Replaces each enum with the struct it is lowered to.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            program_id: input.program_id,
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| (i, self.reconstruct_const(c)))
+                .collect(),
+            structs: input
+                .structs
+                .into_iter()
+                .chain(input.enums.values().map(|enum_| (enum_.name(), enum_.to_struct())))
+                .collect(),
+            enums: Default::default(),
+            mappings: input.mappings,
+            functions: input
+                .functions
+                .into_iter()
+                .map(|(i, f)| (i, self.reconstruct_function(f)))
+                .collect(),
+            span: input.span,
+        }
+    }
+}
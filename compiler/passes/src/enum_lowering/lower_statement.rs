@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::*;
+
+impl StatementReconstructor for EnumLowerer {
+    fn reconstruct_decrement(&mut self, input: DecrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Decrement(DecrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_increment(&mut self, input: IncrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Increment(IncrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The enum lowering pass replaces enums with structs after type checking, so that later passes only see structs.
+//! An enum is lowered to a struct with a `tag: u8` member, holding the index of the variant, followed by a member
+//! `{Variant}_{i}` for the `i`-th value carried by each variant. The values of the inactive variants are zero,
+//! so that two enum values are equal exactly when their structs are.
+//!
+//! The `match` statements and expressions over enums are left in place, with the enums in the symbol table,
+//! since they are lowered by the match lowering pass, which compares the tags of the lowered values.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! enum Order { Market(u64), Limit(u64, u64) }
+//!
+//! transition market(amount: u64) -> Order {
+//!     return Order::Market(amount);
+//! }
+//! ```
+//!
+//! The enum lowering pass produces the following code.
+//! ```leo
+//! struct Order { tag: u8, Market_0: u64, Limit_0: u64, Limit_1: u64 }
+//!
+//! transition market(amount: u64) -> Order {
+//!     return Order { tag: 0u8, Market_0: amount, Limit_0: 0u64, Limit_1: 0u64 };
+//! }
+//! ```
+
+mod lower_expression;
+
+mod lower_program;
+
+mod lower_statement;
+
+pub mod enum_lowerer;
+pub use enum_lowerer::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl Pass for EnumLowerer {
+    const NAME: &'static str = "lower_enums";
+
+    type Input = (Ast, SymbolTable);
+    type Output = Result<(Ast, SymbolTable)>;
+
+    fn do_pass((ast, mut symbol_table): Self::Input) -> Self::Output {
+        // Add the structs that the enums are lowered to, so that later passes treat them as any other struct.
+        // Note that the enums stay in the symbol table, so that matches and the inputs of the program can be lowered as well.
+        for (name, enum_) in symbol_table.enums.iter() {
+            symbol_table.structs.insert(*name, enum_.to_struct());
+        }
+
+        let mut reconstructor = Self::new(symbol_table.enums.clone());
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok((Ast::new(program), symbol_table))
+    }
+}
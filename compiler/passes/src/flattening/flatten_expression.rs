@@ -18,9 +18,11 @@ use crate::Flattener;
 use itertools::Itertools;
 
 use leo_ast::{
-    AccessExpression, AssociatedFunction, Expression, ExpressionReconstructor, Member, MemberAccess, Statement,
-    StructExpression, StructVariableInitializer, TernaryExpression, TupleExpression,
+    AccessExpression, ArrayAccess, ArrayExpression, AssociatedFunction, Expression, ExpressionReconstructor,
+    Identifier, IntegerType, Literal, Member, MemberAccess, Statement, StructExpression, StructVariableInitializer,
+    TernaryExpression, TupleExpression,
 };
+use std::cell::RefCell;
 
 // TODO: Clean up logic. To be done in a follow-up PR (feat/tuples)
 
@@ -99,7 +101,7 @@ impl ExpressionReconstructor for Flattener<'_> {
         )
     }
 
-    /// Reconstructs ternary expressions over tuples and structs, accumulating any statements that are generated.
+    /// Reconstructs ternary expressions over tuples, structs, and arrays, accumulating any statements that are generated.
     /// This is necessary because Aleo instructions does not support ternary expressions over composite data types.
     /// For example, the ternary expression `cond ? (a, b) : (c, d)` is flattened into the following:
     /// ```leo
@@ -114,6 +116,7 @@ impl ExpressionReconstructor for Flattener<'_> {
     /// let var$2 = Foo { bar: var$0, baz: var$1 };
     /// var$2
     /// ```
+    /// Arrays are handled similarly, with a ternary expression constructed for each element of the array.
     fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
         let mut statements = Vec::new();
         match (*input.if_true, *input.if_false) {
@@ -234,6 +237,25 @@ impl ExpressionReconstructor for Flattener<'_> {
                     _ => {
                         let if_true = Expression::Access(AccessExpression::Member(first));
                         let if_false = Expression::Access(AccessExpression::Member(second));
+
+                        // If the members are arrays, assign them to new variables and construct a ternary expression over the arrays.
+                        if self.lookup_array_type(&if_true).is_some() {
+                            let (first, statement) = self.unique_simple_assign_statement(if_true);
+                            statements.push(statement);
+                            let (second, statement) = self.unique_simple_assign_statement(if_false);
+                            statements.push(statement);
+
+                            let (expr, stmts) = self.reconstruct_ternary(TernaryExpression {
+                                condition: input.condition,
+                                if_true: Box::new(Expression::Identifier(first)),
+                                if_false: Box::new(Expression::Identifier(second)),
+                                span: input.span,
+                            });
+                            statements.extend(stmts);
+
+                            return (expr, statements);
+                        }
+
                         // Reconstruct the true case.
                         let (if_true, stmts) = self.reconstruct_expression(if_true);
                         statements.extend(stmts);
@@ -326,6 +348,65 @@ impl ExpressionReconstructor for Flattener<'_> {
 
                 (Expression::Identifier(identifier), statements)
             }
+            // If both expressions are identifiers which are arrays, construct ternary expression for each of the elements and an array expression for the result.
+            (Expression::Identifier(first), Expression::Identifier(second))
+                if self.arrays.contains_key(&first.name) && self.arrays.contains_key(&second.name) =>
+            {
+                // Note that this unwrap is safe since we check that `self.arrays` contains the key.
+                let array_type = self.arrays.get(&first.name).unwrap().clone();
+
+                // For each array element, construct a new ternary expression.
+                let elements = (0..array_type.length())
+                    .map(|i| {
+                        // Construct an access expression for the array element.
+                        let mut access = |array: Identifier| {
+                            let (element, statement) = self.unique_simple_assign_statement(Expression::Access(
+                                AccessExpression::Array(ArrayAccess {
+                                    array: Box::new(Expression::Identifier(array)),
+                                    index: Box::new(Expression::Literal(Literal::Integer(
+                                        IntegerType::U32,
+                                        i.to_string(),
+                                        Default::default(),
+                                    ))),
+                                    span: Default::default(),
+                                }),
+                            ));
+                            statements.push(statement);
+                            Expression::Identifier(element)
+                        };
+                        let if_true = access(first);
+                        let if_false = access(second);
+
+                        // Construct a new ternary expression for the array element.
+                        let (expression, stmts) = self.reconstruct_ternary(TernaryExpression {
+                            condition: input.condition.clone(),
+                            if_true: Box::new(if_true),
+                            if_false: Box::new(if_false),
+                            span: Default::default(),
+                        });
+
+                        // Accumulate any statements generated.
+                        statements.extend(stmts);
+
+                        // Create and accumulate an intermediate assignment statement for the ternary expression corresponding to the array element.
+                        let (result, statement) = self.unique_simple_assign_statement(expression);
+                        statements.push(statement);
+
+                        Expression::Identifier(result)
+                    })
+                    .collect();
+
+                // Create a new assignment statement for the array expression.
+                // Note that `self.arrays` is updated by `unique_simple_assign_statement`.
+                let (identifier, statement) = self.unique_simple_assign_statement(Expression::Array(ArrayExpression {
+                    elements,
+                    type_: RefCell::new(Some(array_type)),
+                    span: Default::default(),
+                }));
+                statements.push(statement);
+
+                (Expression::Identifier(identifier), statements)
+            }
             // If both expressions are identifiers which map to tuples, construct ternary expression over the tuples.
             (Expression::Identifier(first), Expression::Identifier(second))
                 if self.tuples.contains_key(&first.name) && self.tuples.contains_key(&second.name) =>
@@ -16,7 +16,7 @@
 
 use crate::Flattener;
 use itertools::Itertools;
-use std::borrow::Borrow;
+use std::{borrow::Borrow, cell::RefCell};
 
 use leo_ast::{
     AssertStatement, AssertVariant, AssignStatement, BinaryExpression, BinaryOperation, Block, ConditionalStatement,
@@ -86,6 +86,7 @@ impl StatementReconstructor for Flattener<'_> {
                         left: Box::new(Expression::Unary(UnaryExpression {
                             op: UnaryOperation::Not,
                             receiver: Box::new(guard),
+                            operand_type: RefCell::new(Some(Type::Boolean)),
                             span: Default::default(),
                         })),
                         op: BinaryOperation::Or,
@@ -99,6 +100,7 @@ impl StatementReconstructor for Flattener<'_> {
                                 op: BinaryOperation::Eq,
                                 right: Box::new(right),
                                 span: Default::default(),
+                                operand_type: Default::default(),
                             }),
                             // If the assert statement is an `assert_ne`, construct a new inequality expression.
                             AssertVariant::AssertNeq(left, right) => Expression::Binary(BinaryExpression {
@@ -106,8 +108,10 @@ impl StatementReconstructor for Flattener<'_> {
                                 op: BinaryOperation::Neq,
                                 right: Box::new(right),
                                 span: Default::default(),
+                                operand_type: Default::default(),
                             }),
                         }),
+                        operand_type: RefCell::new(Some(Type::Boolean)),
                     })),
                 }),
                 statements,
@@ -116,7 +120,7 @@ impl StatementReconstructor for Flattener<'_> {
     }
 
     /// Flattens an assign statement, if necessary.
-    /// Marks variables as structs as necessary.
+    /// Marks variables as structs and arrays as necessary.
     /// Note that new statements are only produced if the right hand side is a ternary expression over structs.
     /// Otherwise, the statement is returned as is.
     fn reconstruct_assign(&mut self, assign: AssignStatement) -> (Statement, Self::AdditionalOutput) {
@@ -164,10 +168,8 @@ impl StatementReconstructor for Flattener<'_> {
                                         self.assigner.unique_symbol(lhs_identifier.name, format!("$index${i}$")),
                                     );
 
-                                    // If the output type is a struct, add it to `self.structs`.
-                                    if let Type::Identifier(struct_name) = type_ {
-                                        self.structs.insert(identifier.name, struct_name.name);
-                                    }
+                                    // If the output type is a struct or an array, add it to `self.structs` or `self.arrays`.
+                                    self.track_type(&identifier, type_);
 
                                     Expression::Identifier(identifier)
                                 })
@@ -188,10 +190,8 @@ impl StatementReconstructor for Flattener<'_> {
                     }
                     // Otherwise, reconstruct the assignment as is.
                     type_ => {
-                        // If the function returns a struct, add it to `self.structs`.
-                        if let Type::Identifier(struct_name) = type_ {
-                            self.structs.insert(lhs_identifier.name, struct_name.name);
-                        };
+                        // If the function returns a struct or an array, add it to `self.structs` or `self.arrays`.
+                        self.track_type(&lhs_identifier, type_);
                         (
                             Statement::Assign(Box::new(AssignStatement {
                                 place: Expression::Identifier(lhs_identifier),
@@ -205,6 +205,7 @@ impl StatementReconstructor for Flattener<'_> {
             }
             (Expression::Identifier(identifier), expression) => {
                 self.update_structs(&identifier, &expression);
+                self.update_arrays(&identifier, &expression);
                 (
                     self.assigner.simple_assign_statement(identifier, expression),
                     statements,
@@ -237,10 +238,8 @@ impl StatementReconstructor for Flattener<'_> {
                                 "Type checking guarantees that a tuple element on the lhs is an identifier."
                             ),
                         };
-                        // If the output type is a struct, add it to `self.structs`.
-                        if let Type::Identifier(struct_name) = type_ {
-                            self.structs.insert(identifier.name, struct_name.name);
-                        }
+                        // If the output type is a struct or an array, add it to `self.structs` or `self.arrays`.
+                        self.track_type(identifier, type_);
                     });
 
                 (
@@ -261,6 +260,7 @@ impl StatementReconstructor for Flattener<'_> {
                             _ => unreachable!("Type checking guarantees that `lhs` is an identifier."),
                         };
                         self.update_structs(identifier, &rhs);
+                        self.update_arrays(identifier, &rhs);
                         Statement::Assign(Box::new(AssignStatement {
                             place: lhs,
                             value: rhs,
@@ -284,6 +284,7 @@ impl StatementReconstructor for Flattener<'_> {
                         _ => unreachable!("Type checking guarantees that `lhs` is an identifier."),
                     };
                     self.update_structs(identifier, &rhs);
+                    self.update_arrays(identifier, &rhs);
 
                     statements.push(Statement::Assign(Box::new(AssignStatement {
                         place: lhs,
@@ -346,6 +347,7 @@ impl StatementReconstructor for Flattener<'_> {
             self.condition_stack.push(Expression::Unary(UnaryExpression {
                 op: UnaryOperation::Not,
                 receiver: Box::new(conditional.condition.clone()),
+                operand_type: RefCell::new(Some(Type::Boolean)),
                 span: conditional.condition.span(),
             }));
 
@@ -17,12 +17,13 @@
 use crate::{Assigner, SymbolTable};
 
 use leo_ast::{
-    AccessExpression, BinaryExpression, BinaryOperation, Block, Expression, ExpressionReconstructor, Identifier,
-    Member, ReturnStatement, Statement, TernaryExpression, TupleExpression, Type,
+    AccessExpression, ArrayType, BinaryExpression, BinaryOperation, Block, Expression, ExpressionReconstructor,
+    Identifier, Member, ReturnStatement, Statement, TernaryExpression, TupleExpression, Type,
 };
 use leo_span::Symbol;
 
 use indexmap::IndexMap;
+use std::cell::RefCell;
 
 pub struct Flattener<'a> {
     /// The symbol table associated with the program.
@@ -31,6 +32,8 @@ pub struct Flattener<'a> {
     pub(crate) assigner: Assigner,
     /// The set of variables that are structs.
     pub(crate) structs: IndexMap<Symbol, Symbol>,
+    /// The set of variables that are arrays, along with their types.
+    pub(crate) arrays: IndexMap<Symbol, ArrayType>,
     /// A stack of condition `Expression`s visited up to the current point in the AST.
     pub(crate) condition_stack: Vec<Expression>,
     /// A list containing tuples of guards and expressions associated `ReturnStatement`s.
@@ -48,6 +51,7 @@ impl<'a> Flattener<'a> {
             symbol_table,
             assigner,
             structs: IndexMap::new(),
+            arrays: IndexMap::new(),
             condition_stack: Vec::new(),
             returns: Vec::new(),
             tuples: IndexMap::new(),
@@ -71,6 +75,7 @@ impl<'a> Flattener<'a> {
                         left: Box::new(acc),
                         right: Box::new(condition),
                         span: Default::default(),
+                        operand_type: RefCell::new(Some(Type::Boolean)),
                     })
                 }))
             }
@@ -150,6 +155,45 @@ impl<'a> Flattener<'a> {
         }
     }
 
+    /// Looks up the type of an identifier or access expression, if it is an array.
+    pub(crate) fn lookup_array_type(&self, expression: &Expression) -> Option<ArrayType> {
+        match expression {
+            Expression::Identifier(identifier) => self.arrays.get(&identifier.name).cloned(),
+            Expression::Array(array) => array.type_.borrow().clone(),
+            Expression::Access(AccessExpression::Array(access)) => {
+                match *self.lookup_array_type(&access.array)?.element_type {
+                    Type::Array(array_type) => Some(array_type),
+                    _ => None,
+                }
+            }
+            Expression::Access(AccessExpression::Member(access)) => {
+                let name = self.lookup_struct_symbol(&access.inner)?;
+                let struct_ = self.symbol_table.lookup_struct(name)?;
+                match struct_.members.iter().find(|member| member.name() == access.name.name) {
+                    Some(Member {
+                        type_: Type::Array(array_type),
+                        ..
+                    }) => Some(array_type.clone()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Marks a variable as a struct or an array, if its type is a struct or an array.
+    pub(crate) fn track_type(&mut self, identifier: &Identifier, type_: &Type) {
+        match type_ {
+            Type::Identifier(struct_name) => {
+                self.structs.insert(identifier.name, struct_name.name);
+            }
+            Type::Array(array_type) => {
+                self.arrays.insert(identifier.name, array_type.clone());
+            }
+            _ => (),
+        }
+    }
+
     /// Updates `self.structs` for new assignment statements.
     /// Expects the left hand side of the assignment to be an identifier.
     pub(crate) fn update_structs(&mut self, lhs: &Identifier, rhs: &Expression) {
@@ -162,26 +206,44 @@ impl<'a> Flattener<'a> {
                 // Note that this unwrap is safe because we just checked that the key exists.
                 self.structs.insert(lhs.name, *self.structs.get(&rhs.name).unwrap());
             }
+            // If the rhs of the assignment is an element of an array of structs, add it to `self.structs`.
+            Expression::Access(AccessExpression::Array(access)) => {
+                if let Some(array_type) = self.lookup_array_type(&access.array) {
+                    if let Type::Identifier(struct_name) = array_type.element_type.as_ref() {
+                        self.structs.insert(lhs.name, struct_name.name);
+                    }
+                }
+            }
             // Otherwise, do nothing.
             _ => (),
         }
     }
 
-    /// A wrapper around `assigner.unique_simple_assign_statement` that updates `self.structs`.
+    /// Updates `self.arrays` for new assignment statements.
+    /// Expects the left hand side of the assignment to be an identifier.
+    pub(crate) fn update_arrays(&mut self, lhs: &Identifier, rhs: &Expression) {
+        if let Some(array_type) = self.lookup_array_type(rhs) {
+            self.arrays.insert(lhs.name, array_type);
+        }
+    }
+
+    /// A wrapper around `assigner.unique_simple_assign_statement` that updates `self.structs` and `self.arrays`.
     pub(crate) fn unique_simple_assign_statement(&mut self, expr: Expression) -> (Identifier, Statement) {
         let (place, statement) = self.assigner.unique_simple_assign_statement(expr);
         match &statement {
             Statement::Assign(assign) => {
                 self.update_structs(&place, &assign.value);
+                self.update_arrays(&place, &assign.value);
             }
             _ => unreachable!("`assigner.unique_simple_assign_statement` always returns an assignment statement."),
         }
         (place, statement)
     }
 
-    /// A wrapper around `assigner.simple_assign_statement` that updates `self.structs`.
+    /// A wrapper around `assigner.simple_assign_statement` that updates `self.structs` and `self.arrays`.
     pub(crate) fn simple_assign_statement(&mut self, lhs: Identifier, rhs: Expression) -> Statement {
         self.update_structs(&lhs, &rhs);
+        self.update_arrays(&lhs, &rhs);
         self.assigner.simple_assign_statement(lhs, rhs)
     }
 
@@ -65,6 +65,8 @@ use leo_ast::{Ast, ProgramReconstructor};
 use leo_errors::Result;
 
 impl<'a> Pass for Flattener<'a> {
+    const NAME: &'static str = "flatten";
+
     type Input = (Ast, &'a SymbolTable, Assigner);
     type Output = Result<(Ast, Assigner)>;
 
@@ -51,7 +51,7 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
                 let parameter_to_argument = callee
                     .input
                     .iter()
-                    .map(|input| input.identifier())
+                    .map(|input| input.identifier().name)
                     .zip_eq(input.arguments.into_iter())
                     .collect::<IndexMap<_, _>>();
 
@@ -71,7 +71,8 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
                 self.assignment_renamer.clear();
 
                 // Replace each input variable with the appropriate parameter.
-                let replace = |identifier: &Identifier| match parameter_to_argument.get(identifier) {
+                // Note that the uses of an input variable are matched by name, since their spans differ from the span of the input.
+                let replace = |identifier: &Identifier| match parameter_to_argument.get(&identifier.name) {
                     Some(expression) => expression.clone(),
                     None => Expression::Identifier(*identifier),
                 };
@@ -49,7 +49,9 @@ impl ProgramReconstructor for FunctionInliner<'_> {
 
         ProgramScope {
             program_id: input.program_id,
+            consts: input.consts,
             structs: input.structs,
+            enums: input.enums,
             mappings: input.mappings,
             functions,
             span: input.span,
@@ -70,6 +70,8 @@ use leo_ast::{Ast, ProgramReconstructor};
 use leo_errors::Result;
 
 impl<'a> Pass for FunctionInliner<'a> {
+    const NAME: &'static str = "inline";
+
     type Input = (Ast, &'a CallGraph, Assigner);
     type Output = Result<(Ast, Assigner)>;
 
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The import resolution pass loads the programs imported by the main program after parsing.
+//! An import `import foo.leo;` names the file `foo.leo` in the import directory, which may import other programs in turn.
+//! An import `import foo.aleo;` names the file `foo.aleo` in the import directory, which contains the Aleo instructions
+//! of a deployed program. Such a program is not compiled; instead, the pass constructs its interface, or stub,
+//! which declares the records, structs, mappings, and transitions of the program, so that calls to it can be checked.
+//!
+//! The pass builds the import dependency graph, with an edge from each program to each program it imports,
+//! and reports an error if the graph has a cycle.
+//! Each imported program is loaded once, even if it is imported by several programs.
+//!
+//! Consider the following Leo code, where `bar.leo` imports `baz.leo`.
+//! ```leo
+//! import bar.leo;
+//!
+//! program foo.aleo { ... }
+//! ```
+//!
+//! After import resolution, the imports of `foo.aleo` are `baz.aleo` followed by `bar.aleo`.
+//! That is, the imports of the main program contain every program that is imported, directly or indirectly,
+//! in dependency order, and the imported programs do not have imports of their own.
+
+pub mod resolver;
+pub use resolver::*;
+
+use crate::Pass;
+
+use leo_ast::Ast;
+use leo_errors::{emitter::Handler, Result};
+
+use std::path::Path;
+
+impl<'a> Pass for ImportResolver<'a> {
+    const NAME: &'static str = "resolve_imports";
+
+    type Input = (Ast, &'a Handler, &'a Path, Disassembler);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, directory, disassembler): Self::Input) -> Self::Output {
+        let mut program = ast.into_repr();
+
+        // Note that the parser may have recovered from syntax errors, so only the errors found here stop the pass.
+        let err_count = handler.err_count();
+        let mut resolver = ImportResolver::new(handler, directory, disassembler);
+        (program.stubs, program.imports) = resolver.resolve(&program)?;
+        if handler.err_count() > err_count {
+            handler.last_err().map_err(|e| *e)?;
+        }
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DiGraphError, ImportGraph};
+
+use leo_ast::Program;
+use leo_errors::{emitter::Handler, CompilerError, Result, TypeCheckerError};
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::path::{Path, PathBuf};
+
+/// Constructs the interface of a program from its Aleo instructions, given the span of the statement importing it.
+/// Note that parsing Aleo instructions requires snarkVM, so the function is supplied by the compiler.
+pub type Disassembler = fn(&str, Span) -> Result<Program>;
+
+/// The imported programs, with the span of the first import statement naming them.
+type Imports = IndexMap<Symbol, (Program, Span)>;
+
+/// A compiler pass that loads the programs imported by the main program, and the programs they import in turn.
+pub struct ImportResolver<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The directory containing the imported programs.
+    directory: &'a Path,
+    /// Constructs the interfaces of the programs imported from Aleo instructions.
+    disassembler: Disassembler,
+    /// The import dependency graph, with an edge from each program to each program it imports.
+    import_graph: ImportGraph,
+    /// The imported programs, with the span of the first import statement naming them.
+    programs: Imports,
+    /// The names of the programs imported from Aleo instructions.
+    stubs: IndexSet<Symbol>,
+}
+
+impl<'a> ImportResolver<'a> {
+    /// Returns a new import resolver, which loads the imported programs from `directory`.
+    pub fn new(handler: &'a Handler, directory: &'a Path, disassembler: Disassembler) -> Self {
+        Self {
+            handler,
+            directory,
+            disassembler,
+            import_graph: ImportGraph::new(IndexSet::new()),
+            programs: IndexMap::new(),
+            stubs: IndexSet::new(),
+        }
+    }
+
+    /// Returns the programs imported by `program`, directly or indirectly, in dependency order.
+    /// That is, each program precedes the programs that import it.
+    /// The interfaces of the programs imported from Aleo instructions are returned first, followed by the Leo programs.
+    pub fn resolve(&mut self, program: &Program) -> Result<(Imports, Imports)> {
+        // Note that parsing enforces that there is exactly one program scope in a file.
+        let name = match program.program_scopes.keys().next() {
+            Some(program_id) => program_id.name.name,
+            None => return Ok(Default::default()),
+        };
+        self.import_graph = ImportGraph::new(IndexSet::from([name]));
+
+        // Load the imported programs, so that each one is loaded once.
+        let mut pending = vec![(name, Self::imports_of(program))];
+        while let Some((importer, imports)) = pending.pop() {
+            for (import, span, is_aleo) in imports {
+                self.import_graph.add_edge(importer, import);
+
+                // Note that importing the main program is a cycle, which is reported below.
+                if import == name || self.programs.contains_key(&import) {
+                    continue;
+                }
+
+                let mut imported = match is_aleo {
+                    true => self.load_stub(import, span)?,
+                    false => self.load(import, span)?,
+                };
+                pending.push((import, Self::imports_of(&imported)));
+                // The imports of an imported program are imported by the main program instead.
+                imported.imports.clear();
+                imported.stubs.clear();
+                if is_aleo {
+                    self.stubs.insert(import);
+                }
+                self.programs.insert(import, (imported, span));
+            }
+        }
+
+        // Order the imported programs so that each one precedes the programs that import it.
+        match self.import_graph.post_order() {
+            Ok(order) => Ok(order
+                .into_iter()
+                .filter_map(|import| self.programs.swap_remove(&import).map(|program| (import, program)))
+                .partition(|(import, _)| self.stubs.contains(import))),
+            Err(DiGraphError::CycleDetected(path)) => {
+                // Report the cycle at the import statement of the main program that leads to it.
+                let span = Self::imports_of(program)
+                    .into_iter()
+                    .find(|(import, ..)| self.import_graph.reachable_from([*import]).contains(&path[0]))
+                    .map_or_else(Span::default, |(_, span, _)| span);
+                let path = path.into_iter().map(|name| format!("{name}.aleo")).collect();
+                Err(TypeCheckerError::cyclic_import_dependency(path, span).into())
+            }
+        }
+    }
+
+    /// Returns the names of the programs imported by `program`, with the spans of their import statements,
+    /// and whether they are imported from Aleo instructions.
+    fn imports_of(program: &Program) -> Vec<(Symbol, Span, bool)> {
+        let stubs = program.stubs.iter().map(|(name, (_, span))| (*name, *span, true));
+        let imports = program.imports.iter().map(|(name, (_, span))| (*name, *span, false));
+        stubs.chain(imports).collect()
+    }
+
+    /// Parses the program `name` from the file `name.leo` in the import directory.
+    fn load(&self, name: Symbol, span: Span) -> Result<Program> {
+        let path = self.path(name, "leo", span)?;
+
+        let source_file = with_session_globals(|s| s.source_map.load_file(&path))
+            .map_err(|e| CompilerError::file_read_error(&path, e))?;
+
+        Ok(leo_parser::parse_ast(self.handler, &source_file.src, source_file.start_pos)?.into_repr())
+    }
+
+    /// Constructs the interface of the program `name` from the Aleo instructions in the file `name.aleo` in the import directory.
+    fn load_stub(&self, name: Symbol, span: Span) -> Result<Program> {
+        let path = self.path(name, "aleo", span)?;
+
+        let bytecode = std::fs::read_to_string(&path).map_err(|e| CompilerError::file_read_error(&path, e))?;
+
+        (self.disassembler)(&bytecode, span)
+    }
+
+    /// Returns the path of the file `name.extension` in the import directory, if it exists.
+    fn path(&self, name: Symbol, extension: &str, span: Span) -> Result<PathBuf> {
+        let path = self.directory.join(format!("{name}.{extension}"));
+        match path.exists() {
+            true => Ok(path),
+            false => Err(CompilerError::import_not_found(path.display(), span).into()),
+        }
+    }
+}
@@ -23,23 +23,50 @@ pub use code_generation::*;
 pub mod common;
 pub use common::*;
 
+pub mod common_subexpression_elimination;
+pub use common_subexpression_elimination::*;
+
+pub mod constant_folding;
+pub use constant_folding::*;
+
+pub mod dead_code_elimination;
+pub use dead_code_elimination::*;
+
+pub mod enum_lowering;
+pub use enum_lowering::*;
+
 pub mod flattening;
 pub use flattening::*;
 
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod import_resolution;
+pub use import_resolution::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod match_lowering;
+pub use match_lowering::*;
+
+pub mod module_resolution;
+pub use module_resolution::*;
+
 pub mod pass;
 pub use self::pass::*;
 
+pub mod pass_manager;
+pub use self::pass_manager::*;
+
 pub mod static_single_assignment;
 pub use static_single_assignment::*;
 
 pub mod symbol_table_creation;
 pub use symbol_table_creation::*;
 
+pub mod taint_analysis;
+pub use taint_analysis::*;
+
 pub mod type_checking;
 pub use type_checking::*;
@@ -35,6 +35,8 @@ use leo_ast::{Ast, ProgramReconstructor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for Unroller<'a> {
+    const NAME: &'static str = "unroll";
+
     type Input = (Ast, &'a Handler, SymbolTable);
     type Output = Result<(Ast, SymbolTable)>;
 
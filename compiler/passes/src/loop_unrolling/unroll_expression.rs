@@ -20,4 +20,123 @@ use crate::Unroller;
 
 impl ExpressionReconstructor for Unroller<'_> {
     type AdditionalOutput = ();
+
+    /// Resolves the indices of array accesses, so that they are constant.
+    fn reconstruct_access(&mut self, input: AccessExpression) -> (Expression, Self::AdditionalOutput) {
+        match input {
+            AccessExpression::Array(access) => {
+                let array = self.reconstruct_expression(*access.array).0;
+                let length = match self.lookup_type(&array) {
+                    Some(Type::Array(array_type)) => Some(array_type.length()),
+                    _ => None,
+                };
+                let index = self.reconstruct_expression(*access.index).0;
+                (
+                    Expression::Access(AccessExpression::Array(ArrayAccess {
+                        array: Box::new(array),
+                        index: Box::new(self.resolve_array_index(index, length)),
+                        span: access.span,
+                    })),
+                    Default::default(),
+                )
+            }
+            AccessExpression::AssociatedFunction(function) => (
+                Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                    ty: function.ty,
+                    name: function.name,
+                    args: function
+                        .args
+                        .into_iter()
+                        .map(|arg| self.reconstruct_expression(arg).0)
+                        .collect(),
+                    span: function.span,
+                })),
+                Default::default(),
+            ),
+            AccessExpression::Member(member) => (
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.reconstruct_expression(*member.inner).0),
+                    name: member.name,
+                    span: member.span,
+                })),
+                Default::default(),
+            ),
+            AccessExpression::Tuple(tuple) => (
+                Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: Box::new(self.reconstruct_expression(*tuple.tuple).0),
+                    index: tuple.index,
+                    span: tuple.span,
+                })),
+                Default::default(),
+            ),
+            expr => (Expression::Access(expr), Default::default()),
+        }
+    }
+
+    /// Folds cast expressions whose operands are literals, constants, or loop variables.
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        let expression = self.reconstruct_expression(*input.expression).0;
+
+        // Lossy casts are left to snarkVM, which truncates the value.
+        if let (Expression::Literal(literal), false) = (&expression, input.lossy.get()) {
+            match Value::from(literal).cast(&input.type_, input.span) {
+                Ok(Some(value)) => {
+                    if let Ok(literal) = Literal::try_from(value) {
+                        return (Expression::Literal(literal), Default::default());
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => self.handler.emit_err(err),
+            }
+        }
+
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_: input.type_,
+                expression_type: input.expression_type,
+                lossy: input.lossy,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Substitutes the values of constants and loop variables.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        match self.constants.get(&input.name) {
+            Some(value) => {
+                let mut literal = value.clone();
+                literal.set_span(input.span);
+                (Expression::Literal(literal), Default::default())
+            }
+            None => (Expression::Identifier(input), Default::default()),
+        }
+    }
+
+    /// Reconstructs the members of a struct init expression, since they may contain array accesses or constants.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            // The shorthand `Foo { x }` is expanded if `x` is a constant.
+                            None if self.constants.contains_key(&member.identifier.name) => {
+                                Some(self.reconstruct_identifier(member.identifier).0)
+                            }
+                            None => None,
+                        },
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
 }
@@ -19,6 +19,25 @@ use leo_ast::*;
 use crate::Unroller;
 
 impl ProgramReconstructor for Unroller<'_> {
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> ConstDeclaration {
+        // Evaluate the constant, so that its value can be substituted into its uses.
+        // Note that the declaration is kept, with its value folded into a literal.
+        let value = match self.evaluate_literal(&input.value) {
+            Some(literal) => {
+                self.constants.insert(input.identifier.name, literal.clone());
+                Expression::Literal(literal)
+            }
+            None => input.value,
+        };
+
+        ConstDeclaration {
+            identifier: input.identifier,
+            type_: input.type_,
+            value,
+            span: input.span,
+        }
+    }
+
     fn reconstruct_function(&mut self, function: Function) -> Function {
         // Lookup function metadata in the symbol table.
         // Note that this unwrap is safe since function metadata is stored in a prior pass.
@@ -22,12 +22,28 @@ use crate::unroller::Unroller;
 use crate::{VariableSymbol, VariableType};
 
 impl StatementReconstructor for Unroller<'_> {
+    /// Resolves the array indices in an assignment statement.
+    /// Note that the static single assignment pass desugars assignments to array elements, struct members,
+    /// and tuple elements into assignments to the variable, once the indices are constants.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: self.reconstruct_expression(input.place).0,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+            })),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
         let scope_index = self.current_scope_index();
 
         // Enter the block scope.
         let previous_scope_index = self.enter_scope(scope_index);
 
+        let num_constants = self.constants.len();
+
         let block = Block {
             statements: input
                 .statements
@@ -37,6 +53,9 @@ impl StatementReconstructor for Unroller<'_> {
             span: input.span,
         };
 
+        // Remove the constants declared in the block.
+        self.constants.truncate(num_constants);
+
         // Exit the block scope.
         self.exit_scope(previous_scope_index);
 
@@ -85,10 +104,59 @@ impl StatementReconstructor for Unroller<'_> {
 
             }
         }
-        (Statement::Definition(input), Default::default())
+
+        // Constants are evaluated and substituted into their uses, so they are removed from the program.
+        if input.declaration_type == DeclarationType::Const {
+            if let Expression::Identifier(identifier) = &input.place {
+                if let Some(literal) = self.evaluate_literal(&input.value) {
+                    self.constants.insert(identifier.name, literal);
+                }
+            }
+            return (Statement::dummy(input.span), Default::default());
+        }
+
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_: input.type_,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_decrement(&mut self, input: DecrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Decrement(DecrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_increment(&mut self, input: IncrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Increment(IncrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
     }
 
     fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        // Evaluate the loop bounds.
+        // Note that type checking guarantees that the loop bounds are constant expressions.
+        input.start_value.replace(self.evaluate_constant(&input.start));
+        input.stop_value.replace(self.evaluate_constant(&input.stop));
+
         // We match on start and stop cause loops require
         // bounds to be constants.
         match (
@@ -15,12 +15,15 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::{
-    Block, DeclarationType, DefinitionStatement, Expression, IntegerType, IterationStatement, Literal, Statement,
-    StatementReconstructor, Type, Value,
+    AccessExpression, Block, DeclarationType, DefinitionStatement, Expression, IntegerType, IterationStatement,
+    Literal, Node, Statement, StatementReconstructor, Type, Value,
 };
 use std::cell::RefCell;
 
-use leo_errors::emitter::Handler;
+use leo_errors::{emitter::Handler, FlattenError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
 
 use crate::{Clusivity, LoopBound, RangeIterator, SymbolTable};
 
@@ -33,6 +36,9 @@ pub struct Unroller<'a> {
     pub(crate) handler: &'a Handler,
     /// Are we in the midst of unrolling a loop?
     pub(crate) is_unrolling: bool,
+    /// A mapping from constants and loop variables to their values.
+    /// Note that constants that are no longer in scope are removed from the mapping.
+    pub(crate) constants: IndexMap<Symbol, Literal>,
 }
 
 impl<'a> Unroller<'a> {
@@ -42,7 +48,132 @@ impl<'a> Unroller<'a> {
             scope_index: 0,
             handler,
             is_unrolling: false,
+            constants: IndexMap::new(),
+        }
+    }
+
+    /// Returns the type of an expression, if it can be determined from the symbol table.
+    /// Note that this is always possible for expressions that produce arrays.
+    pub(crate) fn lookup_type(&self, expression: &Expression) -> Option<Type> {
+        match expression {
+            Expression::Identifier(identifier) => self
+                .symbol_table
+                .borrow()
+                .lookup_variable(identifier.name)
+                .map(|variable| variable.type_.clone()),
+            Expression::Array(array) => array.type_.borrow().clone().map(Type::Array),
+            Expression::Access(AccessExpression::Array(access)) => match self.lookup_type(&access.array)? {
+                Type::Array(array_type) => Some(*array_type.element_type),
+                _ => None,
+            },
+            Expression::Access(AccessExpression::Member(access)) => match self.lookup_type(&access.inner)? {
+                Type::Identifier(identifier) => self
+                    .symbol_table
+                    .borrow()
+                    .lookup_struct(identifier.name)?
+                    .members
+                    .iter()
+                    .find(|member| member.name() == access.name.name)
+                    .map(|member| member.type_.clone()),
+                _ => None,
+            },
+            Expression::Access(AccessExpression::Tuple(access)) => match self.lookup_type(&access.tuple)? {
+                Type::Tuple(tuple) => tuple.get(access.index.to_usize()).cloned(),
+                _ => None,
+            },
+            Expression::Call(call) => match &*call.function {
+                Expression::Identifier(identifier) => self
+                    .symbol_table
+                    .borrow()
+                    .lookup_fn_symbol(identifier.name)
+                    .map(|function| function.output_type.clone()),
+                _ => None,
+            },
+            Expression::Struct(struct_) => Some(Type::Identifier(struct_.name)),
+            Expression::Match(match_) => match_.arms.first().and_then(|arm| self.lookup_type(&arm.expression)),
+            Expression::Ternary(ternary) => self.lookup_type(&ternary.if_true),
+            _ => None,
+        }
+    }
+
+    /// Evaluates an expression that type checking guarantees to be constant.
+    /// Emits an error and returns `None` if the expression cannot be evaluated at compile time.
+    pub(crate) fn evaluate_constant(&self, expression: &Expression) -> Option<Value> {
+        let result = match expression {
+            Expression::Literal(literal) => return Some(Value::from(literal)),
+            // Note that a constant is missing only if an error was emitted while evaluating it.
+            Expression::Identifier(identifier) => return self.constants.get(&identifier.name).map(Value::from),
+            Expression::Binary(binary) => {
+                let left = self.evaluate_constant(&binary.left)?;
+                let right = self.evaluate_constant(&binary.right)?;
+                left.binary(binary.op, right, binary.span)
+            }
+            Expression::Cast(cast) => {
+                let value = self.evaluate_constant(&cast.expression)?;
+                match cast.lossy.get() {
+                    true => value.cast_lossy(&cast.type_, cast.span),
+                    false => value.cast(&cast.type_, cast.span),
+                }
+            }
+            Expression::Ternary(ternary) => match self.evaluate_constant(&ternary.condition)? {
+                Value::Boolean(true, _) => return self.evaluate_constant(&ternary.if_true),
+                Value::Boolean(false, _) => return self.evaluate_constant(&ternary.if_false),
+                _ => unreachable!("Type checking guarantees that the condition of a ternary is a boolean."),
+            },
+            Expression::Unary(unary) => self.evaluate_constant(&unary.receiver)?.unary(unary.op, unary.span),
+            _ => Ok(None),
+        };
+
+        match result {
+            Ok(Some(value)) => Some(value),
+            Ok(None) => {
+                self.handler.emit_err(FlattenError::constant_cannot_be_evaluated(
+                    expression,
+                    expression.span(),
+                ));
+                None
+            }
+            Err(err) => {
+                self.handler.emit_err(err);
+                None
+            }
+        }
+    }
+
+    /// Evaluates a constant expression to a literal, which can be substituted into the uses of the constant.
+    /// Emits an error and returns `None` if the expression cannot be evaluated, or if its value cannot be written as a literal.
+    pub(crate) fn evaluate_literal(&self, expression: &Expression) -> Option<Literal> {
+        match Literal::try_from(self.evaluate_constant(expression)?) {
+            Ok(literal) => Some(literal),
+            Err(_) => {
+                self.handler.emit_err(FlattenError::constant_cannot_be_evaluated(
+                    expression,
+                    expression.span(),
+                ));
+                None
+            }
+        }
+    }
+
+    /// Checks that an array index, whose constants have already been substituted, is a literal that is in range.
+    /// Emits an error if the index is not a literal or is out of range.
+    pub(crate) fn resolve_array_index(&self, index: Expression, length: Option<usize>) -> Expression {
+        let span = index.span();
+
+        match (&index, length) {
+            (Expression::Literal(Literal::Integer(_, value, _)), Some(length)) => {
+                if value.parse::<usize>().map_or(true, |value| value >= length) {
+                    self.handler
+                        .emit_err(FlattenError::array_index_out_of_range(value, length, span));
+                }
+            }
+            (Expression::Literal(_), None) => {}
+            _ => self
+                .handler
+                .emit_err(FlattenError::array_index_must_be_constant(&index, span)),
         }
+
+        index
     }
 
     /// Returns the index of the current scope.
@@ -79,27 +210,25 @@ impl<'a> Unroller<'a> {
         start: Value,
         stop: Value,
     ) -> Statement {
-        // Closure to check that the constant values are valid u128.
+        // Closure to check that the constant values are valid u128, emitting an error otherwise.
         // We already know these are integers since loop unrolling occurs after type checking.
-        let cast_to_number = |v: Value| -> Result<I, Statement> {
+        let cast_to_number = |v: Value| -> Option<I> {
             match v.try_into() {
-                Ok(val_as_u128) => Ok(val_as_u128),
+                Ok(val_as_u128) => Some(val_as_u128),
                 Err(err) => {
                     self.handler.emit_err(err);
-                    Err(Statement::dummy(input.span))
+                    None
                 }
             }
         };
 
         // Cast `start` to `I`.
-        let start = match cast_to_number(start) {
-            Ok(v) => v,
-            Err(s) => return s,
+        let Some(start) = cast_to_number(start) else {
+            return Statement::dummy(input.span);
         };
         // Cast `stop` to `I`.
-        let stop = match cast_to_number(stop) {
-            Ok(v) => v,
-            Err(s) => return s,
+        let Some(stop) = cast_to_number(stop) else {
+            return Statement::dummy(input.span);
         };
 
         // Get the index of the current scope.
@@ -184,6 +313,10 @@ impl<'a> Unroller<'a> {
             ),
         };
 
+        // Record the value of the loop variable, so that it can be substituted into the loop body.
+        let num_constants = self.constants.len();
+        self.constants.insert(input.variable.name, value.clone());
+
         // The first statement in the block is the assignment of the loop variable to the current iteration count.
         let mut statements = vec![
             self.reconstruct_definition(DefinitionStatement {
@@ -206,6 +339,9 @@ impl<'a> Unroller<'a> {
             span: input.block.span,
         });
 
+        // Remove the loop variable and the constants declared in the loop body.
+        self.constants.truncate(num_constants);
+
         self.is_unrolling = prior_is_unrolling;
 
         // Exit the scope.
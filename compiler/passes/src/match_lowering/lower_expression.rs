@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MatchLowerer;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for MatchLowerer<'_> {
+    type AdditionalOutput = ();
+
+    /// Lowers a `match` expression to a balanced tree of ternary expressions.
+    /// The variables bound by the patterns are defined before the current statement,
+    /// since every arm of a ternary expression is evaluated.
+    fn reconstruct_match_expression(&mut self, input: MatchExpression) -> (Expression, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(*input.expression).0;
+        let value = self.store_value(value, input.arms.iter().map(|arm| &arm.pattern), input.span);
+
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                let bindings = self.bindings(&value, &arm.pattern);
+                self.definitions.extend(bindings);
+                (arm.pattern, self.reconstruct_expression(arm.expression).0)
+            })
+            .collect();
+
+        let span = input.span;
+        let tree = self.select(&value, arms, |condition, if_true, if_false| {
+            Expression::Ternary(TernaryExpression {
+                condition: Box::new(condition),
+                if_true: Box::new(if_true),
+                if_false: Box::new(if_false),
+                span,
+            })
+        });
+
+        (tree, Default::default())
+    }
+
+    /// Lowers the `match` expressions in the member initializers.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: member
+                            .expression
+                            .map(|expression| self.reconstruct_expression(expression).0),
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+}
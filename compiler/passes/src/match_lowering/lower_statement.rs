@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MatchLowerer;
+
+use leo_ast::*;
+
+impl StatementReconstructor for MatchLowerer<'_> {
+    /// Reconstructs the statements of the block, each preceded by the definitions it requires.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        // Note that the definitions required by the statement containing the block are set aside.
+        let outer_definitions = core::mem::take(&mut self.definitions);
+
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.definitions);
+            statements.push(statement);
+        }
+
+        self.definitions = outer_definitions;
+
+        (
+            Block {
+                statements,
+                span: input.span,
+            },
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_decrement(&mut self, input: DecrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Decrement(DecrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_increment(&mut self, input: IncrementStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Increment(IncrementStatement {
+                mapping: input.mapping,
+                index: self.reconstruct_expression(input.index).0,
+                amount: self.reconstruct_expression(input.amount).0,
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Lowers a `match` statement to a balanced tree of conditional statements.
+    /// The variables bound by the pattern of an arm are defined at the start of its block.
+    fn reconstruct_match(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.expression).0;
+        let value = self.store_value(value, input.arms.iter().map(|arm| &arm.pattern), input.span);
+
+        let arms = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                let mut block = self.reconstruct_block(arm.block).0;
+                block.statements.splice(0..0, self.bindings(&value, &arm.pattern));
+                (arm.pattern, block)
+            })
+            .collect();
+
+        let span = input.span;
+        let tree = self.select(&value, arms, |condition, then, otherwise| Block {
+            statements: vec![Statement::Conditional(ConditionalStatement {
+                condition,
+                then,
+                otherwise: Some(Box::new(Statement::Block(otherwise))),
+                span,
+            })],
+            span,
+        });
+
+        (Statement::Block(tree), Default::default())
+    }
+}
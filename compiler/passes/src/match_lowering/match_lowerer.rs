@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, SymbolTable};
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use std::cell::RefCell;
+
+/// A reconstructor that lowers `match` statements and expressions to conditional statements and ternary expressions.
+pub struct MatchLowerer<'a> {
+    /// The symbol table, which contains the enums of the program.
+    pub(crate) symbol_table: &'a SymbolTable,
+    /// An assigner used to name the values being matched.
+    pub(crate) assigner: Assigner,
+    /// The definitions that precede the statement being reconstructed,
+    /// i.e. of the values being matched, and of the variables bound by the arms of `match` expressions.
+    pub(crate) definitions: Vec<Statement>,
+}
+
+impl<'a> MatchLowerer<'a> {
+    /// Initializes a new `MatchLowerer`, given the symbol table of the program.
+    pub(crate) fn new(symbol_table: &'a SymbolTable) -> Self {
+        Self {
+            symbol_table,
+            assigner: Assigner::default(),
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Stores the value being matched in a new variable, unless it is a variable or a literal, and returns the value.
+    /// The definition of the variable precedes the current statement.
+    /// Note that if every pattern is a wildcard, then the value is never compared, so it is not stored.
+    pub(crate) fn store_value<'b>(
+        &mut self,
+        value: Expression,
+        mut patterns: impl Iterator<Item = &'b MatchPattern>,
+        span: Span,
+    ) -> Expression {
+        let type_ = patterns.find_map(|pattern| match pattern {
+            MatchPattern::Literal(Literal::Boolean(..)) => Some(Type::Boolean),
+            MatchPattern::Literal(Literal::Integer(integer_type, ..)) => Some(Type::Integer(*integer_type)),
+            MatchPattern::Variant(pattern) => Some(Type::Identifier(pattern.enum_name)),
+            _ => None,
+        });
+
+        match (value, type_) {
+            (value @ (Expression::Identifier(_) | Expression::Literal(_)), _) | (value, None) => value,
+            (value, Some(type_)) => {
+                let identifier = Identifier {
+                    name: self.assigner.unique_symbol("$match", "$"),
+                    span: Default::default(),
+                };
+                self.definitions.push(Statement::Definition(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: Expression::Identifier(identifier),
+                    type_,
+                    value,
+                    span,
+                }));
+                Expression::Identifier(identifier)
+            }
+        }
+    }
+
+    /// Returns the definitions of the variables that a pattern binds to the values carried by a variant of an enum.
+    pub(crate) fn bindings(&self, value: &Expression, pattern: &MatchPattern) -> Vec<Statement> {
+        match pattern {
+            MatchPattern::Variant(pattern) => {
+                let (_, variant) = self.variant(pattern);
+                pattern
+                    .bindings
+                    .iter()
+                    .zip(variant.payload.iter())
+                    .enumerate()
+                    .map(|(i, (binding, type_))| {
+                        Statement::Definition(DefinitionStatement {
+                            declaration_type: DeclarationType::Let,
+                            place: Expression::Identifier(*binding),
+                            type_: type_.clone(),
+                            value: Self::member(value.clone(), Enum::payload_member(variant.name(), i), binding.span),
+                            span: binding.span,
+                        })
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Lowers the arms of a `match` to a balanced selection tree, whose nodes are built by `branch(condition, then, otherwise)`.
+    /// Note that type checking guarantees that the patterns are distinct and exhaustive, and that a wildcard is the last pattern.
+    pub(crate) fn select<T: Clone>(
+        &self,
+        value: &Expression,
+        arms: Vec<(MatchPattern, T)>,
+        branch: impl Fn(Expression, T, T) -> T,
+    ) -> T {
+        // The tag of an enum value is compared, instead of the value itself.
+        let subject = match arms
+            .iter()
+            .any(|(pattern, _)| matches!(pattern, MatchPattern::Variant(_)))
+        {
+            true => Self::member(value.clone(), Enum::tag_member(), value.span()),
+            false => value.clone(),
+        };
+
+        // The largest ordinal of the subject, i.e. the tag of the last variant of an enum, or the maximum of its type.
+        let mut max = 0;
+        let mut keyed = Vec::with_capacity(arms.len());
+        let mut default = None;
+        for (pattern, arm) in arms {
+            match pattern {
+                MatchPattern::Literal(literal) => {
+                    max = Self::max_ordinal(&literal);
+                    keyed.push((literal, arm));
+                }
+                MatchPattern::Variant(pattern) => {
+                    max = self.lookup_enum(&pattern).variants.len() as u128 - 1;
+                    keyed.push((Enum::tag(self.variant(&pattern).0, pattern.span), arm));
+                }
+                MatchPattern::Wildcard(_) => default = Some(arm),
+            }
+        }
+        keyed.sort_by_key(|(key, _)| Self::ordinal(key));
+
+        if keyed.is_empty() {
+            return default.expect("Type checking guarantees that a `match` has at least one arm.");
+        }
+
+        // In the tree, an arm is reached by the values greater than the key of the previous arm, up to its own key,
+        // or up to the largest value for the last arm. An arm is exact if only its key reaches it.
+        // Otherwise, it compares the subject with its key, and the other values select the wildcard arm,
+        // so that no value is compared with the same key twice.
+        let last = keyed.len() - 1;
+        let mut lowest = 0;
+        let arms = keyed
+            .into_iter()
+            .enumerate()
+            .map(|(i, (key, arm))| {
+                let ordinal = Self::ordinal(&key);
+                let exact = lowest == ordinal && (i < last || ordinal == max);
+                lowest = ordinal.wrapping_add(1);
+                let arm = match (exact, &default) {
+                    (true, _) => arm,
+                    (false, Some(default)) => branch(
+                        Self::condition(&subject, BinaryOperation::Eq, &key),
+                        arm,
+                        default.clone(),
+                    ),
+                    (false, None) => unreachable!("Type checking guarantees that the patterns are exhaustive."),
+                };
+                (key, arm, exact)
+            })
+            .collect();
+
+        Self::tree(&subject, arms, &branch)
+    }
+
+    /// Returns a balanced tree over the arms, which are sorted by their keys.
+    /// Each node compares the subject with the largest key in its left half,
+    /// or checks that it is equal to the key if the left half is a single exact arm.
+    fn tree<T>(subject: &Expression, mut arms: Vec<(Literal, T, bool)>, branch: &impl Fn(Expression, T, T) -> T) -> T {
+        if arms.len() == 1 {
+            return arms.pop().unwrap().1;
+        }
+
+        let right = arms.split_off((arms.len() + 1) / 2);
+        // Note that booleans cannot be ordered, but the arms of a match over booleans are either exact or a single arm.
+        let condition = match &arms[..] {
+            [(key, _, true)] => Self::condition(subject, BinaryOperation::Eq, key),
+            [.., (key, _, _)] => Self::condition(subject, BinaryOperation::Lte, key),
+            [] => unreachable!("The left half of a tree with several arms is not empty."),
+        };
+
+        branch(
+            condition,
+            Self::tree(subject, arms, branch),
+            Self::tree(subject, right, branch),
+        )
+    }
+
+    /// Returns the comparison `subject op key`.
+    fn condition(subject: &Expression, op: BinaryOperation, key: &Literal) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(subject.clone()),
+            right: Box::new(Expression::Literal(key.clone())),
+            op,
+            span: key.span(),
+            operand_type: RefCell::new(Some(key.type_())),
+        })
+    }
+
+    /// Returns the position of a boolean or integer literal among the values of its type, from `false` or the minimum of the type.
+    fn ordinal(literal: &Literal) -> u128 {
+        match literal {
+            Literal::Boolean(value, _) => u128::from(*value),
+            Literal::Integer(integer_type, value, _) if integer_type.is_signed() => {
+                // The offset from the minimum, i.e. the value with its sign bit flipped.
+                let bits = integer_type.size_in_bits();
+                (value.parse::<i128>().unwrap() as u128).wrapping_add(1 << (bits - 1)) & (u128::MAX >> (128 - bits))
+            }
+            Literal::Integer(_, value, _) => value.parse::<u128>().unwrap(),
+            _ => unreachable!("Type checking guarantees that the patterns are booleans or integers of the same type."),
+        }
+    }
+
+    /// Returns the largest ordinal of the type of a boolean or integer literal.
+    fn max_ordinal(literal: &Literal) -> u128 {
+        match literal {
+            Literal::Boolean(..) => 1,
+            Literal::Integer(integer_type, ..) => u128::MAX >> (128 - integer_type.size_in_bits()),
+            _ => unreachable!("Type checking guarantees that the patterns are booleans or integers of the same type."),
+        }
+    }
+
+    /// Returns the enum of the variant matched by the pattern.
+    fn lookup_enum(&self, pattern: &VariantPattern) -> &Enum {
+        self.symbol_table
+            .lookup_enum(pattern.enum_name.name)
+            .expect("Type checking guarantees that the enum exists.")
+    }
+
+    /// Returns the index and the definition of the variant matched by the pattern.
+    fn variant(&self, pattern: &VariantPattern) -> (usize, &EnumVariant) {
+        self.lookup_enum(pattern)
+            .variant(pattern.variant.name)
+            .expect("Type checking guarantees that the variant exists.")
+    }
+
+    /// Returns the access `value.name` to a member of the struct that an enum value is lowered to.
+    fn member(value: Expression, name: Symbol, span: Span) -> Expression {
+        Expression::Access(AccessExpression::Member(MemberAccess {
+            inner: Box::new(value),
+            name: Identifier { name, span },
+            span,
+        }))
+    }
+}
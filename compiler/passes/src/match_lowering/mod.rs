@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The match lowering pass replaces `match` statements and expressions with conditional statements and ternary expressions.
+//! It runs after loop unrolling, which relies on the arms of a `match` statement being scopes, and before static single assignment.
+//!
+//! The arms are sorted by their patterns and lowered to a balanced selection tree, in which each node compares the value
+//! with the largest pattern in its left half, so that the depth of the tree is logarithmic in the number of arms,
+//! instead of linear as for a chain of `if`/`else` statements or ternary expressions.
+//! If there is a wildcard arm, then each arm that other values may reach compares the value with its pattern,
+//! and selects the wildcard arm otherwise, so that the value is never compared with the same pattern twice.
+//! A `match` over an enum compares the tag of the lowered value, and defines the variables bound by the patterns
+//! as the members of the lowered value.
+//! The value is stored in a new variable, unless it is a variable or a literal, so that it is evaluated once.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! let fee: u64 = match tier {
+//!     0u8 => 100u64,
+//!     1u8 => 50u64,
+//!     2u8 => 25u64,
+//!     _ => 0u64,
+//! };
+//! ```
+//!
+//! The match lowering pass produces the following code.
+//! ```leo
+//! let fee: u64 = tier <= 1u8
+//!     ? (tier == 0u8 ? 100u64 : 50u64)
+//!     : (tier == 2u8 ? 25u64 : 0u64);
+//! ```
+
+mod lower_expression;
+
+mod lower_program;
+
+mod lower_statement;
+
+pub mod match_lowerer;
+pub use match_lowerer::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for MatchLowerer<'a> {
+    const NAME: &'static str = "lower_matches";
+
+    type Input = (Ast, &'a SymbolTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, symbol_table): Self::Input) -> Self::Output {
+        let mut reconstructor = MatchLowerer::new(symbol_table);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
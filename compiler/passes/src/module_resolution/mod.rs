@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The module resolution pass loads the modules declared by the main program after parsing.
+//! A declaration `mod utils;` names the file `utils.leo` next to the main file, which contains constant, struct,
+//! and inline function definitions. The items of a module are referred to by paths, e.g. `utils::Point`,
+//! while the items of the module refer to each other by their names.
+//!
+//! The pass merges the items of every module into the program scope of the main program, so that later passes see
+//! a single program scope. Each item is renamed to `{module}__{name}`, and the paths to it are replaced by that name.
+//!
+//! Consider the following Leo code, where `utils.leo` declares the struct `Point` and the inline function `origin`.
+//! ```leo
+//! mod utils;
+//!
+//! program foo.aleo {
+//!     transition main(a: u32) -> utils::Point {
+//!         let p: utils::Point = utils::origin();
+//!         return utils::Point { x: a, y: p.y };
+//!     }
+//! }
+//! ```
+//!
+//! After module resolution, the program scope declares `utils__Point` and `utils__origin`.
+//! ```leo
+//! program foo.aleo {
+//!     struct utils__Point { x: u32, y: u32 }
+//!
+//!     inline utils__origin() -> utils__Point {
+//!         return utils__Point { x: 0u32, y: 0u32 };
+//!     }
+//!
+//!     transition main(a: u32) -> utils__Point {
+//!         let p: utils__Point = utils__origin();
+//!         return utils__Point { x: a, y: p.y };
+//!     }
+//! }
+//! ```
+
+mod rename_expression;
+
+mod rename_program;
+
+mod rename_statement;
+
+pub mod module_renamer;
+pub use module_renamer::*;
+
+pub mod module_resolver;
+pub use module_resolver::*;
+
+use crate::Pass;
+
+use leo_ast::Ast;
+use leo_errors::{emitter::Handler, Result};
+
+use std::path::Path;
+
+impl<'a> Pass for ModuleResolver<'a> {
+    const NAME: &'static str = "resolve_modules";
+
+    type Input = (Ast, &'a Handler, &'a Path);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, directory): Self::Input) -> Self::Output {
+        // Note that the parser may have recovered from syntax errors, so only the errors found here stop the pass.
+        let err_count = handler.err_count();
+        let program = ModuleResolver::new(handler, directory).resolve(ast.into_repr())?;
+        if handler.err_count() > err_count {
+            handler.last_err().map_err(|e| *e)?;
+        }
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{ArrayType, Identifier, MappingType, Tuple, Type};
+use leo_errors::{emitter::Handler, CompilerError};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A reconstructor that renames the items of modules, and replaces the paths to them with their new names.
+pub struct ModuleRenamer<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The names of the items declared by each module.
+    modules: &'a IndexMap<Symbol, IndexSet<Symbol>>,
+    /// The module whose items are being reconstructed, if any, in which they are referred to without a path.
+    pub(crate) module: Option<Symbol>,
+}
+
+impl<'a> ModuleRenamer<'a> {
+    /// Initializes a new `ModuleRenamer`, given the names of the items declared by each module.
+    pub fn new(handler: &'a Handler, modules: &'a IndexMap<Symbol, IndexSet<Symbol>>) -> Self {
+        Self {
+            handler,
+            modules,
+            module: None,
+        }
+    }
+
+    /// Returns the name of the item `name` of `module` in the program scope.
+    pub fn mangle(module: Symbol, name: Symbol) -> Symbol {
+        Symbol::intern(&format!("{module}__{name}"))
+    }
+
+    /// Returns `true` if `name` is the name of a module.
+    pub(crate) fn is_module(&self, name: Symbol) -> bool {
+        self.modules.contains_key(&name)
+    }
+
+    /// Renames an identifier that refers to an item of a module, either by a path, e.g. `utils::Point`,
+    /// or by its name within the module that declares it.
+    pub(crate) fn rename(&self, identifier: Identifier) -> Identifier {
+        let name = identifier.name.to_string();
+        match (name.split_once("::"), self.module) {
+            (Some((module, item)), _) => {
+                let span = identifier.span;
+                let module = Identifier {
+                    name: Symbol::intern(module),
+                    span,
+                };
+                let item = Identifier {
+                    name: Symbol::intern(item),
+                    span,
+                };
+                self.rename_path(module, item)
+            }
+            (None, Some(module)) if self.modules[&module].contains(&identifier.name) => Identifier {
+                name: Self::mangle(module, identifier.name),
+                span: identifier.span,
+            },
+            (None, _) => identifier,
+        }
+    }
+
+    /// Renames the path `module::item`, reporting an error if the module does not declare the item.
+    pub(crate) fn rename_path(&self, module: Identifier, item: Identifier) -> Identifier {
+        let span = module.span + item.span;
+        match self.modules.get(&module.name) {
+            Some(items) if items.contains(&item.name) => Identifier {
+                name: Self::mangle(module.name, item.name),
+                span,
+            },
+            Some(_) => {
+                self.handler
+                    .emit_err(CompilerError::unknown_module_item(module, item, span));
+                Identifier {
+                    name: Symbol::intern(&format!("{module}::{item}")),
+                    span,
+                }
+            }
+            None => {
+                self.handler.emit_err(CompilerError::unknown_module(module, span));
+                Identifier {
+                    name: Symbol::intern(&format!("{module}::{item}")),
+                    span,
+                }
+            }
+        }
+    }
+
+    /// Renames the structs that occur in a type.
+    pub(crate) fn rename_type(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Array(array) => Type::Array(ArrayType {
+                element_type: Box::new(self.rename_type(*array.element_type)),
+                length: array.length,
+            }),
+            Type::Identifier(identifier) => Type::Identifier(self.rename(identifier)),
+            Type::Mapping(mapping) => Type::Mapping(MappingType {
+                key: Box::new(self.rename_type(*mapping.key)),
+                value: Box::new(self.rename_type(*mapping.value)),
+            }),
+            Type::Tuple(tuple) => Type::Tuple(Tuple(
+                tuple.0.into_iter().map(|type_| self.rename_type(type_)).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+}
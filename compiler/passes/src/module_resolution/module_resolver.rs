@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ModuleRenamer;
+
+use leo_ast::{Module, Program, ProgramReconstructor};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::path::Path;
+
+/// A compiler pass that loads the modules declared by the main program, and merges their items into its program scope.
+pub struct ModuleResolver<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The directory containing the modules.
+    directory: &'a Path,
+}
+
+impl<'a> ModuleResolver<'a> {
+    /// Returns a new module resolver, which loads the modules from `directory`.
+    pub fn new(handler: &'a Handler, directory: &'a Path) -> Self {
+        Self { handler, directory }
+    }
+
+    /// Returns `program`, with the items of the modules it declares in its program scope, preceding its own items.
+    pub fn resolve(&self, mut program: Program) -> Result<Program> {
+        if program.modules.is_empty() {
+            return Ok(program);
+        }
+
+        let modules = std::mem::take(&mut program.modules)
+            .into_iter()
+            .map(|(name, (_, span))| Ok((name, self.load(name, span)?)))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let items = modules
+            .iter()
+            .map(|(name, module)| {
+                let consts = module.consts.keys();
+                let structs = module.structs.keys();
+                let functions = module.functions.keys();
+                (
+                    *name,
+                    consts.chain(structs).chain(functions).copied().collect::<IndexSet<_>>(),
+                )
+            })
+            .collect();
+
+        // Rename the items of each module, so that they do not clash with the items of the program or other modules.
+        let mut renamer = ModuleRenamer::new(self.handler, &items);
+        let mut merged = Module::default();
+        for (name, module) in modules {
+            renamer.module = Some(name);
+            for const_ in module
+                .consts
+                .into_values()
+                .map(|const_| renamer.reconstruct_const(const_))
+            {
+                merged.consts.insert(const_.identifier.name, const_);
+            }
+            for struct_ in module
+                .structs
+                .into_values()
+                .map(|struct_| renamer.reconstruct_struct(struct_))
+            {
+                merged.structs.insert(struct_.identifier.name, struct_);
+            }
+            for function in module
+                .functions
+                .into_values()
+                .map(|function| renamer.reconstruct_function(function))
+            {
+                merged.functions.insert(function.identifier.name, function);
+            }
+        }
+
+        // Replace the paths to the items of the modules in the program.
+        renamer.module = None;
+        let mut program = renamer.reconstruct_program(program);
+
+        // Note that parsing enforces that there is exactly one program scope in a file.
+        if let Some(scope) = program.program_scopes.values_mut().next() {
+            scope.consts = merged
+                .consts
+                .into_iter()
+                .chain(std::mem::take(&mut scope.consts))
+                .collect();
+            scope.structs = merged
+                .structs
+                .into_iter()
+                .chain(std::mem::take(&mut scope.structs))
+                .collect();
+            scope.functions = merged
+                .functions
+                .into_iter()
+                .chain(std::mem::take(&mut scope.functions))
+                .collect();
+        }
+
+        Ok(program)
+    }
+
+    /// Parses the module `name` from the file `name.leo` in the module directory.
+    fn load(&self, name: Symbol, span: Span) -> Result<Module> {
+        let path = self.directory.join(format!("{name}.leo"));
+        if !path.exists() {
+            return Err(CompilerError::module_not_found(path.display(), span).into());
+        }
+
+        let source_file = with_session_globals(|s| s.source_map.load_file(&path))
+            .map_err(|e| CompilerError::file_read_error(&path, e))?;
+
+        leo_parser::parse_module(self.handler, &source_file.src, source_file.start_pos)
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ModuleRenamer;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for ModuleRenamer<'_> {
+    type AdditionalOutput = ();
+
+    /// Replaces calls to the functions of modules, e.g. `utils::origin()`, and the constants of modules, e.g. `utils::SCALE`,
+    /// with their names in the program scope.
+    fn reconstruct_access(&mut self, input: AccessExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            match input {
+                AccessExpression::AssociatedFunction(AssociatedFunction {
+                    ty: Type::Identifier(module),
+                    name,
+                    args,
+                    span,
+                }) if self.is_module(module.name) => Expression::Call(CallExpression {
+                    function: Box::new(Expression::Identifier(self.rename_path(module, name))),
+                    arguments: args.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                    external: None,
+                    span,
+                }),
+                AccessExpression::AssociatedConstant(AssociatedConstant {
+                    ty: Type::Identifier(module),
+                    name,
+                    span,
+                }) if self.is_module(module.name) => Expression::Identifier(Identifier {
+                    span,
+                    ..self.rename_path(module, name)
+                }),
+                AccessExpression::Array(array) => Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.reconstruct_expression(*array.array).0),
+                    index: Box::new(self.reconstruct_expression(*array.index).0),
+                    span: array.span,
+                })),
+                AccessExpression::AssociatedFunction(function) => {
+                    Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                        ty: function.ty,
+                        name: function.name,
+                        args: function
+                            .args
+                            .into_iter()
+                            .map(|arg| self.reconstruct_expression(arg).0)
+                            .collect(),
+                        span: function.span,
+                    }))
+                }
+                AccessExpression::Member(member) => Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.reconstruct_expression(*member.inner).0),
+                    name: member.name,
+                    span: member.span,
+                })),
+                AccessExpression::Tuple(tuple) => Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: Box::new(self.reconstruct_expression(*tuple.tuple).0),
+                    index: tuple.index,
+                    span: tuple.span,
+                })),
+                expr => Expression::Access(expr),
+            },
+            Default::default(),
+        )
+    }
+
+    /// Renames the items of the module being reconstructed.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Identifier(self.rename(input)), Default::default())
+    }
+
+    /// Renames the struct, and the items of the module being reconstructed in the member initializers.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: self.rename(input.name),
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| {
+                        // A member without an initializer refers to the variable, or constant, with the same name.
+                        let expression = match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            None => Some(self.rename(member.identifier))
+                                .filter(|identifier| identifier.name != member.identifier.name)
+                                .map(Expression::Identifier),
+                        };
+                        StructVariableInitializer {
+                            identifier: member.identifier,
+                            expression,
+                        }
+                    })
+                    .collect(),
+                span: input.span,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ModuleRenamer;
+
+use leo_ast::*;
+
+impl ProgramReconstructor for ModuleRenamer<'_> {
+    /// Renames the function, if it is declared by a module, and the structs in its signature.
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: self.rename(input.identifier),
+            input: self.rename_inputs(input.input),
+            output: self.rename_outputs(input.output),
+            output_type: self.rename_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: self.rename_inputs(finalize.input),
+                output: self.rename_outputs(finalize.output),
+                output_type: self.rename_type(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+            }),
+            span: input.span,
+        }
+    }
+
+    /// Renames the constant, if it is declared by a module, and the structs in its type.
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> ConstDeclaration {
+        ConstDeclaration {
+            identifier: self.rename(input.identifier),
+            type_: self.rename_type(input.type_),
+            value: self.reconstruct_expression(input.value).0,
+            span: input.span,
+        }
+    }
+
+    /// Renames the struct, if it is declared by a module, and the structs in the types of its members.
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            identifier: self.rename(input.identifier),
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member {
+                    type_: self.rename_type(member.type_),
+                    ..member
+                })
+                .collect(),
+            ..input
+        }
+    }
+
+    /// Renames the structs in the key and value types of the mapping.
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            key_type: self.rename_type(input.key_type),
+            value_type: self.rename_type(input.value_type),
+            ..input
+        }
+    }
+}
+
+impl ModuleRenamer<'_> {
+    /// Renames the structs in the types of the function inputs.
+    fn rename_inputs(&self, inputs: Vec<Input>) -> Vec<Input> {
+        inputs
+            .into_iter()
+            .map(|input| match input {
+                Input::Internal(input) => Input::Internal(FunctionInput {
+                    type_: self.rename_type(input.type_),
+                    ..input
+                }),
+                input => input,
+            })
+            .collect()
+    }
+
+    /// Renames the structs in the types of the function outputs.
+    fn rename_outputs(&self, outputs: Vec<Output>) -> Vec<Output> {
+        outputs
+            .into_iter()
+            .map(|output| match output {
+                Output::Internal(output) => Output::Internal(FunctionOutput {
+                    type_: self.rename_type(output.type_),
+                    ..output
+                }),
+                output => output,
+            })
+            .collect()
+    }
+}
@@ -16,6 +16,9 @@
 
 /// A compiler pass consuming `Self::Input` and returning `Self::Output`.
 pub trait Pass {
+    /// The name of the pass, used to refer to it from the command line.
+    const NAME: &'static str;
+
     type Input;
     type Output;
 
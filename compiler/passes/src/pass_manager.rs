@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Pass;
+
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+/// Runs compiler passes, recording the wall time of each pass and deciding when compilation stops.
+#[derive(Clone, Debug, Default)]
+pub struct PassManager {
+    /// The name of the pass after which compilation stops, if any.
+    stop_after: Option<String>,
+    /// Whether the wall time of each pass is recorded.
+    time_passes: bool,
+    /// The name and wall time of each pass that was run, in the order in which they were run.
+    timings: RefCell<Vec<(&'static str, Duration)>>,
+}
+
+impl PassManager {
+    /// Initializes a new `PassManager`.
+    pub fn new(stop_after: Option<String>, time_passes: bool) -> Self {
+        Self {
+            stop_after,
+            time_passes,
+            timings: Default::default(),
+        }
+    }
+
+    /// Runs the pass `P` on the given input.
+    pub fn run<P: Pass>(&self, input: P::Input) -> P::Output {
+        if !self.time_passes {
+            return P::do_pass(input);
+        }
+
+        let start = Instant::now();
+        let output = P::do_pass(input);
+        self.timings.borrow_mut().push((P::NAME, start.elapsed()));
+
+        output
+    }
+
+    /// Returns `true` if compilation should stop after the pass with the given name.
+    pub fn stops_after(&self, name: &str) -> bool {
+        self.stop_after.as_deref() == Some(name)
+    }
+
+    /// Returns the name and wall time of each pass that was run, in the order in which they were run.
+    /// Note that timings are only recorded if `time_passes` is enabled.
+    pub fn timings(&self) -> Vec<(&'static str, Duration)> {
+        self.timings.borrow().clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Double;
+
+    impl Pass for Double {
+        const NAME: &'static str = "double";
+
+        type Input = u32;
+        type Output = u32;
+
+        fn do_pass(input: Self::Input) -> Self::Output {
+            input * 2
+        }
+    }
+
+    #[test]
+    fn test_timings() {
+        let manager = PassManager::new(None, true);
+        assert_eq!(manager.run::<Double>(2), 4);
+        assert_eq!(manager.run::<Double>(4), 8);
+
+        let names: Vec<_> = manager.timings().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["double", "double"]);
+
+        let manager = PassManager::new(None, false);
+        assert_eq!(manager.run::<Double>(2), 4);
+        assert!(manager.timings().is_empty());
+    }
+
+    #[test]
+    fn test_stops_after() {
+        let manager = PassManager::new(Some("double".to_string()), false);
+        assert!(manager.stops_after(Double::NAME));
+        assert!(!manager.stops_after("ssa"));
+        assert!(!PassManager::default().stops_after(Double::NAME));
+    }
+}
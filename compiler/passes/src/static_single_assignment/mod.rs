@@ -17,6 +17,7 @@
 //! The Static Single Assignment pass traverses the AST and converts it into SSA form.
 //! See https://en.wikipedia.org/wiki/Static_single-assignment_form for more information.
 //! The pass also replaces `DefinitionStatement`s with `AssignmentStatement`s.
+//! The pass also desugars assignments to array elements, struct members, and tuple elements into assignments to variables. For example, `p.x = v;` is rewritten into `p = Point { x: v, y: p.y };`.
 //! The pass also simplifies complex expressions into a sequence of `AssignStatement`s. For example, `(a + b) * c` is rewritten into `$var$1 = a + b; $var$2 = $var$1 * c`.
 //!
 //! Consider the following Leo code.
@@ -65,6 +66,8 @@ use leo_ast::{Ast, ProgramConsumer};
 use leo_errors::Result;
 
 impl<'a> Pass for StaticSingleAssigner<'a> {
+    const NAME: &'static str = "ssa";
+
     type Input = (Ast, &'a SymbolTable);
     type Output = Result<(Ast, Assigner)>;
 
@@ -17,9 +17,10 @@
 use crate::StaticSingleAssigner;
 
 use leo_ast::{
-    AccessExpression, AssociatedFunction, BinaryExpression, CallExpression, Expression, ExpressionConsumer, Identifier,
-    Literal, MemberAccess, Statement, Struct, StructExpression, StructVariableInitializer, TernaryExpression,
-    TupleAccess, TupleExpression, UnaryExpression, UnitExpression,
+    AccessExpression, ArrayAccess, ArrayExpression, AssociatedFunction, BinaryExpression, CallExpression,
+    CastExpression, Expression, ExpressionConsumer, Identifier, Literal, MemberAccess, Statement, Struct,
+    StructExpression, StructVariableInitializer, TernaryExpression, TupleAccess, TupleExpression, UnaryExpression,
+    UnitExpression,
 };
 use leo_span::{sym, Symbol};
 
@@ -71,6 +72,18 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
                     statements,
                 )
             }
+            AccessExpression::Array(array) => {
+                // Note that loop unrolling guarantees that the index is a literal, so it does not need to be consumed.
+                let (expr, statements) = self.consume_expression(*array.array);
+                (
+                    AccessExpression::Array(ArrayAccess {
+                        array: Box::new(expr),
+                        index: array.index,
+                        span: array.span,
+                    }),
+                    statements,
+                )
+            }
             AccessExpression::Tuple(tuple) => {
                 let (expr, statements) = self.consume_expression(*tuple.tuple);
                 (
@@ -90,6 +103,34 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         (Expression::Identifier(place), statements)
     }
 
+    /// Consumes an array expression, accumulating any statements that are generated.
+    fn consume_array(&mut self, input: ArrayExpression) -> Self::Output {
+        let mut statements = Vec::new();
+
+        // Process the elements, accumulating any statements produced.
+        let elements = input
+            .elements
+            .into_iter()
+            .map(|element| {
+                let (element, mut stmts) = self.consume_expression(element);
+                statements.append(&mut stmts);
+                element
+            })
+            .collect();
+
+        // Construct and accumulate a new assignment statement for the array expression.
+        let (place, statement) = self
+            .assigner
+            .unique_simple_assign_statement(Expression::Array(ArrayExpression {
+                elements,
+                type_: input.type_,
+                span: input.span,
+            }));
+        statements.push(statement);
+
+        (Expression::Identifier(place), statements)
+    }
+
     /// Consumes a binary expression, accumulating any statements that are generated.
     fn consume_binary(&mut self, input: BinaryExpression) -> Self::Output {
         // Reconstruct the lhs of the binary expression.
@@ -107,6 +148,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
                 right: Box::new(right_expression),
                 op: input.op,
                 span: input.span,
+                operand_type: input.operand_type,
             }));
         statements.push(statement);
 
@@ -144,6 +186,30 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         (Expression::Identifier(place), statements)
     }
 
+    /// Consumes a cast expression, accumulating any statements that are generated.
+    fn consume_cast(&mut self, input: CastExpression) -> Self::Output {
+        if input.lossy.get() {
+            return self.consume_lossy_cast(input);
+        }
+
+        // Reconstruct the expression being casted.
+        let (expression, mut statements) = self.consume_expression(*input.expression);
+
+        // Construct and accumulate a unique assignment statement storing the result of the cast expression.
+        let (place, statement) = self
+            .assigner
+            .unique_simple_assign_statement(Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_: input.type_,
+                expression_type: input.expression_type,
+                lossy: input.lossy,
+                span: input.span,
+            }));
+        statements.push(statement);
+
+        (Expression::Identifier(place), statements)
+    }
+
     /// Consumes a struct initialization expression with renamed variables, accumulating any statements that are generated.
     fn consume_struct_init(&mut self, input: StructExpression) -> Self::Output {
         let mut statements = Vec::new();
@@ -316,6 +382,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
             .unique_simple_assign_statement(Expression::Unary(UnaryExpression {
                 op: input.op,
                 receiver: Box::new(receiver),
+                operand_type: input.operand_type,
                 span: input.span,
             }));
         statements.push(statement);
@@ -66,9 +66,12 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
 
         // There is no need to reconstruct `function.inputs`.
         // However, for each input, we must add each symbol to the rename table.
+        self.variable_types.clear();
         for input_variable in function.input.iter() {
             self.rename_table
                 .update(input_variable.identifier().name, input_variable.identifier().name);
+            self.variable_types
+                .insert(input_variable.identifier().name, input_variable.type_());
         }
 
         let block = Block {
@@ -85,9 +88,12 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
 
             // There is no need to reconstruct `finalize.inputs`.
             // However, for each input, we must add each symbol to the rename table.
+            self.variable_types.clear();
             for input_variable in finalize.input.iter() {
                 self.rename_table
                     .update(input_variable.identifier().name, input_variable.identifier().name);
+                self.variable_types
+                    .insert(input_variable.identifier().name, input_variable.type_());
             }
 
             let block = Block {
@@ -128,11 +134,13 @@ impl ProgramScopeConsumer for StaticSingleAssigner<'_> {
     fn consume_program_scope(&mut self, input: ProgramScope) -> Self::Output {
         ProgramScope {
             program_id: input.program_id,
+            consts: input.consts,
             structs: input
                 .structs
                 .into_iter()
                 .map(|(i, s)| (i, self.consume_struct(s)))
                 .collect(),
+            enums: input.enums,
             mappings: input.mappings,
             functions: input
                 .functions
@@ -154,6 +162,8 @@ impl ProgramConsumer for StaticSingleAssigner<'_> {
                 .into_iter()
                 .map(|(name, (import, span))| (name, (self.consume_program(import), span)))
                 .collect(),
+            stubs: input.stubs,
+            modules: input.modules,
             program_scopes: input
                 .program_scopes
                 .into_iter()
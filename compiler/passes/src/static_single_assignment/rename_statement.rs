@@ -17,14 +17,15 @@
 use crate::{RenameTable, StaticSingleAssigner};
 
 use leo_ast::{
-    AssertStatement, AssertVariant, AssignStatement, Block, CallExpression, ConditionalStatement, ConsoleStatement,
-    DecrementStatement, DefinitionStatement, Expression, ExpressionConsumer, ExpressionStatement, Identifier,
-    IncrementStatement, IterationStatement, ReturnStatement, Statement, StatementConsumer, TernaryExpression,
-    TupleExpression,
+    AccessExpression, AssertStatement, AssertVariant, AssignStatement, AssociatedFunction, Block, CallExpression,
+    ConditionalStatement, ConsoleStatement, DecrementStatement, DefinitionStatement, Expression, ExpressionConsumer,
+    ExpressionStatement, Identifier, IncrementStatement, IterationStatement, ReturnStatement, Statement,
+    StatementConsumer, TernaryExpression, TupleExpression, Type,
 };
 use leo_span::Symbol;
 
 use indexmap::IndexSet;
+use itertools::Itertools;
 
 impl StatementConsumer for StaticSingleAssigner<'_> {
     type Output = Vec<Statement>;
@@ -68,14 +69,17 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
     }
 
     /// Consume all `AssignStatement`s, renaming as necessary.
+    /// Assignments to array elements, struct members, and tuple elements are first desugared into assignments to the variable.
     fn consume_assign(&mut self, assign: AssignStatement) -> Self::Output {
+        let (place, value) = self.desugar_assignment(assign.place, assign.value);
+
         // First consume the right-hand-side of the assignment.
-        let (value, mut statements) = self.consume_expression(assign.value);
+        let (value, mut statements) = self.consume_expression(value);
 
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
-        let place = match self.consume_expression(assign.place).0 {
+        let place = match self.consume_expression(place).0 {
             Expression::Identifier(identifier) => identifier,
             _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier."),
         };
@@ -223,6 +227,21 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
     /// Consumes the `DefinitionStatement` into an `AssignStatement`, renaming the left-hand-side as appropriate.
     fn consume_definition(&mut self, definition: DefinitionStatement) -> Self::Output {
+        // Record the types of the defined variables, which are needed to desugar assignments to their elements and members.
+        match (&definition.place, &definition.type_) {
+            (Expression::Identifier(identifier), type_) => {
+                self.variable_types.insert(identifier.name, type_.clone());
+            }
+            (Expression::Tuple(tuple), Type::Tuple(tuple_type)) => {
+                for (element, type_) in tuple.elements.iter().zip_eq(tuple_type.iter()) {
+                    if let Expression::Identifier(identifier) = element {
+                        self.variable_types.insert(identifier.name, type_.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+
         // First consume the right-hand-side of the definition.
         let (value, mut statements) = self.consume_expression(definition.value);
 
@@ -273,7 +292,31 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         // Extract the call expression.
         let call = match input.expression {
             Expression::Call(call) => call,
-            _ => unreachable!("Type checking guarantees that expression statements are always function calls."),
+            // Mapping operations are consumed like other associated functions, except that their output is not assigned.
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                let args = function
+                    .args
+                    .into_iter()
+                    .map(|arg| {
+                        let (arg, mut stmts) = self.consume_expression(arg);
+                        statements.append(&mut stmts);
+                        arg
+                    })
+                    .collect();
+                statements.push(Statement::Expression(ExpressionStatement {
+                    expression: Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                        ty: function.ty,
+                        name: function.name,
+                        args,
+                        span: function.span,
+                    })),
+                    span: input.span,
+                }));
+                return statements;
+            }
+            _ => unreachable!(
+                "Type checking guarantees that expression statements are always function calls or mapping operations."
+            ),
         };
 
         // Process the arguments, accumulating any statements produced.
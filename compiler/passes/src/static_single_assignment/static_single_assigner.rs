@@ -16,6 +16,16 @@
 
 use crate::{Assigner, RenameTable, SymbolTable};
 
+use leo_ast::{
+    AccessExpression, ArrayAccess, ArrayExpression, BinaryExpression, BinaryOperation, CastExpression, Expression,
+    ExpressionConsumer, IntegerType, Literal, MemberAccess, PositiveNumber, Statement, StructExpression,
+    StructVariableInitializer, TernaryExpression, TupleAccess, TupleExpression, Type,
+};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use std::cell::RefCell;
+
 pub struct StaticSingleAssigner<'a> {
     /// The `SymbolTable` of the program.
     pub(crate) symbol_table: &'a SymbolTable,
@@ -25,6 +35,9 @@ pub struct StaticSingleAssigner<'a> {
     pub(crate) is_lhs: bool,
     /// A struct used to construct (unique) assignment statements.
     pub(crate) assigner: Assigner,
+    /// The types of the variables in the current function, by their names before renaming.
+    /// Note that a name always refers to its latest definition, since variables cannot be shadowed.
+    pub(crate) variable_types: IndexMap<Symbol, Type>,
 }
 
 impl<'a> StaticSingleAssigner<'a> {
@@ -35,6 +48,7 @@ impl<'a> StaticSingleAssigner<'a> {
             rename_table: RenameTable::new(None),
             is_lhs: false,
             assigner: Assigner::default(),
+            variable_types: IndexMap::new(),
         }
     }
 
@@ -49,4 +63,237 @@ impl<'a> StaticSingleAssigner<'a> {
         let parent = self.rename_table.parent.clone().unwrap_or_default();
         core::mem::replace(&mut self.rename_table, *parent)
     }
+
+    /// Returns the type of the place of an assignment, i.e. a variable or an element or member of one.
+    fn place_type(&self, place: &Expression) -> Type {
+        match place {
+            Expression::Identifier(identifier) => self.variable_types[&identifier.name].clone(),
+            Expression::Access(AccessExpression::Array(access)) => match self.place_type(&access.array) {
+                Type::Array(array_type) => *array_type.element_type,
+                _ => unreachable!("Type checking guarantees that the indexed expression is an array."),
+            },
+            Expression::Access(AccessExpression::Member(access)) => match self.place_type(&access.inner) {
+                Type::Identifier(identifier) => self.symbol_table.structs[&identifier.name]
+                    .members
+                    .iter()
+                    .find(|member| member.name() == access.name.name)
+                    .expect("Type checking guarantees that the member exists.")
+                    .type_
+                    .clone(),
+                _ => unreachable!("Type checking guarantees that the accessed expression is a struct."),
+            },
+            Expression::Access(AccessExpression::Tuple(access)) => match self.place_type(&access.tuple) {
+                Type::Tuple(tuple_type) => tuple_type[access.index.to_usize()].clone(),
+                _ => unreachable!("Type checking guarantees that the accessed expression is a tuple."),
+            },
+            _ => unreachable!("Type checking guarantees that the place of an assignment is a variable or an element or member of one."),
+        }
+    }
+
+    /// Desugars an assignment to an array element, struct member, or tuple element into an assignment to the variable.
+    /// Returns the new place and value of the assignment.
+    /// For example,
+    ///   - `a[1u32] = v;`, where `a` has type `[u8; 3]`, is rewritten as `a = [a[0u32], v, a[2u32]];`.
+    ///   - `p.x = v;`, where `p` has type `Point`, is rewritten as `p = Point { x: v, y: p.y };`.
+    ///   - `t.0 = v;`, where `t` has type `(u8, u8)`, is rewritten as `t = (v, t.1);`.
+    pub(crate) fn desugar_assignment(&self, mut place: Expression, mut value: Expression) -> (Expression, Expression) {
+        loop {
+            (value, place) = match place {
+                Expression::Access(AccessExpression::Array(access)) => {
+                    let array_type = match self.place_type(&access.array) {
+                        Type::Array(array_type) => array_type,
+                        _ => unreachable!("Type checking guarantees that the indexed expression is an array."),
+                    };
+                    let index = match *access.index {
+                        Expression::Literal(Literal::Integer(_, ref index, _)) => index.parse::<usize>().ok(),
+                        _ => None,
+                    }
+                    .expect("Loop unrolling guarantees that array indices are constants in range.");
+
+                    // Construct the new value of the array, with the element at `index` replaced by `value`.
+                    let array = Expression::Array(ArrayExpression {
+                        elements: (0..array_type.length())
+                            .map(|i| match i == index {
+                                true => value.clone(),
+                                false => Expression::Access(AccessExpression::Array(ArrayAccess {
+                                    array: access.array.clone(),
+                                    index: Box::new(Expression::Literal(Literal::Integer(
+                                        IntegerType::U32,
+                                        i.to_string(),
+                                        Default::default(),
+                                    ))),
+                                    span: Default::default(),
+                                })),
+                            })
+                            .collect(),
+                        type_: RefCell::new(Some(array_type)),
+                        span: Default::default(),
+                    });
+                    (array, *access.array)
+                }
+                Expression::Access(AccessExpression::Member(access)) => {
+                    let struct_ = match self.place_type(&access.inner) {
+                        Type::Identifier(identifier) => &self.symbol_table.structs[&identifier.name],
+                        _ => unreachable!("Type checking guarantees that the accessed expression is a struct."),
+                    };
+
+                    // Construct the new value of the struct, with the member `access.name` replaced by `value`.
+                    let struct_expression = Expression::Struct(StructExpression {
+                        name: struct_.identifier,
+                        members: struct_
+                            .members
+                            .iter()
+                            .map(|member| StructVariableInitializer {
+                                identifier: member.identifier,
+                                expression: Some(match member.name() == access.name.name {
+                                    true => value.clone(),
+                                    false => Expression::Access(AccessExpression::Member(MemberAccess {
+                                        inner: access.inner.clone(),
+                                        name: member.identifier,
+                                        span: Default::default(),
+                                    })),
+                                }),
+                            })
+                            .collect(),
+                        span: Default::default(),
+                    });
+                    (struct_expression, *access.inner)
+                }
+                Expression::Access(AccessExpression::Tuple(access)) => {
+                    let length = match self.place_type(&access.tuple) {
+                        Type::Tuple(tuple_type) => tuple_type.len(),
+                        _ => unreachable!("Type checking guarantees that the accessed expression is a tuple."),
+                    };
+                    let index = access.index.to_usize();
+
+                    // Construct the new value of the tuple, with the element at `index` replaced by `value`.
+                    let tuple = Expression::Tuple(TupleExpression {
+                        elements: (0..length)
+                            .map(|i| match i == index {
+                                true => value.clone(),
+                                false => Expression::Access(AccessExpression::Tuple(TupleAccess {
+                                    tuple: access.tuple.clone(),
+                                    index: PositiveNumber { value: i.to_string() },
+                                    span: Default::default(),
+                                })),
+                            })
+                            .collect(),
+                        span: Default::default(),
+                    });
+                    (tuple, *access.tuple)
+                }
+                _ => return (place, value),
+            };
+        }
+    }
+
+    /// Lowers a lossy cast from an integer to an integer, accumulating any statements that are generated.
+    /// snarkVM does not support the `cast.lossy` instruction yet, so the cast is rewritten with checked casts that never halt.
+    /// The two's complement bits of the value are read as a `u128` through a `field`, truncated or sign extended,
+    /// and reinterpreted as the target type. For example, `x as u8`, where `x` has type `i16`, is rewritten as
+    /// `((x as field) as u128 & 255u128) as u8`, and a signed target type adds its minimum value if the sign bit is set.
+    pub(crate) fn consume_lossy_cast(&mut self, input: CastExpression) -> (Expression, Vec<Statement>) {
+        let from = match input.expression_type.take() {
+            Some(Type::Integer(integer_type)) => integer_type,
+            _ => unreachable!("Type checking guarantees that lossy casts are from integers."),
+        };
+        let literal = |value: u128| {
+            Expression::Literal(Literal::Integer(
+                IntegerType::U128,
+                value.to_string(),
+                Default::default(),
+            ))
+        };
+        // A binary operation on the bits of the value, which are read as a `u128`.
+        let binary = |op: BinaryOperation, left: Expression, right: Expression| {
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op,
+                span: Default::default(),
+                operand_type: RefCell::new(Some(Type::Integer(IntegerType::U128))),
+            })
+        };
+        let cast = |expression: Expression, type_: Type| {
+            Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_,
+                expression_type: Default::default(),
+                lossy: Default::default(),
+                span: Default::default(),
+            })
+        };
+        // The mask of the lowest `bits` bits.
+        let mask = |bits: u32| u128::MAX >> (128 - bits);
+
+        // Note that a cast to a field never halts, and the bits of an integer always fit in a `u128`.
+        let bits = cast(cast(*input.expression, Type::Field), Type::Integer(IntegerType::U128));
+        let (bits, mut statements) = self.consume_expression(bits);
+
+        let lowered = match input.type_ {
+            Type::Integer(to) => {
+                let (m, n) = (from.size_in_bits(), to.size_in_bits());
+                // The lowest `n` bits of the value, where negative values are sign extended if the target type is wider.
+                let unsigned = match n > m && from.is_signed() {
+                    true => {
+                        let is_negative = binary(
+                            BinaryOperation::Neq,
+                            binary(BinaryOperation::BitwiseAnd, bits.clone(), literal(1 << (m - 1))),
+                            literal(0),
+                        );
+                        let extension = Expression::Ternary(TernaryExpression {
+                            condition: Box::new(is_negative),
+                            if_true: Box::new(literal(mask(n) - mask(m))),
+                            if_false: Box::new(literal(0)),
+                            span: Default::default(),
+                        });
+                        binary(BinaryOperation::Add, bits, extension)
+                    }
+                    false if n == 128 => bits,
+                    false => binary(BinaryOperation::BitwiseAnd, bits, literal(mask(n))),
+                };
+                match to {
+                    IntegerType::U128 => unsigned,
+                    _ if !to.is_signed() => cast(unsigned, input.type_),
+                    // The lowest `n - 1` bits, plus the minimum value if the sign bit is set.
+                    _ => {
+                        let (unsigned, mut unsigned_statements) = self.consume_expression(unsigned);
+                        statements.append(&mut unsigned_statements);
+                        let is_negative = binary(
+                            BinaryOperation::Neq,
+                            binary(BinaryOperation::BitwiseAnd, unsigned.clone(), literal(1 << (n - 1))),
+                            literal(0),
+                        );
+                        let low = cast(
+                            binary(BinaryOperation::BitwiseAnd, unsigned, literal(mask(n - 1))),
+                            input.type_.clone(),
+                        );
+                        let minimum = Expression::Literal(Literal::Integer(
+                            to,
+                            format!("-{}", 1u128 << (n - 1)),
+                            Default::default(),
+                        ));
+                        let with_minimum = Expression::Binary(BinaryExpression {
+                            left: Box::new(low.clone()),
+                            right: Box::new(minimum),
+                            op: BinaryOperation::Add,
+                            span: Default::default(),
+                            operand_type: RefCell::new(Some(Type::Integer(to))),
+                        });
+                        Expression::Ternary(TernaryExpression {
+                            condition: Box::new(is_negative),
+                            if_true: Box::new(with_minimum),
+                            if_false: Box::new(low),
+                            span: Default::default(),
+                        })
+                    }
+                }
+            }
+            _ => unreachable!("Type checking guarantees that lossy casts are to integers."),
+        };
+
+        let (expression, mut lowered_statements) = self.consume_expression(lowered);
+        statements.append(&mut lowered_statements);
+        (expression, statements)
+    }
 }
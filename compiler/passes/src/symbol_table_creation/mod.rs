@@ -23,14 +23,20 @@ use leo_ast::{Ast, ProgramVisitor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for SymbolTableCreator<'a> {
+    const NAME: &'static str = "symbol_table";
+
     type Input = (&'a Ast, &'a Handler);
     type Output = Result<SymbolTable>;
 
     /// Runs the compiler pass.
     fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        // Note that the parser may have recovered from syntax errors, so only the errors found here stop the pass.
+        let err_count = handler.err_count();
         let mut visitor = SymbolTableCreator::new(handler);
         visitor.visit_program(ast.as_repr());
-        handler.last_err().map_err(|e| *e)?;
+        if handler.err_count() > err_count {
+            handler.last_err().map_err(|e| *e)?;
+        }
 
         Ok(visitor.symbol_table)
     }
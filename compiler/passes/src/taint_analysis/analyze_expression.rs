@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Taint, TaintAnalyzer};
+
+use leo_ast::*;
+use leo_errors::TaintAnalysisWarning;
+use leo_span::{sym, Span};
+
+impl<'a> TaintAnalyzer<'a> {
+    /// Returns the first taint among the given expressions, visiting all of them.
+    fn visit_expressions(&mut self, expressions: impl IntoIterator<Item = &'a Expression>) -> Option<Taint> {
+        expressions
+            .into_iter()
+            .map(|expression| self.visit_expression(expression, &()))
+            .fold(None, |taint, next| taint.or(next))
+    }
+
+    /// Warns if a private value is used as a key or value of a mapping.
+    pub(crate) fn check_mapping_access(&self, mapping: &Identifier, taint: Option<Taint>, span: Span) {
+        if let Some(taint) = taint {
+            self.emit_warning(
+                TaintAnalysisWarning::private_value_in_mapping(&taint.source, mapping, span),
+                &taint,
+            );
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for TaintAnalyzer<'a> {
+    type AdditionalInput = ();
+    type Output = Option<Taint>;
+
+    fn visit_access(&mut self, input: &'a AccessExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => self.visit_expressions([access.array.as_ref(), access.index.as_ref()]),
+            AccessExpression::AssociatedConstant(_) => None,
+            AccessExpression::AssociatedFunction(function) => match (&function.ty, function.name.name) {
+                // Writing a private value to a mapping makes it public.
+                (Type::Identifier(Identifier { name: sym::Mapping, .. }), sym::set) => {
+                    let taint = self.visit_expressions(function.args.iter().skip(1));
+                    if let Expression::Identifier(mapping) = &function.args[0] {
+                        self.check_mapping_access(mapping, taint, function.span);
+                    }
+                    None
+                }
+                // Like `set`, `get_or_init` writes its default value to the mapping if the key is missing.
+                // Note that the value read may be the default, so it carries the taint of the default.
+                (Type::Identifier(Identifier { name: sym::Mapping, .. }), sym::get_or_init) => {
+                    let taint = self.visit_expressions(function.args.iter().skip(1));
+                    if let Expression::Identifier(mapping) = &function.args[0] {
+                        self.check_mapping_access(mapping, taint.clone(), function.span);
+                    }
+                    taint
+                }
+                // A value read from a mapping is public, unless it was selected by a private key.
+                (Type::Identifier(Identifier { name: sym::Mapping, .. }), _) => {
+                    self.visit_expressions(function.args.iter().skip(1))
+                }
+                // A commitment hides the committed value, so it is safe to make public.
+                (_, sym::commit) => {
+                    self.visit_expressions(function.args.iter());
+                    None
+                }
+                // Note that a hash of a private value is not hiding, since the value may be guessed.
+                _ => self.visit_expressions(function.args.iter()),
+            },
+            AccessExpression::Member(access) => match access.inner.as_ref() {
+                // The fields of a record input are private, unless they are declared as public or constant.
+                Expression::Identifier(identifier) if self.record_inputs.contains_key(&identifier.name) => {
+                    let record = self.record_inputs[&identifier.name];
+                    let member = record
+                        .members
+                        .iter()
+                        .find(|member| member.identifier.name == access.name.name);
+                    match member.map(|member| member.mode) {
+                        Some(Mode::Public | Mode::Constant) => None,
+                        _ => self.taint_of(identifier).map(|taint| Taint {
+                            source: format!("{}.{}", identifier, access.name),
+                            ..taint
+                        }),
+                    }
+                }
+                inner => self.visit_expression(inner, &()),
+            },
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple, &()),
+        }
+    }
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expressions(input.elements.iter())
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expressions([input.left.as_ref(), input.right.as_ref()])
+    }
+
+    /// Note that the outputs of a call are assumed to be derived from all of its arguments.
+    fn visit_call(&mut self, input: &'a CallExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expressions(input.arguments.iter())
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, &())
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .members
+            .iter()
+            .map(|member| match &member.expression {
+                Some(expression) => self.visit_expression(expression, &()),
+                None => self.taint_of(&member.identifier),
+            })
+            .fold(None, |taint, next| taint.or(next))
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.taint_of(input)
+    }
+
+    /// Note that a value selected by a private condition is derived from the condition.
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expressions([
+            input.condition.as_ref(),
+            input.if_true.as_ref(),
+            input.if_false.as_ref(),
+        ])
+    }
+
+    fn visit_tuple(&mut self, input: &'a TupleExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expressions(input.elements.iter())
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, &())
+    }
+}
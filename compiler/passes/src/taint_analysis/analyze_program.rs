@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Taint, TaintAnalyzer};
+
+use leo_ast::*;
+
+impl<'a> ProgramVisitor<'a> for TaintAnalyzer<'a> {
+    /// Only transitions have public outputs and finalize blocks, so the other functions are not visited.
+    /// Note that inline functions have already been inlined into their callers.
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.records = input
+            .structs
+            .iter()
+            .filter(|(_, struct_)| struct_.is_record)
+            .map(|(name, struct_)| (*name, struct_))
+            .collect();
+
+        input
+            .functions
+            .values()
+            .filter(|function| matches!(function.variant, Variant::Transition) && !function.is_test())
+            .for_each(|function| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, function: &'a Function) {
+        self.current_function = Some(function);
+        self.taints.clear();
+        self.record_inputs.clear();
+        self.finalize_taints.clear();
+
+        // The inputs of a transition are private, unless they are declared as public or constant.
+        // Records are always private, although their fields may be declared as public.
+        for input in function.input.iter() {
+            let is_private = match input {
+                Input::Internal(input) => match &input.type_ {
+                    Type::Identifier(identifier) if self.records.contains_key(&identifier.name) => {
+                        self.record_inputs
+                            .insert(input.identifier.name, self.records[&identifier.name]);
+                        true
+                    }
+                    _ => matches!(input.mode, Mode::None | Mode::Private),
+                },
+                Input::External(_) => true,
+            };
+            if is_private {
+                let identifier = input.identifier();
+                self.taints.insert(
+                    identifier.name,
+                    Taint {
+                        source: identifier.to_string(),
+                        source_span: input.span(),
+                        path: Vec::new(),
+                    },
+                );
+            }
+        }
+
+        self.visit_block(&function.block);
+
+        // The inputs of the finalize block receive the taints of the arguments passed to it.
+        if let Some(finalize) = &function.finalize {
+            self.taints.clear();
+            self.record_inputs.clear();
+            self.in_finalize = true;
+
+            let taints = std::mem::take(&mut self.finalize_taints);
+            for (input, taint) in finalize.input.iter().zip(taints) {
+                if let Some(taint) = taint {
+                    self.taints.insert(input.identifier().name, taint.through(input.span()));
+                }
+            }
+
+            self.visit_block(&finalize.block);
+
+            self.in_finalize = false;
+        }
+
+        self.current_function = None;
+    }
+
+    /// Imported programs are compiled separately, so they are analyzed on their own.
+    fn visit_import(&mut self, _input: &'a Program) {}
+}
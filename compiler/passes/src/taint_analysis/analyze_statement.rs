@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Taint, TaintAnalyzer};
+
+use leo_ast::*;
+use leo_errors::TaintAnalysisWarning;
+use leo_span::Span;
+
+impl<'a> TaintAnalyzer<'a> {
+    /// Records the taint of the variables in `place`, which are assigned `value` by the statement at `span`.
+    fn assign(&mut self, place: &'a Expression, value: &'a Expression, span: Span) {
+        let taint = self.visit_expression(value, &());
+        // Statements produced by SSA have no span, so point at the assigned value instead.
+        let span = if span.is_dummy() { value.span() } else { span };
+        self.assign_taint(place, taint, span);
+    }
+
+    /// Returns the span to report for a tainted value.
+    /// Values produced by flattening have no span, so the last step of the flow, or else `fallback`, is used instead.
+    fn span_of(value: &Expression, taint: &Taint, fallback: Span) -> Span {
+        match value.span() {
+            span if span.is_dummy() => taint.path.last().copied().unwrap_or(fallback),
+            span => span,
+        }
+    }
+
+    /// Records the taint of the variables in `place`, which are assigned a value with the given taint by the statement at `span`.
+    fn assign_taint(&mut self, place: &'a Expression, taint: Option<Taint>, span: Span) {
+        match place {
+            Expression::Identifier(identifier) => match taint {
+                Some(taint) => {
+                    self.taints.insert(identifier.name, taint.through(span));
+                }
+                None => {
+                    self.taints.remove(&identifier.name);
+                }
+            },
+            // Note that each element of a tuple is assumed to be derived from the whole value.
+            Expression::Tuple(tuple) => tuple
+                .elements
+                .iter()
+                .for_each(|element| self.assign_taint(element, taint.clone(), span)),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for TaintAnalyzer<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.assign(&input.place, &input.value, input.span);
+    }
+
+    fn visit_decrement(&mut self, input: &'a DecrementStatement) {
+        let taint = self
+            .visit_expression(&input.index, &())
+            .or(self.visit_expression(&input.amount, &()));
+        self.check_mapping_access(&input.mapping, taint, input.span);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.assign(&input.place, &input.value, input.span);
+    }
+
+    fn visit_increment(&mut self, input: &'a IncrementStatement) {
+        let taint = self
+            .visit_expression(&input.index, &())
+            .or(self.visit_expression(&input.amount, &()));
+        self.check_mapping_access(&input.mapping, taint, input.span);
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        // Note that this unwrap is safe, since `current_function` is set in `visit_function`.
+        let function = self.current_function.unwrap();
+
+        // Warn if a private value is returned as a public or constant output of the transition.
+        // Note that records are encrypted, so they may hold private values.
+        if !self.in_finalize {
+            let values: Vec<&Expression> = match (&input.expression, function.output.len()) {
+                (Expression::Tuple(tuple), len) if len > 1 => tuple.elements.iter().collect(),
+                (expression, 1) => vec![expression],
+                _ => Vec::new(),
+            };
+            for (value, output) in values.into_iter().zip(function.output.iter()) {
+                let taint = self.visit_expression(value, &());
+                if let (Some(taint), Mode::Public | Mode::Constant) = (taint, output.mode()) {
+                    let span = Self::span_of(value, &taint, output.span());
+                    self.emit_warning(
+                        TaintAnalysisWarning::private_value_in_public_output(&taint.source, span),
+                        &taint,
+                    );
+                }
+            }
+        }
+
+        // Warn if a private value is passed to the finalize block, whose inputs are public.
+        if let Some(arguments) = &input.finalize_arguments {
+            let finalize_inputs = function.finalize.iter().flat_map(|finalize| finalize.input.iter());
+            self.finalize_taints = arguments
+                .iter()
+                .zip(finalize_inputs)
+                .map(|(argument, finalize_input)| {
+                    let taint = self.visit_expression(argument, &())?;
+                    let span = Self::span_of(argument, &taint, finalize_input.span());
+                    self.emit_warning(
+                        TaintAnalysisWarning::private_value_in_finalize_argument(&taint.source, span),
+                        &taint,
+                    );
+                    Some(taint.through(span))
+                })
+                .collect();
+        }
+    }
+}
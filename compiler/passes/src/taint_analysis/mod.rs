@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The taint analysis pass traverses the AST after the dead code elimination pass and warns when private data becomes public.
+//! A value is private if it is derived from a private input of a transition, or from a private field of a record input.
+//! The pass warns when such a value is returned as a public or constant output, passed to the finalize block,
+//! or stored in a mapping, and shows the statements that carried the value from the input as secondary spans.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(secret: u8, public bound: u8) -> public bool {
+//!     let scaled: u8 = secret * 2u8;
+//!     return scaled < bound;
+//! }
+//! ```
+//!
+//! The pass warns that the public output is derived from the private input `secret`, through the definition of `scaled`.
+//! Note that values selected by a private condition are also derived from it, whereas a commitment hides the committed value.
+//! The pass does not modify the AST.
+
+mod analyze_expression;
+
+mod analyze_program;
+
+mod analyze_statement;
+
+pub mod taint_analyzer;
+pub use taint_analyzer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for TaintAnalyzer<'a> {
+    const NAME: &'static str = "taint";
+
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = TaintAnalyzer::new(handler);
+        visitor.visit_program(ast.as_repr());
+
+        Ok(())
+    }
+}
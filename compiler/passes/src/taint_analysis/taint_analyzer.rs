@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Function, Identifier, Struct};
+use leo_errors::{emitter::Handler, TaintAnalysisWarning};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// The private input that a value is derived from, along with the statements that carried it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Taint {
+    /// The private input, e.g. `secret`, or `token.amount` for a private field of a record input.
+    pub source: String,
+    /// The span of the declaration of the private input.
+    pub source_span: Span,
+    /// The spans of the statements that carried the value from the input, in order.
+    pub path: Vec<Span>,
+}
+
+impl Taint {
+    /// Returns the taint of a value computed from this one by the statement or expression at `span`.
+    pub(crate) fn through(mut self, span: Span) -> Self {
+        if !span.is_dummy() && span != self.source_span && !self.path.contains(&span) {
+            self.path.push(span);
+        }
+        self
+    }
+
+    /// Returns the secondary spans of a warning about this taint, omitting the warning's own span.
+    pub(crate) fn labels(&self, primary: Option<Span>) -> Vec<(Span, String)> {
+        std::iter::once((self.source_span, format!("`{}` is private", self.source)))
+            .chain(
+                self.path
+                    .iter()
+                    .filter(|span| Some(**span) != primary)
+                    .map(|span| (*span, "the value flows through here".to_string())),
+            )
+            .collect()
+    }
+}
+
+pub struct TaintAnalyzer<'a> {
+    /// The error handler, which receives the warnings.
+    pub(crate) handler: &'a Handler,
+    /// The records declared in the program, whose fields have their own visibility.
+    pub(crate) records: IndexMap<Symbol, &'a Struct>,
+    /// The record inputs of the current transition, along with their record declarations.
+    pub(crate) record_inputs: IndexMap<Symbol, &'a Struct>,
+    /// The transition being traversed.
+    pub(crate) current_function: Option<&'a Function>,
+    /// The private inputs that each variable in the current function or finalize block is derived from.
+    pub(crate) taints: IndexMap<Symbol, Taint>,
+    /// The taints of the arguments passed to the finalize block of the current transition.
+    pub(crate) finalize_taints: Vec<Option<Taint>>,
+    /// Are we traversing a finalize block?
+    pub(crate) in_finalize: bool,
+}
+
+impl<'a> TaintAnalyzer<'a> {
+    /// Initializes a new `TaintAnalyzer`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self {
+            handler,
+            records: Default::default(),
+            record_inputs: Default::default(),
+            current_function: None,
+            taints: Default::default(),
+            finalize_taints: Default::default(),
+            in_finalize: false,
+        }
+    }
+
+    /// Emits a warning, showing the data flow from the private input as secondary spans.
+    pub(crate) fn emit_warning(&self, warning: TaintAnalysisWarning, taint: &Taint) {
+        let labels = taint.labels(warning.span());
+        self.handler.emit_warning(warning.with_labels(labels).into());
+    }
+
+    /// Returns the taint of the given variable, if it is derived from a private input.
+    pub(crate) fn taint_of(&self, identifier: &Identifier) -> Option<Taint> {
+        self.taints.get(&identifier.name).cloned()
+    }
+}
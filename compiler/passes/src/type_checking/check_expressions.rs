@@ -21,6 +21,7 @@ use leo_errors::emitter::Handler;
 use leo_errors::TypeCheckerError;
 use leo_span::{sym, Span};
 
+use indexmap::IndexSet;
 use std::str::FromStr;
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
@@ -47,7 +48,57 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
     fn visit_access(&mut self, input: &'a AccessExpression, expected: &Self::AdditionalInput) -> Self::Output {
         match input {
+            AccessExpression::Array(access) => {
+                // Check that the index is a `u8`, `u16`, or `u32`.
+                let index_type = self.visit_expression(&access.index, &None);
+                self.assert_magnitude_type(&index_type, access.index.span());
+
+                // Check that the expression being accessed is an array.
+                match self.visit_expression(&access.array, &None) {
+                    Some(Type::Array(array_type)) => {
+                        // If the index is a literal, check that it is in range.
+                        if let Expression::Literal(Literal::Integer(_, index, _)) = &*access.index {
+                            if let (Ok(index), Ok(length)) =
+                                (index.parse::<usize>(), array_type.length.value.parse::<usize>())
+                            {
+                                if index >= length {
+                                    self.emit_err(TypeCheckerError::array_index_out_of_range(
+                                        index,
+                                        length,
+                                        access.index.span(),
+                                    ));
+                                }
+                            }
+                        }
+
+                        // Return the element type of the array.
+                        return Some(self.assert_and_return_type(*array_type.element_type, expected, access.span()));
+                    }
+                    Some(type_) => {
+                        self.emit_err(TypeCheckerError::type_should_be(type_, "array", access.array.span()));
+                    }
+                    None => {
+                        self.emit_err(TypeCheckerError::could_not_determine_type(
+                            &access.array,
+                            access.array.span(),
+                        ));
+                    }
+                }
+            }
             AccessExpression::AssociatedFunction(access) => {
+                // Check mapping operations, e.g. `Mapping::get(balances, key)`.
+                if matches!(access.ty, Type::Identifier(Identifier { name: sym::Mapping, .. })) {
+                    return self.check_mapping_operation(access, expected);
+                }
+
+                // Check the construction of an enum value that carries values, e.g. `Order::Limit(price, amount)`.
+                if let Type::Identifier(identifier) = &access.ty {
+                    let enum_ = self.symbol_table.borrow().lookup_enum(identifier.name).cloned();
+                    if let Some(enum_) = enum_ {
+                        return self.check_enum_construction(&enum_, &access.name, &access.args, expected, access.span);
+                    }
+                }
+
                 // Check core struct name and function.
                 if let Some(core_instruction) = self.check_core_function_call(&access.ty, &access.name) {
                     // Check num input arguments.
@@ -178,17 +229,57 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     }
                 }
             }
-            AccessExpression::AssociatedConstant(..) => {} // todo: Add support for associated constants (u8::MAX).
+            AccessExpression::AssociatedConstant(access) => {
+                // Check the construction of an enum value, e.g. `Status::Open`.
+                if let Type::Identifier(identifier) = &access.ty {
+                    let enum_ = self.symbol_table.borrow().lookup_enum(identifier.name).cloned();
+                    if let Some(enum_) = enum_ {
+                        return self.check_enum_construction(&enum_, &access.name, &[], expected, access.span);
+                    }
+                }
+                // todo: Add support for associated constants (u8::MAX).
+            }
         }
         None
     }
 
+    fn visit_array(&mut self, input: &'a ArrayExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        // If the expected type is an array, then the elements must have its element type.
+        // Otherwise, the element type is inferred from the first element.
+        let mut element_type = match expected {
+            Some(Type::Array(array_type)) => Some(*array_type.element_type.clone()),
+            _ => None,
+        };
+
+        // Check that the elements all have the same type.
+        for element in input.elements.iter() {
+            let type_ = self.visit_expression(element, &element_type);
+            if element_type.is_none() {
+                element_type = type_;
+            }
+        }
+
+        // Construct the type of the array, checking that it is valid.
+        let array_type = ArrayType {
+            element_type: Box::new(element_type?),
+            length: PositiveNumber {
+                value: input.elements.len().to_string(),
+            },
+        };
+        self.assert_type_is_defined(&Type::Array(array_type.clone()), input.span());
+
+        // Store the type of the array for later phases of compilation.
+        input.type_.replace(Some(array_type.clone()));
+
+        Some(self.assert_and_return_type(Type::Array(array_type), expected, input.span()))
+    }
+
     fn visit_binary(&mut self, input: &'a BinaryExpression, destination: &Self::AdditionalInput) -> Self::Output {
         match input.op {
             BinaryOperation::And | BinaryOperation::Or | BinaryOperation::Nand | BinaryOperation::Nor => {
                 // Only boolean types.
                 self.assert_bool_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -199,7 +290,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::BitwiseAnd | BinaryOperation::BitwiseOr | BinaryOperation::Xor => {
                 //  Only boolean or integer types.
                 self.assert_bool_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -210,7 +301,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::Add => {
                 // Only field, group, scalar, or integer types.
                 self.assert_field_group_scalar_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -221,7 +312,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::Sub => {
                 // Only field, group, or integer types.
                 self.assert_field_group_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -233,7 +324,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Operation returns field, group or integer types.
                 self.assert_field_group_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, &None);
+                let t1 = self.visit_left_operand(input, &None);
                 let t2 = self.visit_expression(&input.right, &None);
 
                 // Allow group * scalar multiplication.
@@ -289,7 +380,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only field or integer types.
                 self.assert_field_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -301,7 +392,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only integer types.
                 self.assert_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -313,7 +404,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only unsigned integer types.
                 self.assert_unsigned_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -325,7 +416,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Operation returns field or integer types.
                 self.assert_field_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, &None);
+                let t1 = self.visit_left_operand(input, &None);
                 let t2 = self.visit_expression(&input.right, &None);
 
                 // Allow field ^ field.
@@ -368,7 +459,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Eq | BinaryOperation::Neq => {
                 // Assert first and second address, boolean, field, group, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
+                let t1 = self.visit_left_operand(input, &None);
                 let t2 = self.visit_expression(&input.right, &None);
 
                 // Check that the types of the operands are equal.
@@ -381,7 +472,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Lt | BinaryOperation::Gt | BinaryOperation::Lte | BinaryOperation::Gte => {
                 // Assert left and right are equal field, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
+                let t1 = self.visit_left_operand(input, &None);
                 let t2 = self.visit_expression(&input.right, &None);
 
                 match (&t1, &t2) {
@@ -409,7 +500,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             | BinaryOperation::MulWrapped => {
                 // Only integer types.
                 self.assert_int_type(destination, input.span);
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
                 // Check that both operands have the same type.
@@ -422,7 +513,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             | BinaryOperation::Shr
             | BinaryOperation::ShrWrapped
             | BinaryOperation::PowWrapped => {
-                let t1 = self.visit_expression(&input.left, destination);
+                let t1 = self.visit_left_operand(input, destination);
                 let t2 = self.visit_expression(&input.right, &None);
 
                 // Assert left and destination are equal integer types.
@@ -450,8 +541,14 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
                     match self.variant.unwrap() {
                         // If the function is not a transition function, it can only call "inline" functions.
+                        // Test functions can also call transitions.
                         Variant::Inline | Variant::Standard => {
-                            if !matches!(func.variant, Variant::Inline) {
+                            let is_callable = match func.variant {
+                                Variant::Inline => true,
+                                Variant::Transition => self.is_test,
+                                Variant::Standard => false,
+                            };
+                            if !is_callable {
                                 self.emit_err(TypeCheckerError::can_only_call_inline_function(input.span));
                             }
                         }
@@ -470,6 +567,19 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         self.emit_err(TypeCheckerError::cannot_call_external_inline_function(input.span));
                     }
 
+                    // Check that an external function is declared by the imported program that it is called from.
+                    if let Some(Expression::Identifier(program)) = input.external.as_deref() {
+                        let is_declared = self
+                            .imported_programs
+                            .get(&program.name)
+                            .map_or(false, |scope| scope.functions.contains_key(&ident.name));
+                        if !is_declared {
+                            self.emit_err(TypeCheckerError::unknown_external_sym(
+                                "function", program, ident, input.span,
+                            ));
+                        }
+                    }
+
                     let ret = self.assert_and_return_type(func.output_type, expected, input.span());
 
                     // Check number of function arguments.
@@ -506,6 +616,24 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_cast(&mut self, input: &'a CastExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        // Check that the target type of the cast expression is a castable type.
+        self.assert_castable_type(&Some(input.type_.clone()), input.span());
+
+        // Check that the type of the expression being cast is a castable type.
+        let expression_type = self.visit_expression(&input.expression, &None);
+        self.assert_castable_type(&expression_type, input.expression.span());
+
+        // Record whether the cast is checked or lossy, which depends on the pair of types.
+        if let Some(expression_type) = &expression_type {
+            input.lossy.set(Self::is_lossy_cast(expression_type, &input.type_));
+        }
+        input.expression_type.replace(expression_type);
+
+        // Check that the target type matches the expected type.
+        Some(self.assert_and_return_type(input.type_.clone(), expected, input.span()))
+    }
+
     fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
         let struct_ = self.symbol_table.borrow().lookup_struct(input.name.name).cloned();
         if let Some(struct_) = struct_ {
@@ -631,11 +759,45 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         })
     }
 
+    fn visit_match_expression(&mut self, input: &'a MatchExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        let type_ = self.visit_expression(&input.expression, &None);
+        let type_ = self.check_match_value(type_, input.expression.span());
+
+        // If the type of the expression is not expected, then every arm must have the type of the first arm.
+        let mut output = expected.clone();
+        let mut covered = IndexSet::new();
+        // Note that the arms are conditionally executed.
+        let previous_is_conditional = core::mem::replace(&mut self.is_conditional, true);
+        for arm in input.arms.iter() {
+            let bindings = self.check_match_pattern(&arm.pattern, &type_, &mut covered);
+            let arm_type = self.visit_expression(&arm.expression, &output);
+            if output.is_none() {
+                output = arm_type;
+            }
+
+            // Note that the variables bound by the pattern are only in scope in the expression of the arm.
+            for binding in bindings {
+                self.symbol_table.borrow_mut().remove_variable(binding);
+            }
+        }
+        self.is_conditional = previous_is_conditional;
+
+        // Check that the arms cover every value of the type being matched.
+        if let Some(type_) = &type_ {
+            self.check_match_exhaustive(type_, &covered, input.span());
+        }
+
+        output
+    }
+
     fn visit_ternary(&mut self, input: &'a TernaryExpression, expected: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.condition, &Some(Type::Boolean));
 
+        // Note that the branches of a ternary expression are conditionally executed.
+        let previous_is_conditional = core::mem::replace(&mut self.is_conditional, true);
         let t1 = self.visit_expression(&input.if_true, expected);
         let t2 = self.visit_expression(&input.if_false, expected);
+        self.is_conditional = previous_is_conditional;
 
         return_incorrect_type(t1, t2, expected)
     }
@@ -678,7 +840,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_unary(&mut self, input: &'a UnaryExpression, destination: &Self::AdditionalInput) -> Self::Output {
-        match input.op {
+        let type_ = match input.op {
             UnaryOperation::Abs => {
                 // Only signed integer types.
                 self.assert_signed_int_type(destination, input.span());
@@ -721,7 +883,11 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 self.assert_field_type(destination, input.span());
                 self.visit_expression(&input.receiver, destination)
             }
-        }
+        };
+
+        // Record the type of the operand, which later passes depend on, e.g. the estimates of the cost of the program.
+        input.operand_type.replace(type_.clone());
+        type_
     }
 
     fn visit_unit(&mut self, input: &'a UnitExpression, _additional: &Self::AdditionalInput) -> Self::Output {
@@ -734,3 +900,13 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         Some(Type::Unit)
     }
 }
+
+impl<'a> TypeChecker<'a> {
+    /// Visits the left operand of a binary expression and records its type,
+    /// which later passes depend on, e.g. dead code elimination and the estimates of the cost of the program.
+    fn visit_left_operand(&mut self, input: &'a BinaryExpression, expected: &Option<Type>) -> Option<Type> {
+        let type_ = self.visit_expression(&input.left, expected);
+        input.operand_type.replace(type_.clone());
+        type_
+    }
+}
@@ -27,26 +27,18 @@ use std::collections::HashSet;
 
 impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     fn visit_program(&mut self, input: &'a Program) {
-        match self.is_imported {
-            // If the program is imported, then it is not allowed to import any other programs.
-            true => {
-                input.imports.values().for_each(|(_, span)| {
-                    self.emit_err(TypeCheckerError::imported_program_cannot_import_program(*span))
-                });
-            }
-            // Otherwise, typecheck the imported programs.
-            false => {
-                // Set `self.is_imported`.
-                let previous_is_imported = core::mem::replace(&mut self.is_imported, true);
-
-                // Typecheck the imported programs.
-                input.imports.values().for_each(|import| self.visit_import(&import.0));
-
-                // Set `self.is_imported` to its previous state.
-                self.is_imported = previous_is_imported;
+        // Record the program scopes of the imported programs, which declare the records and functions used externally.
+        // Note that the interfaces of the programs imported from Aleo instructions are not type checked, since they have no bodies.
+        for (name, (program, _)) in input.stubs.iter().chain(input.imports.iter()) {
+            if let Some(scope) = program.program_scopes.values().next() {
+                self.imported_programs.insert(*name, scope);
             }
         }
 
+        // Typecheck the imported programs.
+        // Note that import resolution orders the imports so that each program precedes the programs that import it.
+        input.imports.values().for_each(|import| self.visit_import(&import.0));
+
         // Typecheck the program scopes.
         input
             .program_scopes
@@ -55,6 +47,10 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // Typecheck each constant declaration.
+        // Note that constants are checked in order, so a constant may only refer to the constants declared before it.
+        input.consts.values().for_each(|const_| self.visit_const(const_));
+
         // Typecheck each struct definition.
         input.structs.values().for_each(|function| self.visit_struct(function));
 
@@ -63,6 +59,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             self.emit_err(TypeCheckerError::cyclic_struct_dependency(path));
         }
 
+        // Typecheck each enum definition.
+        input.enums.values().for_each(|enum_| self.visit_enum(enum_));
+
         // Typecheck each mapping definition.
         input.mappings.values().for_each(|mapping| self.visit_mapping(mapping));
 
@@ -90,6 +89,32 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        // Check that the constant has a primitive type.
+        self.assert_castable_type(&Some(input.type_.clone()), input.span);
+
+        // Check that the value of the constant has the declared type and can be evaluated at compile time.
+        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        if !self.is_constant_expression(&input.value) {
+            self.emit_err(TypeCheckerError::const_value_must_be_constant(
+                input.identifier.name,
+                input.value.span(),
+            ));
+        }
+
+        // Add the constant to the program scope.
+        if let Err(err) = self.symbol_table.borrow_mut().insert_variable(
+            input.identifier.name,
+            VariableSymbol {
+                type_: input.type_.clone(),
+                span: input.identifier.span,
+                declaration: VariableType::Const,
+            },
+        ) {
+            self.handler.emit_err(err);
+        }
+    }
+
     fn visit_struct(&mut self, input: &'a Struct) {
         // Check for conflicting struct/record member names.
         let mut used = HashSet::new();
@@ -155,9 +180,13 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             }
             // Ensure that there are no record members.
             self.assert_member_is_not_record(identifier.span, input.identifier.name, type_);
-            // If the member is a struct, add it to the struct dependency graph.
+            // If the member is a struct, or an array of structs, add it to the struct dependency graph.
             // Note that we have already checked that each member is defined and valid.
-            if let Type::Identifier(member_type) = type_ {
+            let mut member_type = type_;
+            while let Type::Array(array_type) = member_type {
+                member_type = &array_type.element_type;
+            }
+            if let Type::Identifier(member_type) = member_type {
                 self.struct_graph.add_edge(input.identifier.name, member_type.name);
             }
             // If the input is a struct, then check that the member does not have a mode.
@@ -167,35 +196,79 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_enum(&mut self, input: &'a Enum) {
+        // Check that the enum has at least one variant, and that its variants fit in its `u8` tag.
+        if input.variants.is_empty() {
+            self.emit_err(TypeCheckerError::empty_enum(input.name(), input.span()));
+        } else if input.variants.len() > Enum::MAX_VARIANTS {
+            self.emit_err(TypeCheckerError::too_many_enum_variants(
+                input.name(),
+                Enum::MAX_VARIANTS,
+                input.span(),
+            ));
+        }
+
+        // Check for conflicting variant names.
+        let mut used = HashSet::new();
+        if !input.variants.iter().all(|variant| used.insert(variant.name())) {
+            self.emit_err(TypeCheckerError::duplicate_enum_variant(input.name(), input.span()));
+        }
+
+        // Check that the values carried by each variant have primitive types.
+        for variant in input.variants.iter() {
+            for type_ in variant.payload.iter() {
+                self.assert_castable_type(&Some(type_.clone()), variant.span());
+            }
+        }
+    }
+
     fn visit_mapping(&mut self, input: &'a Mapping) {
         // Check that a mapping's key type is valid.
         self.assert_type_is_defined(&input.key_type, input.span);
-        // Check that a mapping's key type is not tuple types or mapping types.
+        // Check that a mapping's key type is not tuple types, mapping types, or array types.
         match input.key_type {
             Type::Tuple(_) => self.emit_err(TypeCheckerError::invalid_mapping_type("key", "tuple", input.span)),
             // Note that this is not possible since the parser does not currently accept mapping types.
             Type::Mapping(_) => self.emit_err(TypeCheckerError::invalid_mapping_type("key", "mapping", input.span)),
+            Type::Array(_) => self.emit_err(TypeCheckerError::invalid_mapping_type(
+                "key",
+                "fixed-size array",
+                input.span,
+            )),
             _ => {}
         }
 
         // Check that a mapping's value type is valid.
         self.assert_type_is_defined(&input.value_type, input.span);
-        // Check that a mapping's value type is not tuple types or mapping types.
+        // Check that a mapping's value type is not tuple types, mapping types, or array types.
         match input.value_type {
             Type::Tuple(_) => self.emit_err(TypeCheckerError::invalid_mapping_type("value", "tuple", input.span)),
             // Note that this is not possible since the parser does not currently accept mapping types.
             Type::Mapping(_) => self.emit_err(TypeCheckerError::invalid_mapping_type("value", "mapping", input.span)),
+            Type::Array(_) => self.emit_err(TypeCheckerError::invalid_mapping_type(
+                "value",
+                "fixed-size array",
+                input.span,
+            )),
             _ => {}
         }
     }
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        // Note that `@test` is the only annotation that Leo supports.
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            match annotation.identifier.name {
+                // Test functions are run by `leo test` without any inputs.
+                sym::test if function.variant != Variant::Standard || !function.input.is_empty() => {
+                    self.emit_err(TypeCheckerError::invalid_test_function(annotation.span))
+                }
+                sym::test => {}
+                // TODO: Change to compiler warning.
+                _ => self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)),
+            }
         }
+        self.is_test = function.is_test();
 
         self.variant = Some(function.variant);
 
@@ -225,8 +298,12 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         // Type check the function's parameters.
         function.input.iter().for_each(|input_var| {
-            // Check that the type of input parameter is defined.
-            self.assert_type_is_defined(&input_var.type_(), input_var.span());
+            match input_var {
+                // Check that the external record is declared by the imported program.
+                Input::External(external) => self.assert_external_record_is_declared(external),
+                // Check that the type of input parameter is defined.
+                Input::Internal(_) => self.assert_type_is_defined(&input_var.type_(), input_var.span()),
+            }
             // Check that the type of the input parameter is not a tuple.
             if matches!(input_var.type_(), Type::Tuple(_)) {
                 self.emit_err(TypeCheckerError::function_cannot_take_tuple_as_input(input_var.span()))
@@ -268,8 +345,8 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     if !matches!(function.variant, Variant::Transition) {
                         self.emit_err(TypeCheckerError::function_cannot_output_record(external.span()));
                     }
-                    // Otherwise, do not type check external record function outputs.
-                    // TODO: Verify that this is not needed when the import system is updated.
+                    // Check that the external record is declared by the imported program.
+                    self.assert_external_record_is_declared(external);
                 }
                 Output::Internal(function_output) => {
                     // Check that the type of output is defined.
@@ -281,8 +358,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                                 .symbol_table
                                 .borrow()
                                 .lookup_struct(identifier.name)
-                                .unwrap()
-                                .is_record
+                                .map_or(false, |struct_| struct_.is_record)
                         {
                             self.emit_err(TypeCheckerError::function_cannot_output_record(function_output.span));
                         }
@@ -15,11 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{TypeChecker, VariableSymbol, VariableType};
+use indexmap::IndexSet;
 use itertools::Itertools;
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
 
 impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     fn visit_statement(&mut self, input: &'a Statement) {
@@ -40,6 +41,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Increment(stmt) => self.visit_increment(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -61,7 +63,18 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
-        let var_name = match input.place {
+        // The place is either a variable or an element of an array, struct, or tuple variable, e.g. `a[0].b.1`.
+        let mut place = &input.place;
+        loop {
+            place = match place {
+                Expression::Access(AccessExpression::Array(access)) => &access.array,
+                Expression::Access(AccessExpression::Member(access)) => &access.inner,
+                Expression::Access(AccessExpression::Tuple(access)) => &access.tuple,
+                _ => break,
+            };
+        }
+
+        let var_name = match *place {
             Expression::Identifier(id) => id,
             _ => {
                 self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
@@ -86,7 +99,12 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         };
 
         if var_type.is_some() {
-            self.visit_expression(&input.value, &var_type);
+            // If the place is an element or a member, then check the access and use its type.
+            let place_type = match input.place {
+                Expression::Identifier(_) => var_type,
+                _ => self.visit_expression(&input.place, &None),
+            };
+            self.visit_expression(&input.value, &place_type);
         }
     }
 
@@ -113,6 +131,8 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         let previous_has_return = core::mem::replace(&mut self.has_return, then_block_has_return);
         // Set the `has_finalize` flag for the then-block.
         let previous_has_finalize = core::mem::replace(&mut self.has_finalize, then_block_has_finalize);
+        // Set the `is_conditional` flag.
+        let previous_is_conditional = core::mem::replace(&mut self.is_conditional, true);
 
         self.visit_block(&input.then);
 
@@ -146,6 +166,8 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         self.has_return = previous_has_return || (then_block_has_return && otherwise_block_has_return);
         // Restore the previous `has_finalize` flag.
         self.has_finalize = previous_has_finalize || (then_block_has_finalize && otherwise_block_has_finalize);
+        // Restore the previous `is_conditional` flag.
+        self.is_conditional = previous_is_conditional;
     }
 
     fn visit_console(&mut self, _: &'a ConsoleStatement) {
@@ -217,6 +239,19 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         // Check the expression on the left-hand side.
         self.visit_expression(&input.value, &Some(input.type_.clone()));
 
+        // Check that a constant has a primitive type and a value that can be evaluated at compile time.
+        if input.declaration_type == DeclarationType::Const {
+            self.assert_castable_type(&Some(input.type_.clone()), input.span);
+            if let Expression::Identifier(identifier) = &input.place {
+                if !self.is_constant_expression(&input.value) {
+                    self.emit_err(TypeCheckerError::const_value_must_be_constant(
+                        identifier.name,
+                        input.value.span(),
+                    ));
+                }
+            }
+        }
+
         // TODO: Dedup with unrolling pass.
         // Helper to insert the variables into the symbol table.
         let insert_variable = |symbol: Symbol, type_: Type, span: Span, declaration: VariableType| {
@@ -265,8 +300,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
-        // Expression statements can only be function calls.
-        if !matches!(input.expression, Expression::Call(_)) {
+        // Expression statements can only be function calls or mapping operations.
+        if !matches!(
+            input.expression,
+            Expression::Call(_)
+                | Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                    ty: Type::Identifier(Identifier { name: sym::Mapping, .. }),
+                    ..
+                }))
+        ) {
             self.emit_err(TypeCheckerError::expression_statement_must_be_function_call(
                 input.span(),
             ));
@@ -351,23 +393,64 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         self.visit_expression(&input.start, iter_type);
 
-        // If `input.start` is a literal, instantiate it as a value.
-        if let Expression::Literal(literal) = &input.start {
-            input.start_value.replace(Some(Value::from(literal)));
-        } else {
-            self.emit_err(TypeCheckerError::loop_bound_must_be_a_literal(input.start.span()));
+        // Check that `input.start` can be evaluated at compile time.
+        // Note that the loop bounds are evaluated during loop unrolling.
+        if !self.is_constant_expression(&input.start) {
+            self.emit_err(TypeCheckerError::loop_bound_must_be_constant(input.start.span()));
         }
 
         self.visit_expression(&input.stop, iter_type);
 
-        // If `input.stop` is a literal, instantiate it as a value.
-        if let Expression::Literal(literal) = &input.stop {
-            input.stop_value.replace(Some(Value::from(literal)));
-        } else {
-            self.emit_err(TypeCheckerError::loop_bound_must_be_a_literal(input.stop.span()));
+        // Check that `input.stop` can be evaluated at compile time.
+        if !self.is_constant_expression(&input.stop) {
+            self.emit_err(TypeCheckerError::loop_bound_must_be_constant(input.stop.span()));
         }
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        let type_ = self.visit_expression(&input.expression, &None);
+        let type_ = self.check_match_value(type_, input.expression.span());
+
+        // Set the `is_conditional` flag.
+        let previous_is_conditional = core::mem::replace(&mut self.is_conditional, true);
+
+        let previous_has_return = self.has_return;
+        let previous_has_finalize = self.has_finalize;
+        let mut arms_have_return = !input.arms.is_empty();
+        let mut arms_have_finalize = !input.arms.is_empty();
+
+        let mut covered = IndexSet::new();
+        for arm in input.arms.iter() {
+            // Set the `has_return` and `has_finalize` flags for the arm.
+            self.has_return = false;
+            self.has_finalize = false;
+
+            // Create a new scope for the arm, which contains the variables bound by its pattern.
+            // Note that the statements of the arm are in the same scope, since the arm is lowered to a single block.
+            let scope_index = self.create_child_scope();
+
+            self.check_match_pattern(&arm.pattern, &type_, &mut covered);
+
+            arm.block.statements.iter().for_each(|stmt| self.visit_statement(stmt));
+
+            self.exit_scope(scope_index);
+
+            arms_have_return &= self.has_return;
+            arms_have_finalize &= self.has_finalize;
+        }
+
+        // Check that the arms cover every value of the type being matched.
+        if let Some(type_) = &type_ {
+            self.check_match_exhaustive(type_, &covered, input.span());
+        }
+
+        // Restore the previous flags.
+        // Note that since the arms are exhaustive, the statement returns if every arm returns.
+        self.has_return = previous_has_return || arms_have_return;
+        self.has_finalize = previous_has_finalize || arms_have_finalize;
+        self.is_conditional = previous_is_conditional;
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         // We can safely unwrap all self.parent instances because
         // statements should always have some parent block
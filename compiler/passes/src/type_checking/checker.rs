@@ -14,13 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable};
+use crate::{CallGraph, StructGraph, SymbolTable, VariableSymbol, VariableType};
 
-use leo_ast::{Identifier, IntegerType, Node, Type, Variant};
+use leo_ast::{
+    AssociatedFunction, Enum, Expression, ExpressionVisitor, External, Identifier, IntegerType, Literal, MatchPattern,
+    Node, ProgramScope, Type, Variant,
+};
 use leo_core::*;
 use leo_errors::{emitter::Handler, TypeCheckerError};
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
 
+use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use std::cell::RefCell;
 
@@ -33,6 +37,8 @@ pub struct TypeChecker<'a> {
     pub(crate) call_graph: CallGraph,
     /// The error handler.
     pub(crate) handler: &'a Handler,
+    /// The program scopes of the imported programs, including the interfaces of the programs imported from Aleo instructions.
+    pub(crate) imported_programs: IndexMap<Symbol, &'a ProgramScope>,
     /// The name of the function that we are currently traversing.
     pub(crate) function: Option<Symbol>,
     /// The variant of the function that we are currently traversing.
@@ -44,12 +50,16 @@ pub struct TypeChecker<'a> {
 
     /// Whether or not we are currently traversing a finalize block.
     pub(crate) is_finalize: bool,
-    /// Whether or not we are currently traversing an imported program.
-    pub(crate) is_imported: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+    /// Whether or not we are currently traversing a conditional statement.
+    pub(crate) is_conditional: bool,
+    /// Whether or not we are currently traversing a test function.
+    pub(crate) is_test: bool,
 }
 
+const ADDRESS_TYPE: Type = Type::Address;
+
 const BOOLEAN_TYPE: Type = Type::Boolean;
 
 const FIELD_TYPE: Type = Type::Field;
@@ -93,10 +103,20 @@ const MAGNITUDE_TYPES: [Type; 3] = [
     Type::Integer(IntegerType::U32),
 ];
 
+/// The maximum number of elements in an array.
+/// Arrays are lowered to Aleo structs, which can have at most 32 members.
+const MAX_ARRAY_LENGTH: usize = 32;
+
 impl<'a> TypeChecker<'a> {
     /// Returns a new type checker given a symbol table and error handler.
     pub fn new(symbol_table: SymbolTable, handler: &'a Handler) -> Self {
-        let struct_names = symbol_table.structs.keys().cloned().collect();
+        // Note that enums are lowered to structs, so they are nodes of the struct dependency graph.
+        let struct_names = symbol_table
+            .structs
+            .keys()
+            .chain(symbol_table.enums.keys())
+            .cloned()
+            .collect();
 
         let function_names = symbol_table.functions.keys().cloned().collect();
 
@@ -106,16 +126,24 @@ impl<'a> TypeChecker<'a> {
             struct_graph: StructGraph::new(struct_names),
             call_graph: CallGraph::new(function_names),
             handler,
+            imported_programs: IndexMap::new(),
             function: None,
             variant: None,
             has_return: false,
             has_finalize: false,
             is_finalize: false,
-            is_imported: false,
             is_return: false,
+            is_conditional: false,
+            is_test: false,
         }
     }
 
+    /// Consumes the type checker and returns the symbol table.
+    /// The variables of every scope are kept, even if the program has type errors.
+    pub fn into_symbol_table(self) -> SymbolTable {
+        self.symbol_table.into_inner()
+    }
+
     /// Enters a child scope.
     pub(crate) fn enter_scope(&mut self, index: usize) {
         let previous_symbol_table = std::mem::take(&mut self.symbol_table);
@@ -339,6 +367,86 @@ impl<'a> TypeChecker<'a> {
         )
     }
 
+    /// Emits an error to the handler if the given type cannot be cast to or from.
+    /// Castable types are addresses, booleans, fields, groups, scalars, and integers.
+    /// Note that snarkVM implements `Cast` between every pair of these types, so any castable value can be cast to any castable type.
+    pub(crate) fn assert_castable_type(&self, type_: &Option<Type>, span: Span) {
+        self.check_type(
+            Self::is_castable_type,
+            format!(
+                "{ADDRESS_TYPE}, {BOOLEAN_TYPE}, {FIELD_TYPE}, {GROUP_TYPE}, {SCALAR_TYPE}, {}",
+                types_to_string(&INT_TYPES),
+            ),
+            type_,
+            span,
+        )
+    }
+
+    /// Returns `true` if the type can be cast to or from.
+    fn is_castable_type(type_: &Type) -> bool {
+        ADDRESS_TYPE.eq(type_)
+            | BOOLEAN_TYPE.eq(type_)
+            | FIELD_TYPE.eq(type_)
+            | GROUP_TYPE.eq(type_)
+            | SCALAR_TYPE.eq(type_)
+            | INT_TYPES.contains(type_)
+    }
+
+    /// Returns `true` if a cast from `from` to `to` is lossy, i.e. truncates the value if it does not fit the target type.
+    /// A cast between integer types that may not fit keeps the lowest bits of the two's complement value, e.g. `300u16 as u8` is `44u8`,
+    /// as the wrapped arithmetic operations do. Every other cast is checked, i.e. halts if the value does not fit the target type.
+    /// Since snarkVM does not support the `cast.lossy` instruction yet, lossy casts are lowered to other instructions.
+    pub(crate) fn is_lossy_cast(from: &Type, to: &Type) -> bool {
+        matches!((from, to), (Type::Integer(_), Type::Integer(_))) && Self::cast_may_halt(from, to)
+    }
+
+    /// Returns `true` if a checked cast from `from` to `to` halts for some values, following snarkVM.
+    /// Addresses and groups are cast through their x-coordinate, and values are cast to addresses and groups
+    /// by recovering the point from an x-coordinate, which may not exist.
+    fn cast_may_halt(from: &Type, to: &Type) -> bool {
+        let is_point = |type_: &Type| ADDRESS_TYPE.eq(type_) | GROUP_TYPE.eq(type_);
+        match (from, to) {
+            (from, to) if from == to => false,
+            // Booleans are zero or one, which every castable type represents.
+            (Type::Boolean, _) => false,
+            // The x-coordinate of a point and the bits of a scalar or an integer are always a field element.
+            (_, Type::Field) => false,
+            (from, to) if is_point(to) => !is_point(from),
+            // The bits of an integer always fit in a scalar, while fields and x-coordinates may not.
+            (Type::Integer(_), Type::Scalar) => false,
+            (Type::Integer(from), Type::Integer(to)) => match (from.is_signed(), to.is_signed()) {
+                (false, false) | (true, true) => from.size_in_bits() > to.size_in_bits(),
+                (false, true) => from.size_in_bits() >= to.size_in_bits(),
+                (true, false) => true,
+            },
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if the expression can be evaluated at compile time.
+    /// Constant expressions are built from literals, constants, and loop variables, using casts, unary, binary, and ternary operations.
+    pub(crate) fn is_constant_expression(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Literal(_) => true,
+            Expression::Identifier(identifier) => self
+                .symbol_table
+                .borrow()
+                .lookup_variable(identifier.name)
+                .map_or(false, |variable| variable.declaration == VariableType::Const),
+            Expression::Binary(binary) => {
+                self.is_constant_expression(&binary.left) && self.is_constant_expression(&binary.right)
+            }
+            Expression::Cast(cast) => self.is_constant_expression(&cast.expression),
+            Expression::Ternary(ternary) => {
+                self.is_constant_expression(&ternary.condition)
+                    && self.is_constant_expression(&ternary.if_true)
+                    && self.is_constant_expression(&ternary.if_false)
+            }
+            Expression::Unary(unary) => self.is_constant_expression(&unary.receiver),
+            _ => false,
+        }
+    }
+
     /// Emits an error if the `struct` is not a core library struct.
     /// Emits an error if the `function` is not supported by the struct.
     pub(crate) fn check_core_function_call(&self, struct_: &Type, function: &Identifier) -> Option<CoreInstruction> {
@@ -359,6 +467,278 @@ impl<'a> TypeChecker<'a> {
         None
     }
 
+    /// Type checks an operation on a mapping, e.g. `Mapping::get(balances, key)`, returning the type of its output.
+    /// Emits an error if the operation is unknown, is outside of a finalize block, or if its arguments are invalid.
+    pub(crate) fn check_mapping_operation(
+        &mut self,
+        access: &'a AssociatedFunction,
+        expected: &Option<Type>,
+    ) -> Option<Type> {
+        // The number of arguments to the operation and whether it updates the mapping.
+        // Note that `get_or_init` writes the default to the mapping if the key is missing.
+        let (num_args, is_update) = match access.name.name {
+            sym::contains | sym::get => (2, false),
+            sym::get_or_init => (3, true),
+            sym::remove => (2, true),
+            sym::set => (3, true),
+            _ => {
+                self.emit_err(TypeCheckerError::invalid_core_function(
+                    sym::Mapping,
+                    access.name,
+                    access.span,
+                ));
+                return None;
+            }
+        };
+
+        // Mappings can only be accessed in a finalize block, or in a test function.
+        if !self.is_finalize && !self.is_test {
+            self.emit_err(TypeCheckerError::mapping_operation_outside_finalize(
+                access.name,
+                access.span,
+            ));
+        }
+
+        // Updates to a mapping cannot be conditionally executed.
+        // Neither can `get`, which halts if the key is missing, since both branches of a conditional are executed after flattening.
+        if is_update && self.is_conditional {
+            self.emit_err(TypeCheckerError::mapping_update_in_conditional(
+                access.name,
+                access.span,
+            ));
+        } else if access.name.name == sym::get && self.is_conditional {
+            self.emit_err(TypeCheckerError::mapping_get_in_conditional(access.span));
+        }
+
+        if access.args.len() != num_args {
+            self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
+                num_args,
+                access.args.len(),
+                access.span,
+            ));
+            return None;
+        }
+
+        // Check that the first argument is a mapping.
+        let mapping_type = match self.visit_expression(&access.args[0], &None) {
+            Some(Type::Mapping(mapping_type)) => mapping_type,
+            Some(type_) => {
+                self.emit_err(TypeCheckerError::expected_one_type_of(
+                    "mapping",
+                    type_,
+                    access.args[0].span(),
+                ));
+                return None;
+            }
+            None => return None,
+        };
+
+        // Check that the key and the value have the key and value types of the mapping.
+        self.visit_expression(&access.args[1], &Some(*mapping_type.key.clone()));
+        if let Some(value) = access.args.get(2) {
+            self.visit_expression(value, &Some(*mapping_type.value.clone()));
+        }
+
+        let output_type = match access.name.name {
+            sym::contains => Type::Boolean,
+            sym::get | sym::get_or_init => *mapping_type.value,
+            _ => Type::Unit,
+        };
+
+        Some(self.assert_and_return_type(output_type, expected, access.span))
+    }
+
+    /// Type checks the construction of an enum value, e.g. `Status::Open` or `Order::Limit(price, amount)`, returning its type.
+    /// Emits an error if the variant is unknown, or if the values it carries are invalid.
+    pub(crate) fn check_enum_construction(
+        &mut self,
+        enum_: &Enum,
+        variant: &Identifier,
+        args: &'a [Expression],
+        expected: &Option<Type>,
+        span: Span,
+    ) -> Option<Type> {
+        let definition = match enum_.variant(variant.name) {
+            Some((_, definition)) => definition,
+            None => {
+                self.emit_err(TypeCheckerError::unknown_enum_variant(
+                    enum_.name(),
+                    variant.name,
+                    variant.span,
+                ));
+                return None;
+            }
+        };
+
+        // Check that the values carried by the variant have the types of its payload.
+        if definition.payload.len() != args.len() {
+            self.emit_err(TypeCheckerError::incorrect_num_enum_payloads(
+                variant.name,
+                definition.payload.len(),
+                args.len(),
+                span,
+            ));
+        }
+        for (arg, type_) in args.iter().zip(definition.payload.iter()) {
+            self.visit_expression(arg, &Some(type_.clone()));
+        }
+
+        Some(self.check_expected_struct(
+            Identifier {
+                name: enum_.name(),
+                span,
+            },
+            expected,
+            span,
+        ))
+    }
+
+    /// Returns the type of the value being matched, if values of its type can be matched, that is, integers, booleans, and enums.
+    /// Emits an error otherwise.
+    pub(crate) fn check_match_value(&mut self, type_: Option<Type>, span: Span) -> Option<Type> {
+        match type_ {
+            Some(Type::Boolean | Type::Integer(_)) => type_,
+            Some(Type::Identifier(identifier)) if self.symbol_table.borrow().lookup_enum(identifier.name).is_some() => {
+                Some(Type::Identifier(identifier))
+            }
+            Some(type_) => {
+                self.emit_err(TypeCheckerError::invalid_match_value(type_, span));
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Type checks the pattern of a match arm against the type of the value being matched, and adds the variables it binds to the current scope.
+    /// Returns the variables that were added.
+    /// Emits an error if the pattern is invalid, or if it is covered by the patterns of the previous arms, given by `covered`.
+    pub(crate) fn check_match_pattern(
+        &mut self,
+        pattern: &'a MatchPattern,
+        type_: &Option<Type>,
+        covered: &mut IndexSet<String>,
+    ) -> Vec<Symbol> {
+        // Note that no pattern is reachable after a wildcard.
+        let after_wildcard = covered.contains("_");
+        let mut bindings = Vec::new();
+
+        let key = match pattern {
+            MatchPattern::Literal(literal) => {
+                self.visit_literal(literal, type_);
+                match literal {
+                    Literal::Boolean(value, _) => Some(value.to_string()),
+                    // Note that the value of an integer literal is normalized, e.g. `01u8` is the same pattern as `1u8`.
+                    Literal::Integer(_, string, _) => string
+                        .parse::<i128>()
+                        .map(|value| value.to_string())
+                        .or_else(|_| string.parse::<u128>().map(|value| value.to_string()))
+                        .ok(),
+                    _ => None,
+                }
+            }
+            MatchPattern::Variant(pattern) => {
+                let enum_ = match type_ {
+                    Some(Type::Identifier(identifier)) => {
+                        self.symbol_table.borrow().lookup_enum(identifier.name).cloned()
+                    }
+                    _ => None,
+                };
+                match (enum_, type_) {
+                    (Some(enum_), _) if pattern.enum_name.name == enum_.name() => {
+                        match enum_.variant(pattern.variant.name) {
+                            Some((_, variant)) => {
+                                // Bind the values carried by the variant.
+                                if pattern.bindings.len() != variant.payload.len() {
+                                    self.emit_err(TypeCheckerError::incorrect_num_enum_payloads(
+                                        variant.name(),
+                                        variant.payload.len(),
+                                        pattern.bindings.len(),
+                                        pattern.span,
+                                    ));
+                                }
+                                for (binding, type_) in pattern.bindings.iter().zip(variant.payload.iter()) {
+                                    match self.symbol_table.borrow_mut().insert_variable(
+                                        binding.name,
+                                        VariableSymbol {
+                                            type_: type_.clone(),
+                                            span: binding.span,
+                                            declaration: VariableType::Mut,
+                                        },
+                                    ) {
+                                        Ok(()) => bindings.push(binding.name),
+                                        Err(err) => self.handler.emit_err(err),
+                                    }
+                                }
+                                Some(variant.name().to_string())
+                            }
+                            None => {
+                                self.emit_err(TypeCheckerError::unknown_enum_variant(
+                                    enum_.name(),
+                                    pattern.variant.name,
+                                    pattern.variant.span,
+                                ));
+                                None
+                            }
+                        }
+                    }
+                    (_, Some(type_)) => {
+                        self.emit_err(TypeCheckerError::type_should_be(
+                            pattern.enum_name.name,
+                            type_,
+                            pattern.enum_name.span,
+                        ));
+                        None
+                    }
+                    (_, None) => None,
+                }
+            }
+            MatchPattern::Wildcard(_) => Some("_".to_string()),
+        };
+
+        let duplicate = key.map_or(false, |key| !covered.insert(key));
+        if after_wildcard || duplicate {
+            self.emit_err(TypeCheckerError::unreachable_match_arm(pattern.span()));
+        }
+
+        bindings
+    }
+
+    /// Emits an error if the patterns `covered` by the arms of a match do not cover every value of the type being matched.
+    pub(crate) fn check_match_exhaustive(&mut self, type_: &Type, covered: &IndexSet<String>, span: Span) {
+        if covered.contains("_") {
+            return;
+        }
+
+        let missing = match type_ {
+            Type::Boolean => ["true", "false"]
+                .into_iter()
+                .filter(|value| !covered.contains(*value))
+                .map(|value| format!("`{value}`"))
+                .collect(),
+            Type::Integer(integer_type) => {
+                // Note that only the values of small integer types can be covered without a wildcard in practice.
+                match 1u128.checked_shl(integer_type.size_in_bits()) {
+                    Some(values) if covered.len() as u128 == values => Vec::new(),
+                    _ => vec![format!("every `{type_}` value")],
+                }
+            }
+            Type::Identifier(identifier) => match self.symbol_table.borrow().lookup_enum(identifier.name) {
+                Some(enum_) => enum_
+                    .variants
+                    .iter()
+                    .filter(|variant| !covered.contains(&variant.name().to_string()))
+                    .map(|variant| format!("`{}::{}`", enum_.name(), variant.name()))
+                    .collect(),
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+
+        if !missing.is_empty() {
+            self.emit_err(TypeCheckerError::non_exhaustive_match(missing.join(", "), span));
+        }
+    }
+
     /// Returns the `struct` type and emits an error if the `expected` type does not match.
     pub(crate) fn check_expected_struct(&mut self, struct_: Identifier, expected: &Option<Type>, span: Span) -> Type {
         if let Some(Type::Identifier(expected)) = expected {
@@ -391,10 +771,28 @@ impl<'a> TypeChecker<'a> {
                     self.assert_member_is_not_record(span, parent, type_)
                 }
             }
+            Type::Array(array_type) => self.assert_member_is_not_record(span, parent, &array_type.element_type),
             _ => {} // Do nothing.
         }
     }
 
+    /// Emits an error if the external record is not declared by the imported program that it names.
+    pub(crate) fn assert_external_record_is_declared(&self, external: &External) {
+        let is_declared = self
+            .imported_programs
+            .get(&external.program_name.name)
+            .and_then(|scope| scope.structs.get(&external.record.name))
+            .map_or(false, |struct_| struct_.is_record);
+        if !is_declared {
+            self.emit_err(TypeCheckerError::unknown_external_sym(
+                "record",
+                external.program_name,
+                external.record,
+                external.span,
+            ));
+        }
+    }
+
     /// Emits an error if the type or its constituent types are not defined.
     pub(crate) fn assert_type_is_defined(&self, type_: &Type, span: Span) {
         match type_ {
@@ -402,8 +800,11 @@ impl<'a> TypeChecker<'a> {
             Type::String => {
                 self.emit_err(TypeCheckerError::strings_are_not_supported(span));
             }
-            // Check that the named composite type has been defined.
-            Type::Identifier(identifier) if self.symbol_table.borrow().lookup_struct(identifier.name).is_none() => {
+            // Check that the named composite type, or enum, has been defined.
+            Type::Identifier(identifier)
+                if self.symbol_table.borrow().lookup_struct(identifier.name).is_none()
+                    && self.symbol_table.borrow().lookup_enum(identifier.name).is_none() =>
+            {
                 self.emit_err(TypeCheckerError::undefined_type(identifier.name, span));
             }
             // Check that the constituent types of the tuple are valid.
@@ -417,6 +818,32 @@ impl<'a> TypeChecker<'a> {
                 self.assert_type_is_defined(&mapping_type.key, span);
                 self.assert_type_is_defined(&mapping_type.value, span);
             }
+            // Check that the length and the element type of the array are valid.
+            Type::Array(array_type) => {
+                match array_type.length.value.parse::<usize>() {
+                    Ok(length) if (1..=MAX_ARRAY_LENGTH).contains(&length) => {}
+                    _ => self.emit_err(TypeCheckerError::invalid_array_length(
+                        &array_type.length,
+                        MAX_ARRAY_LENGTH,
+                        span,
+                    )),
+                }
+                match array_type.element_type.as_ref() {
+                    Type::Tuple(_) => self.emit_err(TypeCheckerError::array_element_cannot_be("tuple", span)),
+                    Type::Mapping(_) => self.emit_err(TypeCheckerError::array_element_cannot_be("mapping", span)),
+                    Type::Identifier(identifier)
+                        if self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record) =>
+                    {
+                        self.emit_err(TypeCheckerError::array_element_cannot_be("record", span))
+                    }
+                    _ => {}
+                }
+                self.assert_type_is_defined(&array_type.element_type, span);
+            }
             _ => {} // Do nothing.
         }
     }
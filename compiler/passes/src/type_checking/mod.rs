@@ -33,13 +33,19 @@ use leo_ast::{Ast, ProgramVisitor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for TypeChecker<'a> {
+    const NAME: &'static str = "type_check";
+
     type Input = (&'a Ast, &'a Handler, SymbolTable);
     type Output = Result<(SymbolTable, StructGraph, CallGraph)>;
 
     fn do_pass((ast, handler, st): Self::Input) -> Self::Output {
+        // Note that the parser may have recovered from syntax errors, so only the errors found here stop the pass.
+        let err_count = handler.err_count();
         let mut visitor = TypeChecker::new(st, handler);
         visitor.visit_program(ast.as_repr());
-        handler.last_err().map_err(|e| *e)?;
+        if handler.err_count() > err_count {
+            handler.last_err().map_err(|e| *e)?;
+        }
 
         Ok((visitor.symbol_table.take(), visitor.struct_graph, visitor.call_graph))
     }
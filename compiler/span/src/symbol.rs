@@ -151,6 +151,14 @@ symbols! {
     Poseidon4,
     Poseidon8,
 
+    // mapping operations
+    Mapping,
+    contains,
+    get,
+    get_or_init,
+    remove,
+    set,
+
     // types
     address,
     bool,
@@ -176,14 +184,17 @@ symbols! {
     True: "true",
 
     // general keywords
+    As: "as",
     assert,
     assert_eq,
     assert_neq,
     caller,
     console,
+    Const: "const",
     constant,
     decrement,
     Else: "else",
+    Enum: "enum",
     finalize,
     For: "for",
     function,
@@ -197,6 +208,7 @@ symbols! {
     leo,
     main,
     mapping,
+    Match: "match",
     Mut: "mut",
     Return: "return",
     SelfLower: "self",
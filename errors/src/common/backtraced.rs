@@ -47,7 +47,8 @@ pub struct Backtraced {
     #[derivative(PartialEq = "ignore")]
     #[derivative(Hash = "ignore")]
     /// The backtrace representing where the error occured in Leo.
-    pub backtrace: Backtrace,
+    /// Boxed, since it is rarely printed and errors are passed around by value.
+    pub backtrace: Box<Backtrace>,
 }
 
 impl Backtraced {
@@ -71,7 +72,7 @@ impl Backtraced {
             code_identifier,
             type_,
             error,
-            backtrace,
+            backtrace: Box::new(backtrace),
         }
     }
 
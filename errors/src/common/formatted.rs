@@ -36,6 +36,8 @@ use std::fmt;
 pub struct Formatted {
     /// The formatted error span information.
     pub span: Span,
+    /// Secondary spans, each with a note on how it relates to the message.
+    pub labels: Box<[(Span, String)]>,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: Backtraced,
 }
@@ -58,6 +60,7 @@ impl Formatted {
     {
         Self {
             span,
+            labels: Default::default(),
             backtrace: Backtraced::new_from_backtrace(
                 message.to_string(),
                 help,
@@ -70,6 +73,12 @@ impl Formatted {
         }
     }
 
+    /// Attaches secondary spans, each with a note on how it relates to the message.
+    pub fn with_labels(mut self, labels: Vec<(Span, String)>) -> Self {
+        self.labels = labels.into_boxed_slice();
+        self
+    }
+
     /// Calls the backtraces error exit code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -107,18 +116,39 @@ impl fmt::Display for Formatted {
             underline
         };
 
-        let (loc, contents) = with_session_globals(|s| {
-            (
-                s.source_map
-                    .span_to_location(self.span)
-                    .unwrap_or_else(SpanLocation::dummy),
-                s.source_map
-                    .line_contents_of_span(self.span)
-                    .unwrap_or_else(|| "<contents unavailable>".to_owned()),
-            )
-        });
+        // Writes the location and the underlined source lines of a span.
+        let snippet = |f: &mut fmt::Formatter, span: Span| -> fmt::Result {
+            let (loc, contents) = with_session_globals(|s| {
+                (
+                    s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy),
+                    s.source_map
+                        .line_contents_of_span(span)
+                        .unwrap_or_else(|| "<contents unavailable>".to_owned()),
+                )
+            });
 
-        let underlined = underline(loc.col_start, loc.col_stop);
+            write!(
+                f,
+                "\n{indent     }--> {path}:{line_start}:{start}\n\
+                {indent     } |\n",
+                indent = INDENT,
+                path = &loc.source_file.name,
+                line_start = loc.line_start,
+                start = loc.col_start,
+            )?;
+
+            for (line_no, line) in contents.lines().enumerate() {
+                writeln!(
+                    f,
+                    "{line_no:width$} | {text}",
+                    width = INDENT.len(),
+                    line_no = loc.line_start + line_no,
+                    text = line,
+                )?;
+            }
+
+            write!(f, "{INDENT     } |{}", underline(loc.col_start, loc.col_stop))
+        };
 
         let (kind, code) = if self.backtrace.error {
             ("Error", self.error_code())
@@ -144,28 +174,17 @@ impl fmt::Display for Formatted {
             write!(f, "{message}")?;
         };
 
-        write!(
-            f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
-            indent = INDENT,
-            path = &loc.source_file.name,
-            line_start = loc.line_start,
-            start = loc.col_start,
-        )?;
-
-        for (line_no, line) in contents.lines().enumerate() {
-            writeln!(
+        snippet(f, self.span)?;
+
+        for (span, note) in self.labels.iter() {
+            write!(
                 f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
-                text = line,
+                "\n{INDENT     } |\n\
+            {INDENT     } = note: {note}"
             )?;
+            snippet(f, *span)?;
         }
 
-        write!(f, "{INDENT     } |{underlined}",)?;
-
         if let Some(help) = &self.backtrace.help {
             write!(
                 f,
@@ -86,6 +86,41 @@ macro_rules! create_messages {
         }
 
 
+        impl $type_ {
+            /// Returns the span the message points to, if it is a formatted message.
+            pub fn span(&self) -> Option<leo_span::Span> {
+                match self {
+                    Self::Formatted(formatted) => Some(formatted.span),
+                    Self::Backtraced(_) => None,
+                }
+            }
+
+            /// Returns the contents of the message, i.e. its text, help, and code.
+            pub fn backtraced(&self) -> &Backtraced {
+                match self {
+                    Self::Formatted(formatted) => &formatted.backtrace,
+                    Self::Backtraced(backtraced) => backtraced,
+                }
+            }
+
+            /// Returns the secondary spans of the message, each with a note, if it is a formatted message.
+            pub fn labels(&self) -> &[(leo_span::Span, String)] {
+                match self {
+                    Self::Formatted(formatted) => &formatted.labels,
+                    Self::Backtraced(_) => &[],
+                }
+            }
+
+            /// Attaches secondary spans, each with a note on how it relates to the message.
+            /// Backtraced messages have no span, so the labels are dropped.
+            pub fn with_labels(self, labels: Vec<(leo_span::Span, String)>) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_labels(labels)),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced),
+                }
+            }
+        }
+
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
@@ -14,12 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::LeoWarning;
+use crate::{Backtraced, LeoWarning};
 
 use super::LeoError;
 use core::default::Default;
 use core::fmt;
+use leo_span::{source_map::FileName, symbol::with_session_globals, Span};
+use serde::Serialize;
 use std::cell::RefCell;
+use std::io::{self, Stderr, Write};
 use std::rc::Rc;
 
 /// Types that are sinks for compiler errors.
@@ -55,6 +58,203 @@ impl Emitter for StderrEmitter {
     }
 }
 
+/// An `Emitter` that writes each error and warning as a JSON object on its own line.
+/// This is the format of `--message-format json`, for tools that consume Leo diagnostics.
+/// By default, the diagnostics are written to the standard error, apart from the output of the command.
+pub struct JsonEmitter<W: Write = Stderr> {
+    /// The stream that the diagnostics are written to.
+    writer: W,
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl JsonEmitter {
+    /// Returns a new JSON emitter that writes to the standard error.
+    pub fn new() -> Self {
+        Self::with_writer(io::stderr())
+    }
+}
+
+impl Default for JsonEmitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> JsonEmitter<W> {
+    /// Returns a new JSON emitter that writes to `writer`.
+    pub fn with_writer(writer: W) -> Self {
+        Self {
+            writer,
+            last_error_code: None,
+        }
+    }
+
+    /// Returns the stream that the diagnostics are written to.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+
+    /// Writes the diagnostic on its own line.
+    fn write(&mut self, diagnostic: JsonDiagnostic) {
+        // A diagnostic that cannot be written cannot be reported either, so it is dropped.
+        let _ = writeln!(self.writer, "{diagnostic}").and_then(|()| self.writer.flush());
+    }
+}
+
+impl<W: Write> Emitter for JsonEmitter<W> {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        self.write(JsonDiagnostic::from_error(&err));
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.write(JsonDiagnostic::from_warning(&warning));
+    }
+}
+
+/// The severity of a `JsonDiagnostic`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The diagnostic is an error, which stops the compilation.
+    Error,
+    /// The diagnostic is a warning.
+    Warning,
+}
+
+/// The location of a `JsonDiagnostic` in a source file.
+/// Lines and columns start at 1, and columns count characters.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonSpan {
+    /// The path or name of the source file.
+    pub file: String,
+    /// The line on which the span starts.
+    pub line_start: usize,
+    /// The column at which the span starts.
+    pub column_start: usize,
+    /// The line on which the span ends.
+    pub line_end: usize,
+    /// The column at which the span ends, exclusive.
+    pub column_end: usize,
+}
+
+impl JsonSpan {
+    /// Returns the location of `span`, if it points into a source file.
+    fn new(span: Span) -> Option<Self> {
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        let file = match &location.source_file.name {
+            FileName::Real(path) => path.display().to_string(),
+            FileName::Custom(name) => name.clone(),
+        };
+        Some(Self {
+            file,
+            line_start: location.line_start,
+            column_start: location.col_start,
+            line_end: location.line_stop,
+            column_end: location.col_stop,
+        })
+    }
+}
+
+/// An error or warning in a machine-readable form.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonDiagnostic {
+    /// The stable code of the error or warning, e.g. `ETYC0372003`.
+    /// Only errors that do not come from Leo, such as those of snarkVM, have no code.
+    pub code: Option<String>,
+    /// Whether this is an error or a warning.
+    pub severity: Severity,
+    /// The message, e.g. `unexpected EOF`.
+    pub message: String,
+    /// A hint on how to fix the problem, if there is one.
+    pub help: Option<String>,
+    /// The location the error or warning points to, if it has one.
+    pub span: Option<JsonSpan>,
+    /// Secondary locations that the error or warning relates to, each with a note.
+    pub labels: Vec<JsonLabel>,
+}
+
+/// A secondary location of a `JsonDiagnostic`, e.g. a step of the data flow that led to a warning.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonLabel {
+    /// The note on how the location relates to the diagnostic.
+    pub message: String,
+    /// The location.
+    pub span: JsonSpan,
+}
+
+impl JsonLabel {
+    /// Returns the labels whose spans point into a source file.
+    fn from_labels(labels: &[(Span, String)]) -> Vec<Self> {
+        labels
+            .iter()
+            .filter_map(|(span, message)| {
+                Some(Self {
+                    message: message.clone(),
+                    span: JsonSpan::new(*span)?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl JsonDiagnostic {
+    /// Returns the diagnostic of `err`.
+    pub fn from_error(err: &LeoError) -> Self {
+        let span = err.span().and_then(JsonSpan::new);
+        let labels = JsonLabel::from_labels(err.labels());
+        match err.backtraced() {
+            Some(backtraced) => Self::new(Severity::Error, backtraced.error_code(), backtraced, span, labels),
+            None => Self {
+                code: None,
+                severity: Severity::Error,
+                message: err.to_string(),
+                help: None,
+                span,
+                labels,
+            },
+        }
+    }
+
+    /// Returns the diagnostic of `warning`.
+    pub fn from_warning(warning: &LeoWarning) -> Self {
+        let backtraced = warning.backtraced();
+        let span = warning.span().and_then(JsonSpan::new);
+        let labels = JsonLabel::from_labels(warning.labels());
+        Self::new(Severity::Warning, backtraced.warning_code(), backtraced, span, labels)
+    }
+
+    /// Returns a diagnostic with the contents of `backtraced`.
+    fn new(
+        severity: Severity,
+        code: String,
+        backtraced: &Backtraced,
+        span: Option<JsonSpan>,
+        labels: Vec<JsonLabel>,
+    ) -> Self {
+        Self {
+            code: Some(code),
+            severity,
+            message: backtraced.message.clone(),
+            help: backtraced.help.clone(),
+            span,
+            labels,
+        }
+    }
+}
+
+impl fmt::Display for JsonDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
 /// A buffer of `T`s.
 #[derive(Debug)]
 pub struct Buffer<T>(Vec<T>);
@@ -264,8 +464,8 @@ impl Handler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use crate::{ParserError, TaintAnalysisWarning};
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then, Span};
 
     #[test]
     fn fresh_no_errors() {
@@ -302,4 +502,40 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn json_diagnostics() {
+        create_session_if_not_set_then(|s| {
+            let source_file = s
+                .source_map
+                .new_source("let x = 1;\nlet y = [;\n", FileName::Custom("main.leo".into()));
+            let span = Span::new(source_file.start_pos + BytePos(19), source_file.start_pos + BytePos(20));
+
+            let label = Span::new(source_file.start_pos + BytePos(4), source_file.start_pos + BytePos(5));
+            let warning = TaintAnalysisWarning::private_value_in_public_output("x", span)
+                .with_labels(vec![(label, "`x` is a private input".to_string())]);
+
+            let mut emitter = JsonEmitter::with_writer(Vec::new());
+            emitter.emit_err(ParserError::unexpected_eof(span).into());
+            emitter.emit_err(LeoError::LastErrorCode(1));
+            emitter.emit_warning(warning.into());
+            assert_eq!(emitter.last_emitted_err_code(), Some(1));
+
+            let output = String::from_utf8(emitter.into_writer()).unwrap();
+            let lines: Vec<&str> = output.lines().collect();
+            assert_eq!(lines.len(), 3);
+            assert_eq!(
+                lines[0],
+                r#"{"code":"EPAR0370003","severity":"error","message":"unexpected EOF","help":null,"span":{"file":"main.leo","line_start":2,"column_start":9,"line_end":2,"column_end":10},"labels":[]}"#
+            );
+            let error = JsonDiagnostic::from_error(&LeoError::LastErrorCode(1));
+            assert_eq!(error.code, None);
+            assert_eq!(error.span, None);
+            assert_eq!(lines[1], error.to_string());
+            assert_eq!(
+                lines[2],
+                r#"{"code":"WTAI0371000","severity":"warning","message":"A value derived from the private input `x` is returned as a public output.","help":"Make the output private, or make the input public if it is not secret.","span":{"file":"main.leo","line_start":2,"column_start":9,"line_end":2,"column_end":10},"labels":[{"message":"`x` is a private input","span":{"file":"main.leo","line_start":1,"column_start":5,"line_end":1,"column_end":6}}]}"#
+            );
+        })
+    }
 }
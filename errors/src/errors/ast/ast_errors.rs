@@ -105,4 +105,20 @@ create_messages!(
         msg: format!("variable `{var}` shadowed by"),
         help: None,
     }
+
+    /// For when the AST fails to be written to a file as Leo source code.
+    @backtraced
+    failed_to_write_ast_to_leo_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write ast to a leo file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when a user shadows an enum.
+    @formatted
+    shadowed_enum {
+        args: (enum_: impl Display),
+        msg: format!("enum `{enum_}` shadowed by"),
+        help: None,
+    }
 );
@@ -150,4 +150,60 @@ create_messages!(
         msg: format!("Failed to parse the `aleo deploy` command.\nSnarkVM Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    failed_to_derive_caller_address {
+        args: (error: impl Display),
+        msg: format!("Failed to derive the caller address from the development private key.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    tests_failed {
+        args: (failed: impl Display, total: impl Display),
+        msg: format!("{failed} of {total} tests failed."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_formatted_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the formatted file {path:?}: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    files_not_formatted {
+        args: (count: impl Display),
+        msg: format!("{count} file(s) are not formatted."),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    @backtraced
+    failed_to_write_source_map {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the source map {path:?}: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_stats {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the program statistics {path:?}: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_copy_import {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to copy the imported Aleo program {path:?}: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_development_private_key {
+        args: (error: impl Display),
+        msg: format!("Failed to read the development private key from `program.json`: {error}"),
+        help: Some("Add a `development` object with a `private_key` to `program.json`.".to_string()),
+    }
 );
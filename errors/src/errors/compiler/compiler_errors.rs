@@ -70,4 +70,81 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @formatted
+    duplicate_test_declaration {
+        args: (name: impl Display),
+        msg: format!("`{name}` is declared in both the program and its tests."),
+        help: None,
+    }
+
+    @backtraced
+    unknown_compiler_stage {
+        args: (name: impl Display, stages: impl Display),
+        msg: format!("Unknown compiler stage `{name}`."),
+        help: Some(format!("The compiler stages are: {stages}.")),
+    }
+
+    @formatted
+    invalid_aleo_instructions {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("snarkVM rejected the Aleo instructions generated for `{function}`: {error}"),
+        help: Some("This is a bug in the Leo compiler. Please report it at https://github.com/AleoHQ/leo/issues.".to_string()),
+    }
+
+    @backtraced
+    invalid_aleo_program {
+        args: (program: impl Display, error: impl Display),
+        msg: format!("snarkVM rejected the Aleo instructions generated for `{program}`: {error}"),
+        help: Some("This is a bug in the Leo compiler. Please report it at https://github.com/AleoHQ/leo/issues.".to_string()),
+    }
+
+    @backtraced
+    failed_to_load_snarkvm_process {
+        args: (error: impl Display),
+        msg: format!("Failed to load the snarkVM process to check the generated Aleo instructions: {error}"),
+        help: None,
+    }
+
+    @formatted
+    invalid_aleo_import {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the Aleo instructions of the imported program: {error}"),
+        help: None,
+    }
+
+    @formatted
+    unsupported_aleo_import_type {
+        args: (type_: impl Display, program: impl Display),
+        msg: format!("The type `{type_}` used by the imported program `{program}` is not supported in Leo."),
+        help: None,
+    }
+
+    @formatted
+    module_not_found {
+        args: (file_path: impl Display),
+        msg: format!("Attempted to declare a module whose file does not exist `{file_path}`."),
+        help: Some("The module `foo` declared by `mod foo;` is defined in the file `foo.leo` next to the main file.".to_string()),
+    }
+
+    @formatted
+    unknown_module {
+        args: (module: impl Display),
+        msg: format!("The module `{module}` is not declared."),
+        help: Some(format!("Declare the module with `mod {module};` in the main file.")),
+    }
+
+    @formatted
+    unknown_module_item {
+        args: (module: impl Display, item: impl Display),
+        msg: format!("The module `{module}` does not declare `{item}`."),
+        help: None,
+    }
+
+    @formatted
+    unsupported_by_snarkvm {
+        args: (feature: impl Display),
+        msg: format!("snarkVM does not support {feature} yet."),
+        help: Some("The Aleo instructions generated for this code are rejected by snarkVM. Rewrite the program without it.".to_string()),
+    }
 );
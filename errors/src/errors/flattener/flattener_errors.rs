@@ -53,4 +53,36 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when an array index is not a constant after loop unrolling.
+    @formatted
+    array_index_must_be_constant {
+        args: (index: impl Display),
+        msg: format!("The array index `{index}` is not a constant."),
+        help: Some("Array indices must be literals, constants, or loop variables.".to_string()),
+    }
+
+    /// For when an array index is out of range after loop unrolling.
+    @formatted
+    array_index_out_of_range {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("Array index `{index}` out of range for an array with length `{length}`"),
+        help: None,
+    }
+
+    /// For when a constant cast would produce a value that cannot be represented in the target type.
+    @formatted
+    cast_out_of_range {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The const cast `{value} as {type_}` is out of range for `{type_}`."),
+        help: None,
+    }
+
+    /// For when a constant expression cannot be evaluated at compile time.
+    @formatted
+    constant_cannot_be_evaluated {
+        args: (expression: impl Display),
+        msg: format!("The constant expression `{expression}` cannot be evaluated at compile time."),
+        help: Some("Only integer and boolean operations are supported in constant expressions.".to_string()),
+    }
 );
@@ -55,4 +55,28 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when an enum input is not a variant of the enum.
+    @formatted
+    expected_enum_variant {
+        args: (enum_: impl Display),
+        msg: format!("expected a variant of the enum '{enum_}'"),
+        help: None,
+    }
+
+    /// For when an enum input names a variant that the enum does not declare.
+    @formatted
+    unknown_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("the enum '{enum_}' has no variant '{variant}'"),
+        help: None,
+    }
+
+    /// For when an enum input gives a variant the wrong number of values.
+    @formatted
+    incorrect_enum_payload_count {
+        args: (variant: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("the variant '{variant}' expects {expected} values, but {received} were given"),
+        help: None,
+    }
 );
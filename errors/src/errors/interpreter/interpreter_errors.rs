@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// InterpreterError enum that represents all the errors for the `leo-interpreter` crate.
+    InterpreterError,
+    code_mask: 9000i32,
+    code_prefix: "INT",
+
+    /// For when the program does not contain the function to run.
+    @backtraced
+    unknown_function {
+        args: (function: impl Display, program: impl Display),
+        msg: format!("Function `{function}` does not exist in program `{program}`."),
+        help: None,
+    }
+
+    /// For when the number of inputs does not match the function signature.
+    @backtraced
+    incorrect_number_of_inputs {
+        args: (function: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("Function `{function}` expects `{expected}` inputs, but `{received}` were provided."),
+        help: None,
+    }
+
+    /// For when an input cannot be parsed as a value of the expected type.
+    @backtraced
+    invalid_input {
+        args: (input: impl Display, type_: impl Display),
+        msg: format!("Failed to parse the input `{input}` as a value of type `{type_}`."),
+        help: Some("Inputs are written as Aleo values, e.g. `1u32`, `true`, or `{ x: 1u8, y: 2u8 }`.".to_string()),
+    }
+
+    /// For when the interpreter encounters an operation that it cannot evaluate.
+    @formatted
+    unsupported_operation {
+        args: (operation: impl Display),
+        msg: format!("The interpreter cannot evaluate `{operation}`."),
+        help: Some("Associated constants, affine `group` literals, and external calls are only supported by `leo run`.".to_string()),
+    }
+
+    /// For when an `assert` statement fails.
+    @formatted
+    assertion_failed {
+        args: (condition: impl Display),
+        msg: format!("Assertion `{condition}` failed."),
+        help: None,
+    }
+
+    /// For when an `assert_eq` statement fails.
+    @formatted
+    assert_eq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("Assertion failed: `{left}` is not equal to `{right}`."),
+        help: None,
+    }
+
+    /// For when an `assert_neq` statement fails.
+    @formatted
+    assert_neq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("Assertion failed: `{left}` is equal to `{right}`."),
+        help: None,
+    }
+
+    /// For when a mapping does not contain the key being read.
+    @formatted
+    mapping_key_not_found {
+        args: (mapping: impl Display, key: impl Display),
+        msg: format!("The key `{key}` is not present in the mapping `{mapping}`."),
+        help: Some("Use `Mapping::get_or_init` to provide a default value.".to_string()),
+    }
+
+    /// For when an operation halts, e.g. a field division by zero.
+    @formatted
+    operation_halted {
+        args: (operation: impl Display, reason: impl Display),
+        msg: format!("The operation `{operation}` halted: {reason}."),
+        help: None,
+    }
+
+    /// For when an integer operation overflows.
+    @formatted
+    integer_overflow {
+        args: (operation: impl Display),
+        msg: format!("The operation `{operation}` overflows."),
+        help: Some("Use the wrapping variant of the operation, e.g. `add_wrapped`, if the result is meant to wrap around.".to_string()),
+    }
+);
@@ -15,7 +15,9 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Contains the ASG error definitions.
-use crate::LeoMessageCode;
+use crate::{Backtraced, LeoMessageCode};
+
+use leo_span::Span;
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -33,6 +35,10 @@ pub use self::compiler::*;
 pub mod flattener;
 pub use self::flattener::*;
 
+/// Contains the Interpreter error definitions.
+pub mod interpreter;
+pub use self::interpreter::*;
+
 /// Contains the Input error definitions.
 pub mod input;
 pub use self::input::*;
@@ -77,6 +83,9 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents an Interpreter Error in a Leo Error.
+    #[error(transparent)]
+    InterpreterError(#[from] InterpreterError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -100,6 +109,7 @@ impl LeoError {
             PackageError(error) => error.error_code(),
             TypeCheckerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            InterpreterError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
@@ -118,10 +128,65 @@ impl LeoError {
             PackageError(error) => error.exit_code(),
             TypeCheckerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            InterpreterError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
     }
+
+    /// Returns the span the error points to, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.span(),
+            CompilerError(error) => error.span(),
+            CliError(error) => error.span(),
+            InputError(error) => error.span(),
+            ParserError(error) => error.span(),
+            PackageError(error) => error.span(),
+            TypeCheckerError(error) => error.span(),
+            FlattenError(error) => error.span(),
+            InterpreterError(error) => error.span(),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
+
+    /// Returns the secondary spans of the error, each with a note.
+    pub fn labels(&self) -> &[(Span, String)] {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.labels(),
+            CompilerError(error) => error.labels(),
+            CliError(error) => error.labels(),
+            InputError(error) => error.labels(),
+            ParserError(error) => error.labels(),
+            PackageError(error) => error.labels(),
+            TypeCheckerError(error) => error.labels(),
+            FlattenError(error) => error.labels(),
+            InterpreterError(error) => error.labels(),
+            LastErrorCode(_) | Anyhow(_) => &[],
+        }
+    }
+
+    /// Returns the contents of the error, i.e. its text, help, and code, if it has them.
+    pub fn backtraced(&self) -> Option<&Backtraced> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => Some(error.backtraced()),
+            CompilerError(error) => Some(error.backtraced()),
+            CliError(error) => Some(error.backtraced()),
+            InputError(error) => Some(error.backtraced()),
+            ParserError(error) => Some(error.backtraced()),
+            PackageError(error) => Some(error.backtraced()),
+            TypeCheckerError(error) => Some(error.backtraced()),
+            FlattenError(error) => Some(error.backtraced()),
+            InterpreterError(error) => Some(error.backtraced()),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
 }
 
 /// A global result type for all Leo crates, that defaults the errors to be a LeoError.
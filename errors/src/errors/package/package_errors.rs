@@ -300,9 +300,33 @@ create_messages!(
     }
 
     @backtraced
-    source_directory_can_contain_only_one_file {
+    source_directory_must_contain_main_file {
         args: (),
-        msg: "The `src/` directory can contain only one file and must be named `main.leo`.".to_string(),
+        msg: "The `src/` directory must contain a `main.leo` file.".to_string(),
+        help: Some("The other files in the `src/` directory are modules, declared by `mod foo;` in `main.leo`.".to_string()),
+    }
+
+    /// For when creating the tests directory failed.
+    @backtraced
+    failed_to_create_tests_directory {
+        args: (error: impl ErrorArg),
+        msg: format!("failed creating tests directory {error}"),
+        help: None,
+    }
+
+    /// For when reading the tests directory failed.
+    @backtraced
+    failed_to_read_tests_directory {
+        args: (error: impl ErrorArg),
+        msg: format!("failed reading tests directory {error}"),
+        help: Some("Add `@test` functions to a `.leo` file in the `tests/` directory.".to_string()),
+    }
+
+    /// For when the test file has an IO error.
+    @backtraced
+    io_error_test_file {
+        args: (error: impl ErrorArg),
+        msg: format!("IO error test file from the provided file path - {error}"),
         help: None,
     }
 );
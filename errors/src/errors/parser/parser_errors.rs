@@ -218,7 +218,7 @@ create_messages!(
     leo_imports_only {
         args: (),
         msg: "Invalid import call to non-leo file.",
-        help: Some("Only imports of Leo `.leo` files are currently supported.".to_string()),
+        help: Some("Only imports of Leo `.leo` files and Aleo `.aleo` files are currently supported.".to_string()),
     }
 
     @formatted
@@ -283,4 +283,18 @@ create_messages!(
         msg: format!("`console` statements are not yet supported."),
         help: Some("Consider using `assert`, `assert_eq`, or `assert_neq` instead.".to_string()),
     }
+
+    @formatted
+    array_must_have_at_least_one_element {
+        args: (),
+        msg: format!("An array expression must have at least one element."),
+        help: None,
+    }
+
+    @backtraced
+    formatter_changed_tokens {
+        args: (),
+        msg: "The formatter changed the tokens of the program.",
+        help: Some("This is a bug in the formatter. Please report it, along with the file that was being formatted.".to_string()),
+    }
 );
@@ -427,10 +427,10 @@ create_messages!(
     }
 
     @formatted
-    loop_bound_must_be_a_literal {
+    loop_bound_must_be_constant {
         args: (),
-        msg: format!("Loop bound must be a literal."),
-        help: None,
+        msg: format!("Loop bound must be a constant expression."),
+        help: Some("Constant expressions are built from literals, constants, and loop variables.".to_string()),
     }
 
     @formatted
@@ -441,9 +441,12 @@ create_messages!(
     }
 
     @formatted
-    imported_program_cannot_import_program {
-        args: (),
-        msg: format!("An imported program cannot import another program."),
+    cyclic_import_dependency {
+        args: (path: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic dependency between imported programs: {path_string}")
+        },
         help: None,
     }
 
@@ -508,7 +511,7 @@ create_messages!(
     @formatted
     expression_statement_must_be_function_call {
         args: (),
-        msg: format!("An expression statement must be a function call."),
+        msg: format!("An expression statement must be a function call or a mapping operation."),
         help: None,
     }
 
@@ -573,4 +576,123 @@ create_messages!(
         msg: format!("Cannot call an external `inline` function."),
         help: None,
     }
+
+    @formatted
+    array_element_cannot_be {
+        args: (type_: impl Display),
+        msg: format!("An array cannot have a {type_} as an element type."),
+        help: None,
+    }
+
+    @formatted
+    invalid_array_length {
+        args: (length: impl Display, max: impl Display),
+        msg: format!("An array cannot have `{length}` elements. The length of an array must be between 1 and {max}."),
+        help: None,
+    }
+
+    @formatted
+    array_index_out_of_range {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("Array index `{index}` out of range for an array with length `{length}`"),
+        help: None,
+    }
+
+    @formatted
+    const_value_must_be_constant {
+        args: (name: impl Display),
+        msg: format!("The value of the constant `{name}` must be a constant expression."),
+        help: Some("Constant expressions are built from literals, constants, and loop variables.".to_string()),
+    }
+
+    @formatted
+    mapping_operation_outside_finalize {
+        args: (operation: impl Display),
+        msg: format!("`Mapping::{operation}` must be inside a finalize block."),
+        help: None,
+    }
+
+    @formatted
+    mapping_update_in_conditional {
+        args: (operation: impl Display),
+        msg: format!("`Mapping::{operation}` cannot be called inside a conditional statement or expression."),
+        help: Some("Compute the new value with a ternary expression and update the mapping unconditionally.".to_string()),
+    }
+
+    @formatted
+    invalid_test_function {
+        args: (),
+        msg: "Only a `function` without inputs can be annotated with `@test`.".to_string(),
+        help: Some("Test functions are run by `leo test`, and can call the program's transitions and inline functions.".to_string()),
+    }
+
+    @formatted
+    unknown_external_sym {
+        args: (kind: impl Display, program: impl Display, sym: impl Display),
+        msg: format!("The program `{program}.aleo` does not declare a {kind} `{sym}`."),
+        help: None,
+    }
+
+    @formatted
+    empty_enum {
+        args: (name: impl Display),
+        msg: format!("The enum `{name}` must have at least one variant."),
+        help: None,
+    }
+
+    @formatted
+    too_many_enum_variants {
+        args: (name: impl Display, max: impl Display),
+        msg: format!("The enum `{name}` cannot have more than {max} variants."),
+        help: None,
+    }
+
+    @formatted
+    duplicate_enum_variant {
+        args: (name: impl Display),
+        msg: format!("Enum {name} defined with more than one variant with the same name."),
+        help: None,
+    }
+
+    @formatted
+    unknown_enum_variant {
+        args: (name: impl Display, variant: impl Display),
+        msg: format!("The enum `{name}` does not have a variant `{variant}`."),
+        help: None,
+    }
+
+    @formatted
+    incorrect_num_enum_payloads {
+        args: (variant: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("The variant `{variant}` carries {expected} values, found {received}."),
+        help: None,
+    }
+
+    @formatted
+    invalid_match_value {
+        args: (type_: impl Display),
+        msg: format!("A `match` cannot match a value of type `{type_}`."),
+        help: Some("Only integers, booleans, and enum values can be matched.".to_string()),
+    }
+
+    @formatted
+    unreachable_match_arm {
+        args: (),
+        msg: "This arm is unreachable, since the patterns of the previous arms cover its pattern.".to_string(),
+        help: None,
+    }
+
+    @formatted
+    non_exhaustive_match {
+        args: (missing: impl Display),
+        msg: format!("The `match` does not cover {missing}."),
+        help: Some("Add an arm for each of the missing patterns, or a wildcard `_` arm.".to_string()),
+    }
+
+    @formatted
+    mapping_get_in_conditional {
+        args: (),
+        msg: "`Mapping::get` cannot be called inside a conditional statement or expression.".to_string(),
+        help: Some("`Mapping::get` halts if the key is missing, so call it unconditionally, or use `Mapping::contains` to check for the key first.".to_string()),
+    }
 );
@@ -16,12 +16,18 @@
 
 /// The LeoError type that contains all sub error types.
 /// This allows a unified error type throughout the Leo crates.
-use crate::LeoMessageCode;
+use crate::{Backtraced, LeoMessageCode};
+
+use leo_span::Span;
 
 /// Contains the Parser warning definitions.
 pub mod parser;
 pub use self::parser::*;
 
+/// Contains the Taint Analysis warning definitions.
+pub mod taint_analysis;
+pub use self::taint_analysis::*;
+
 /// The LeoWarning type that contains all sub error types.
 /// This allows a unified error type throughout the Leo crates.
 #[derive(Debug, Error)]
@@ -29,6 +35,9 @@ pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Taint Analysis Warning in a Leo Warning.
+    #[error(transparent)]
+    TaintAnalysisWarning(#[from] TaintAnalysisWarning),
 }
 
 impl LeoWarning {
@@ -38,6 +47,37 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            TaintAnalysisWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the span the warning points to, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.span(),
+            TaintAnalysisWarning(warning) => warning.span(),
+        }
+    }
+
+    /// Returns the contents of the warning, i.e. its text, help, and code.
+    pub fn backtraced(&self) -> &Backtraced {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.backtraced(),
+            TaintAnalysisWarning(warning) => warning.backtraced(),
+        }
+    }
+
+    /// Returns the secondary spans of the warning, each with a note.
+    pub fn labels(&self) -> &[(Span, String)] {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.labels(),
+            TaintAnalysisWarning(warning) => warning.labels(),
         }
     }
 }
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt::Display;
+
+create_messages!(
+    /// TaintAnalysisWarning enum that represents all the warnings for the taint analysis in the `leo-passes` crate.
+    TaintAnalysisWarning,
+    code_mask: 1000i32,
+    code_prefix: "TAI",
+
+    /// For when a value derived from a private input is returned as a public output of a transition.
+    @formatted
+    private_value_in_public_output {
+        args: (source: impl Display),
+        msg: format!("A value derived from the private input `{source}` is returned as a public output."),
+        help: Some("Make the output private, or make the input public if it is not secret.".to_string()),
+    }
+
+    /// For when a value derived from a private input is passed to a finalize block, whose inputs are public.
+    @formatted
+    private_value_in_finalize_argument {
+        args: (source: impl Display),
+        msg: format!("A value derived from the private input `{source}` is passed to `finalize`, which makes it public."),
+        help: Some("Finalize arguments are always public. Pass a commitment to the value instead, e.g. `BHP256::commit(value, salt)`.".to_string()),
+    }
+
+    /// For when a value derived from a private input is used as a key or value of a mapping.
+    @formatted
+    private_value_in_mapping {
+        args: (source: impl Display, mapping: impl Display),
+        msg: format!("A value derived from the private input `{source}` is stored in the mapping `{mapping}`, which is public."),
+        help: Some("Mapping keys and values are public. Store a commitment to the value instead.".to_string()),
+    }
+);
@@ -14,27 +14,27 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::commands::ALEO_CLI_COMMAND;
+use crate::commands::{Aleo, Network};
 use crate::{commands::Command, context::Context};
 
-use leo_ast::Struct;
-use leo_compiler::{Compiler, InputAst, OutputOptions};
-use leo_errors::{CliError, CompilerError, PackageError, Result};
-use leo_package::source::SourceDirectory;
+use leo_ast::{Enum, Struct};
+use leo_compiler::{AstFormat, Compiler, InputAst, OutputOptions};
+use leo_errors::{CliError, CompilerError, LeoError, PackageError, Result};
+use leo_package::source::{MainFile, SourceDirectory};
 use leo_package::{inputs::InputFile, outputs::OutputsDirectory};
 use leo_span::symbol::with_session_globals;
 
-use aleo::commands::Build as AleoBuild;
-
 use clap::StructOpt;
 use indexmap::IndexMap;
-use snarkvm::prelude::{ProgramID, Testnet3};
+use snarkvm::package::Package;
+use snarkvm::prelude::ProgramID;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use leo_errors::emitter::Handler;
+use leo_errors::emitter::{Handler, JsonEmitter};
 use leo_package::build::BuildDirectory;
-use leo_package::imports::ImportsDirectory;
+use leo_package::imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME};
 use leo_span::Symbol;
 use tracing::span::Span;
 
@@ -50,36 +50,123 @@ pub struct BuildOptions {
     pub enable_all_ast_snapshots: bool,
     #[structopt(long, help = "Writes Input AST snapshot of the initial parse.")]
     pub enable_initial_input_ast_snapshot: bool,
-    #[structopt(long, help = "Writes AST snapshot of the initial parse.")]
+    #[structopt(
+        long,
+        value_delimiter = ',',
+        help = "Writes AST snapshots after the given compiler stages, e.g. `--emit parse,ssa`."
+    )]
+    pub emit: Vec<String>,
+    // The flags that wrote a single AST snapshot before `--emit`, kept as hidden aliases of it.
+    #[structopt(long, hide = true, help = "Alias of `--emit parse`.")]
     pub enable_initial_ast_snapshot: bool,
-    #[structopt(long, help = "Writes AST snapshot of the unrolled AST.")]
+    #[structopt(long, hide = true, help = "Alias of `--emit unroll`.")]
     pub enable_unrolled_ast_snapshot: bool,
-    #[structopt(long, help = "Writes AST snapshot of the SSA AST.")]
+    #[structopt(long, hide = true, help = "Alias of `--emit ssa`.")]
     pub enable_ssa_ast_snapshot: bool,
-    #[structopt(long, help = "Writes AST snapshot of the flattened AST.")]
+    #[structopt(long, hide = true, help = "Alias of `--emit const_fold`.")]
+    pub enable_constant_folded_ast_snapshot: bool,
+    #[structopt(long, hide = true, help = "Alias of `--emit flatten`.")]
     pub enable_flattened_ast_snapshot: bool,
-    #[structopt(long, help = "Writes AST snapshot of the inlined AST.")]
+    #[structopt(long, hide = true, help = "Alias of `--emit inline`.")]
     pub enable_inlined_ast_snapshot: bool,
+    #[structopt(long, hide = true, help = "Alias of `--emit cse`.")]
+    pub enable_cse_ast_snapshot: bool,
+    #[structopt(long, hide = true, help = "Alias of `--emit dce`.")]
+    pub enable_dce_ast_snapshot: bool,
+    #[structopt(
+        long,
+        default_value = "json",
+        help = "The format of AST snapshots, either `json` or `leo` for Leo source code."
+    )]
+    pub ast_format: AstFormat,
+    #[structopt(
+        long,
+        help = "Stops compilation after the given compiler stage, e.g. `--stop-after ssa`."
+    )]
+    pub stop_after: Option<String>,
+    #[structopt(long, help = "Prints the wall time of each compiler pass.")]
+    pub time_passes: bool,
+    #[structopt(
+        long,
+        help = "Annotates the Aleo instructions with the Leo source lines they were generated from."
+    )]
+    pub annotate: bool,
+    #[structopt(
+        long,
+        help = "Prints the instruction counts and estimated constraints of each transition, closure and finalize block."
+    )]
+    pub stats: bool,
+    #[structopt(
+        long,
+        default_value = "human",
+        help = "The format of errors and warnings, either `human` or `json` for one JSON object per line."
+    )]
+    pub message_format: MessageFormat,
+}
+
+/// The format in which the compiler prints errors and warnings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Human-readable messages on the standard error.
+    #[default]
+    Human,
+    /// One JSON object per message on the standard error.
+    Json,
+}
+
+impl MessageFormat {
+    /// Returns a handler that prints errors and warnings in this format.
+    pub fn handler(self) -> Handler {
+        match self {
+            Self::Human => Handler::default(),
+            Self::Json => Handler::new(Box::new(JsonEmitter::new())),
+        }
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown message format `{s}`, expected `human` or `json`")),
+        }
+    }
 }
 
 impl From<BuildOptions> for OutputOptions {
     fn from(options: BuildOptions) -> Self {
+        let aliases = [
+            (options.enable_initial_ast_snapshot, Compiler::PARSE),
+            (options.enable_unrolled_ast_snapshot, "unroll"),
+            (options.enable_ssa_ast_snapshot, "ssa"),
+            (options.enable_constant_folded_ast_snapshot, "const_fold"),
+            (options.enable_flattened_ast_snapshot, "flatten"),
+            (options.enable_inlined_ast_snapshot, "inline"),
+            (options.enable_cse_ast_snapshot, "cse"),
+            (options.enable_dce_ast_snapshot, "dce"),
+        ];
+        let mut emit = options.emit;
+        emit.extend(
+            aliases
+                .into_iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, stage)| stage.to_string()),
+        );
+
         let mut out_options = Self {
             spans_enabled: options.enable_spans,
             initial_input_ast: options.enable_initial_input_ast_snapshot,
-            initial_ast: options.enable_initial_ast_snapshot,
-            unrolled_ast: options.enable_unrolled_ast_snapshot,
-            ssa_ast: options.enable_ssa_ast_snapshot,
-            flattened_ast: options.enable_flattened_ast_snapshot,
-            inlined_ast: options.enable_inlined_ast_snapshot,
+            emit,
+            ast_format: options.ast_format,
+            stop_after: options.stop_after,
+            time_passes: options.time_passes,
         };
         if options.enable_all_ast_snapshots {
             out_options.initial_input_ast = true;
-            out_options.initial_ast = true;
-            out_options.unrolled_ast = true;
-            out_options.ssa_ast = true;
-            out_options.flattened_ast = true;
-            out_options.inlined_ast = true;
+            out_options.emit = Compiler::STAGES.iter().map(|stage| stage.to_string()).collect();
         }
 
         out_options
@@ -95,7 +182,7 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>);
+    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>, IndexMap<Symbol, Enum>);
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
@@ -120,7 +207,7 @@ impl Command for Build {
         let build_directory = BuildDirectory::open(&package_path)?;
 
         // Initialize error handler
-        let handler = Handler::default();
+        let handler = self.compiler_options.message_format.handler();
 
         // Fetch paths to all .leo files in the source directory.
         let source_files = SourceDirectory::files(&package_path)?;
@@ -128,13 +215,18 @@ impl Command for Build {
         // Check the source files.
         SourceDirectory::check_files(&source_files)?;
 
-        // Store all struct declarations made in the source files.
-        let mut structs = IndexMap::new();
-
-        // Compile all .leo files into .aleo files.
-        for file_path in source_files.into_iter() {
-            structs.extend(compile_leo_file(
-                file_path,
+        // Compile the main file into a .aleo file.
+        // Note that the other source files are modules, whose items are compiled as part of the main program.
+        // Returns the struct and enum declarations, and the programs imported by the main file, directly or indirectly.
+        let CompiledFile {
+            mut structs,
+            mut enums,
+            imports,
+            stubs,
+        } = emit_error(
+            &handler,
+            compile_leo_file(
+                package_path.join(MainFile::filename()),
                 &package_path,
                 program_id,
                 &outputs_directory,
@@ -142,28 +234,44 @@ impl Command for Build {
                 &handler,
                 self.compiler_options.clone(),
                 false,
-            )?);
-        }
+            ),
+        )?;
 
-        if !ImportsDirectory::is_empty(&package_path)? {
+        if !imports.is_empty() || !stubs.is_empty() {
             // Create Aleo build/imports/ directory.
             let build_imports_directory = ImportsDirectory::create(&build_directory)?;
 
-            // Fetch paths to all .leo files in the imports directory.
-            let import_files = ImportsDirectory::files(&package_path)?;
-
-            // Compile all .leo files into .aleo files.
-            for file_path in import_files.into_iter() {
-                structs.extend(compile_leo_file(
-                    file_path,
-                    &package_path,
-                    program_id,
-                    &outputs_directory,
-                    &build_imports_directory,
+            // Copy each program imported from Aleo instructions, which is already deployed, so it is not compiled.
+            for stub in stubs {
+                let file_name = format!("{stub}.aleo");
+                let file_path = package_path.join(IMPORTS_DIRECTORY_NAME).join(&file_name);
+                std::fs::copy(&file_path, build_imports_directory.join(&file_name))
+                    .map_err(|e| CliError::failed_to_copy_import(&file_path, e))?;
+            }
+
+            // Compile each imported program into a .aleo file once.
+            // Note that each program is compiled after the programs it imports.
+            for import in imports {
+                let file_path = package_path.join(IMPORTS_DIRECTORY_NAME).join(format!("{import}.leo"));
+                let CompiledFile {
+                    structs: import_structs,
+                    enums: import_enums,
+                    ..
+                } = emit_error(
                     &handler,
-                    self.compiler_options.clone(),
-                    true,
-                )?);
+                    compile_leo_file(
+                        file_path,
+                        &package_path,
+                        program_id,
+                        &outputs_directory,
+                        &build_imports_directory,
+                        &handler,
+                        self.compiler_options.clone(),
+                        true,
+                    ),
+                )?;
+                structs.extend(import_structs);
+                enums.extend(import_enums);
             }
         }
 
@@ -184,40 +292,71 @@ impl Command for Build {
             None
         };
 
+        // Stopping after a compiler stage leaves no Aleo instructions to build.
+        if self.compiler_options.stop_after.is_some() {
+            return Ok((input_ast, structs, enums));
+        }
+
         // Unset the Leo panic hook.
         let _ = std::panic::take_hook();
 
-        // Change the cwd to the build directory to compile aleo files.
-        std::env::set_current_dir(&build_directory)
-            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
-
-        // Call the `aleo build` command with the appropriate from the Aleo SDK.
-        let mut args = vec![ALEO_CLI_COMMAND];
-        if self.compiler_options.offline {
-            args.push("--offline");
-        }
-        let command = AleoBuild::try_parse_from(&args).map_err(CliError::failed_to_execute_aleo_build)?;
-        let result = command.parse().map_err(CliError::failed_to_execute_aleo_build)?;
+        // Build the Aleo package in the build directory, which synthesizes the keys of its functions.
+        // Note that the package is built locally, so no endpoint is given.
+        let package = Package::<Network>::open(&build_directory).map_err(CliError::failed_to_execute_aleo_build)?;
+        package
+            .build::<Aleo>(None)
+            .map_err(CliError::failed_to_execute_aleo_build)?;
 
         // Log the result of the build
-        tracing::info!("{}", result);
+        tracing::info!(
+            "✅ Built '{}' (in \"{}\")",
+            package.program_id(),
+            build_directory.display()
+        );
 
-        Ok((input_ast, structs))
+        Ok((input_ast, structs, enums))
     }
 }
 
-/// Compiles a Leo file in the `src/` directory.
+/// Emits an error returned by the compiler through the `handler`, so that it is printed in the selected message format.
+/// Errors that were already emitted, and errors that do not come from Leo, are returned as they are.
+fn emit_error<T>(handler: &Handler, result: Result<T>) -> Result<T> {
+    result.map_err(|err| match err.backtraced() {
+        Some(_) => {
+            let code = err.exit_code();
+            handler.emit_err(err);
+            LeoError::LastErrorCode(code)
+        }
+        None => err,
+    })
+}
+
+/// The declarations and imports of a compiled Leo file.
+#[derive(Default)]
+struct CompiledFile {
+    /// The structs declared in the file.
+    structs: IndexMap<Symbol, Struct>,
+    /// The enums declared in the file.
+    enums: IndexMap<Symbol, Enum>,
+    /// The programs imported by the file, directly or indirectly, in dependency order.
+    imports: Vec<Symbol>,
+    /// The programs imported from Aleo instructions, which are not compiled.
+    stubs: Vec<Symbol>,
+}
+
+/// Compiles a Leo file in the `src/` or `imports/` directory.
+/// Returns the declarations of the file and the programs it imports.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
     file_path: PathBuf,
-    _package_path: &Path,
-    program_id: &ProgramID<Testnet3>,
+    package_path: &Path,
+    program_id: &ProgramID<Network>,
     outputs: &Path,
     build: &Path,
     handler: &Handler,
     options: BuildOptions,
     is_import: bool,
-) -> Result<IndexMap<Symbol, Struct>> {
+) -> Result<CompiledFile> {
     // Construct the Leo file name with extension `foo.leo`.
     let file_name = file_path
         .file_name()
@@ -241,6 +380,10 @@ fn compile_leo_file(
         false => format!("main.{}", program_id.network()),
     });
 
+    let time_passes = options.time_passes;
+    let annotate = options.annotate;
+    let stats = options.stats;
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
         program_name,
@@ -250,9 +393,54 @@ fn compile_leo_file(
         outputs.to_path_buf(),
         Some(options.into()),
     );
+    compiler.import_directory = package_path.join(IMPORTS_DIRECTORY_NAME);
 
     // Compile the Leo program into Aleo instructions.
-    let (symbol_table, instructions) = compiler.compile_and_generate_instructions()?;
+    let compiled = compiler.compile_and_generate_instructions()?;
+
+    // Print the time spent in each compiler pass.
+    if time_passes {
+        for (pass, time) in compiler.pass_timings() {
+            tracing::info!("{pass:>12}: {time:?}");
+        }
+    }
+
+    // If compilation was stopped after a stage, there are no instructions to write.
+    let (symbol_table, instructions, mut source_map, program_stats) = match compiled {
+        Some(compiled) => compiled,
+        None => {
+            tracing::info!("Stopped compiling '{}' after the requested stage", file_name);
+            return Ok(CompiledFile::default());
+        }
+    };
+
+    // Write the source map, with the file names relative to the package.
+    source_map.strip_prefix(package_path);
+    let mut source_map_path = aleo_file_path.clone().into_os_string();
+    source_map_path.push(".map.json");
+    let source_map_json = serde_json::to_string_pretty(&source_map)
+        .map_err(|e| CliError::failed_to_write_source_map(&source_map_path, e))?;
+    std::fs::write(&source_map_path, source_map_json)
+        .map_err(|e| CliError::failed_to_write_source_map(&source_map_path, e))?;
+
+    // Print the cost of the program and write it next to the instructions, if requested.
+    if stats {
+        for line in program_stats.to_string().lines() {
+            tracing::info!("{line}");
+        }
+
+        let mut stats_path = aleo_file_path.clone().into_os_string();
+        stats_path.push(".stats.json");
+        let stats_json = serde_json::to_string_pretty(&program_stats)
+            .map_err(|e| CliError::failed_to_write_stats(&stats_path, e))?;
+        std::fs::write(&stats_path, stats_json).map_err(|e| CliError::failed_to_write_stats(&stats_path, e))?;
+    }
+
+    // Annotate the instructions with their Leo source lines, if requested.
+    let instructions = match annotate {
+        true => source_map.annotate(&instructions),
+        false => instructions,
+    };
 
     // Write the instructions.
     std::fs::File::create(&aleo_file_path)
@@ -266,5 +454,14 @@ fn compile_leo_file(
     // Log the build as successful.
     tracing::info!("Compiled '{}' into Aleo instructions", file_name,);
 
-    Ok(symbol_table.structs)
+    // Note that import resolution orders the imports so that each program precedes the programs that import it.
+    let imports = compiler.ast.ast.imports.keys().copied().collect();
+    let stubs = compiler.ast.ast.stubs.keys().copied().collect();
+
+    Ok(CompiledFile {
+        structs: symbol_table.structs,
+        enums: symbol_table.enums,
+        imports,
+        stubs,
+    })
 }
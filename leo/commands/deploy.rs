@@ -14,17 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::commands::ALEO_CLI_COMMAND;
+use crate::commands::{Aleo, Network};
 use crate::{commands::Command, context::Context};
 
-use leo_errors::{CliError, PackageError, Result};
+use leo_errors::{CliError, Result};
 use leo_package::build::BuildDirectory;
 
-use aleo::commands::Deploy as AleoDeploy;
-
 use clap::StructOpt;
+use snarkvm::package::Package;
 use tracing::span::Span;
 
+/// The endpoint to which deployments are sent.
+const DEPLOY_ENDPOINT: &str = "https://www.aleo.network/testnet3/deploy";
+
 /// Deploys an Aleo program.
 #[derive(StructOpt, Debug)]
 pub struct Deploy;
@@ -46,20 +48,22 @@ impl Command for Deploy {
         let path = context.dir()?;
         let build_directory = BuildDirectory::open(&path).map_err(|_| CliError::needs_leo_build())?;
 
-        // Change the cwd to the Leo build/ directory to deploy aleo files.
-        std::env::set_current_dir(&build_directory)
-            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
-
         // Unset the Leo panic hook.
         let _ = std::panic::take_hook();
 
-        // Call the `aleo node` command from the Aleo SDK.
+        // Deploy the Aleo package in the build directory.
         println!();
-        let command = AleoDeploy::try_parse_from([ALEO_CLI_COMMAND]).map_err(CliError::failed_to_parse_aleo_node)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_aleo_node)?;
-
-        // Log the output of the `aleo node` command.
-        tracing::info!("{}", res);
+        let package = Package::<Network>::open(&build_directory).map_err(CliError::failed_to_execute_aleo_deploy)?;
+        package
+            .deploy::<Aleo>(Some(DEPLOY_ENDPOINT.to_string()))
+            .map_err(CliError::failed_to_execute_aleo_deploy)?;
+
+        // Log the result of the deployment.
+        tracing::info!(
+            "✅ Deployed '{}' (in \"{}\")",
+            package.program_id(),
+            build_directory.display()
+        );
 
         Ok(())
     }
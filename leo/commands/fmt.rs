@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_errors::{emitter::Handler, CliError, CompilerError, Result};
+use leo_package::{
+    source::{SourceDirectory, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    tests::{TestsDirectory, TESTS_DIRECTORY_NAME},
+};
+use leo_span::symbol::with_session_globals;
+
+use clap::StructOpt;
+use colored::Colorize;
+use std::fs;
+use tracing::span::Span;
+
+/// Format the Leo files of a package in the canonical style
+#[derive(StructOpt, Debug)]
+pub struct Fmt {
+    #[structopt(
+        long,
+        help = "Check that the files are formatted without changing them, failing if any are not."
+    )]
+    pub(crate) check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+
+        // Collect the Leo files in the source and tests directories.
+        let mut file_paths = SourceDirectory::files(&package_path)?;
+        if package_path.join(TESTS_DIRECTORY_NAME).is_dir() {
+            file_paths.extend(TestsDirectory::files(&package_path)?);
+        }
+
+        let handler = Handler::default();
+        let mut unformatted = 0;
+        for file_path in file_paths {
+            let sf = with_session_globals(|s| s.source_map.load_file(&file_path))
+                .map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+            // The files in the source directory other than the main file are modules.
+            let is_module =
+                file_path.starts_with(package_path.join(SOURCE_DIRECTORY_NAME)) && !file_path.ends_with(MAIN_FILENAME);
+            let formatted = match is_module {
+                true => leo_parser::format_module(&handler, &sf.src, sf.start_pos)?,
+                false => leo_parser::format(&handler, &sf.src, sf.start_pos)?,
+            };
+            if formatted == *sf.src {
+                continue;
+            }
+
+            let relative_path = file_path.strip_prefix(&package_path).unwrap_or(&file_path);
+            match self.check {
+                true => {
+                    tracing::info!("{} is not formatted", relative_path.display().to_string().bold());
+                    unformatted += 1;
+                }
+                false => {
+                    fs::write(&file_path, formatted)
+                        .map_err(|e| CliError::failed_to_write_formatted_file(&file_path, e))?;
+                    tracing::info!("Formatted {}", relative_path.display().to_string().dimmed());
+                }
+            }
+        }
+
+        match unformatted {
+            0 => Ok(()),
+            count => Err(CliError::files_not_formatted(count).into()),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_errors::{CliError, Result};
+use leo_lsp::Server;
+
+use clap::StructOpt;
+use std::io;
+use tracing::span::Span;
+
+/// Run the Leo language server, which communicates over stdin and stdout
+#[derive(StructOpt, Debug)]
+pub struct Lsp {}
+
+impl Command for Lsp {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        // Nothing else may be written to stdout, since the client reads its messages from there.
+        let stdin = io::stdin();
+        Ok(Server::new(stdin.lock(), io::stdout())
+            .run()
+            .map_err(CliError::cli_io_error)?)
+    }
+}
@@ -24,6 +24,12 @@ pub use clean::Clean;
 pub mod deploy;
 pub use deploy::Deploy;
 
+pub mod fmt;
+pub use fmt::Fmt;
+
+pub mod lsp;
+pub use lsp::Lsp;
+
 pub mod new;
 pub use new::New;
 
@@ -33,13 +39,16 @@ pub use new::New;
 pub mod run;
 pub use run::Run;
 
+pub mod test;
+pub use test::Test;
+
 use crate::context::*;
 use leo_errors::Result;
 
 use tracing::span::Span;
 
 pub(crate) type Network = snarkvm::prelude::Testnet3;
-pub(crate) const ALEO_CLI_COMMAND: &str = "aleo";
+pub(crate) type Aleo = snarkvm::circuit::AleoV0;
 
 /// Base trait for the Leo CLI, see methods and their documentation for details.
 pub trait Command {
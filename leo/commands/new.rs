@@ -15,18 +15,16 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::commands::Network;
-use crate::{
-    commands::{Command, ALEO_CLI_COMMAND},
-    context::Context,
-};
+use crate::{commands::Command, context::Context};
 use leo_errors::{CliError, PackageError, Result};
 use leo_package::build::BUILD_DIRECTORY_NAME;
 use leo_package::package::Package;
-use snarkvm::file::AleoFile;
-
-use aleo::commands::New as AleoNew;
+use snarkvm::file::{AleoFile, Manifest};
+use snarkvm::package::Package as AleoPackage;
+use snarkvm::prelude::{Address, PrivateKey, ProgramID};
 
 use clap::StructOpt;
+use std::{path::Path, str::FromStr};
 use tracing::span::Span;
 
 /// Create new Leo project
@@ -49,21 +47,27 @@ impl Command for New {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        // Call the `aleo new` command from the Aleo SDK.
-        let command =
-            AleoNew::try_parse_from([ALEO_CLI_COMMAND, &self.name]).map_err(CliError::failed_to_parse_aleo_new)?;
-        let result = command.parse().map_err(CliError::failed_to_execute_aleo_new)?;
+        // Derive the program directory path.
+        let mut package_path = context.dir()?;
+        package_path.push(&self.name);
+
+        // Create the Aleo package, i.e. its manifest, `main.aleo` and README files.
+        let program_id = ProgramID::<Network>::from_str(&format!("{}.aleo", self.name))
+            .map_err(CliError::failed_to_execute_aleo_new)?;
+        AleoPackage::create(&package_path, &program_id).map_err(CliError::failed_to_execute_aleo_new)?;
 
         // todo: modify the readme file to recommend building with `leo build`.
 
-        // Log the output of the `aleo new` command.
-        tracing::info!("{}", result);
+        // Add a development private key to the manifest, with which `leo run` and `leo test` run the program.
+        write_development_manifest(&package_path, &program_id)?;
 
-        // Derive the program directory path.
-        let mut package_path = context.dir()?;
-        package_path.push(&self.name);
+        tracing::info!(
+            "✅ Created an Aleo program '{}' (in \"{}\")",
+            self.name,
+            package_path.display()
+        );
 
-        // Initialize the Leo package in the directory created by `aleo new`.
+        // Initialize the Leo package in the directory created for the Aleo package.
         Package::initialize(&self.name, &package_path)?;
 
         // Change the cwd to the Leo package directory to compile aleo files.
@@ -104,3 +108,28 @@ impl Command for New {
         Ok(())
     }
 }
+
+/// Rewrites the manifest `program.json` of a new package with a new development private key and its address.
+fn write_development_manifest(package_path: &Path, program_id: &ProgramID<Network>) -> Result<()> {
+    let private_key =
+        PrivateKey::<Network>::new(&mut rand::thread_rng()).map_err(CliError::failed_to_execute_aleo_new)?;
+    let address = Address::try_from(&private_key).map_err(CliError::failed_to_execute_aleo_new)?;
+
+    let manifest = format!(
+        r#"{{
+    "program": "{program_id}",
+    "version": "0.0.0",
+    "description": "",
+    "development": {{
+        "private_key": "{private_key}",
+        "address": "{address}"
+    }},
+    "license": "MIT"
+}}
+"#
+    );
+    std::fs::write(package_path.join(Manifest::<Network>::file_name()), manifest)
+        .map_err(CliError::failed_to_execute_aleo_new)?;
+
+    Ok(())
+}
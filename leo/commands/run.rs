@@ -15,17 +15,24 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::build::BuildOptions;
-use crate::commands::ALEO_CLI_COMMAND;
+use crate::commands::{Aleo, Network};
 use crate::{
     commands::{Build, Command},
     context::Context,
 };
-use leo_errors::{CliError, PackageError, Result};
-use leo_package::build::BuildDirectory;
-
-use aleo::commands::Run as AleoRun;
+use leo_compiler::Compiler;
+use leo_errors::{CliError, Result};
+use leo_interpreter::{format_value, Interpreter};
+use leo_package::{
+    build::BuildDirectory, imports::IMPORTS_DIRECTORY_NAME, inputs::InputFile, outputs::OutputsDirectory,
+    source::MainFile,
+};
 
 use clap::StructOpt;
+use indexmap::IndexMap;
+use snarkvm::package::Package;
+use snarkvm::prelude::{Address, Identifier, Value};
+use std::str::FromStr;
 use tracing::span::Span;
 
 /// Build, Prove and Run Leo program with inputs
@@ -40,6 +47,12 @@ pub struct Run {
     )]
     inputs: Vec<String>,
 
+    #[structopt(
+        long,
+        help = "Runs the program with the Leo interpreter, without building it or generating a proof."
+    )]
+    interpret: bool,
+
     #[structopt(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -53,6 +66,11 @@ impl Command for Run {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
+        // The interpreter runs the Leo program directly, so it does not need to be built.
+        if self.interpret {
+            return Ok((None, IndexMap::new(), IndexMap::new()));
+        }
+
         (Build {
             compiler_options: self.compiler_options.clone(),
         })
@@ -60,42 +78,125 @@ impl Command for Run {
     }
 
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
+        if self.interpret {
+            return self.interpret(context);
+        }
+
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
-        let mut inputs = match self.inputs.is_empty() {
+        let inputs = match self.inputs.is_empty() {
             true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
+                (Some(input_ast), circuits, enums) => input_ast.program_inputs(&self.name, circuits, enums)?,
                 _ => Vec::new(),
             },
             false => self.inputs,
         };
 
-        // Compose the `aleo run` command.
-        let mut arguments = vec![ALEO_CLI_COMMAND.to_string(), self.name];
-        arguments.append(&mut inputs);
+        // Parse the function name and the inputs as Aleo values.
+        let function = Identifier::<Network>::from_str(&self.name).map_err(CliError::failed_to_parse_aleo_run)?;
+        let inputs = inputs
+            .iter()
+            .map(|input| Value::<Network>::from_str(input))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(CliError::failed_to_parse_aleo_run)?;
 
-        // Open the Leo build/ directory
+        // Open the Aleo package in the Leo build/ directory.
         let path = context.dir()?;
         let build_directory = BuildDirectory::open(&path)?;
-
-        // Change the cwd to the Leo build/ directory to compile aleo files.
-        std::env::set_current_dir(&build_directory)
-            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
-
-        // Call the `aleo run` command from the Aleo SDK.
-        if self.compiler_options.offline {
-            arguments.push(String::from("--offline"));
-        }
+        let package = Package::<Network>::open(&build_directory).map_err(CliError::failed_to_execute_aleo_run)?;
 
         // Unset the Leo panic hook
         let _ = std::panic::take_hook();
 
+        // Run the function as the development private key.
         println!();
-        let command = AleoRun::try_parse_from(&arguments).map_err(CliError::failed_to_parse_aleo_run)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_aleo_run)?;
+        let private_key = context.development_private_key()?;
+        let (response, metrics) = package
+            .run::<Aleo, _>(&private_key, function, &inputs, &mut rand::thread_rng())
+            .map_err(CliError::failed_to_execute_aleo_run)?;
+
+        // Log the constraints of each call, followed by the outputs.
+        for metric in metrics.iter() {
+            tracing::info!(
+                "'{}/{}' - {} constraints",
+                metric.program_id,
+                metric.function_name,
+                metric.num_function_constraints
+            );
+        }
+        println!();
+        println!("Outputs:");
+        for output in response.outputs() {
+            println!("    {output}");
+        }
+
+        tracing::info!("✅ Finished '{}/{}'", package.program_id(), self.name);
 
-        // Log the output of the `aleo run` command.
-        tracing::info!("{}", res);
+        Ok(())
+    }
+}
+
+impl Run {
+    /// Runs the program with the Leo interpreter, printing its outputs and the updates to its mappings.
+    fn interpret(self, context: Context) -> Result<()> {
+        // Get the package path and the program id.
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+
+        // The caller is the address of the development private key, as in `leo run`.
+        let caller = Address::<Network>::try_from(context.development_private_key()?)
+            .map_err(CliError::failed_to_derive_caller_address)?;
+
+        // Parse and type check the main file.
+        let handler = self.compiler_options.message_format.handler();
+        let mut compiler = Compiler::new(
+            program_id.name().to_string(),
+            program_id.network().to_string(),
+            &handler,
+            package_path.join(MainFile::filename()),
+            OutputsDirectory::create(&package_path)?,
+            Some(self.compiler_options.clone().into()),
+        );
+        compiler.import_directory = package_path.join(IMPORTS_DIRECTORY_NAME);
+        compiler.parse_program()?;
+
+        // If input values are provided, then run the program with those inputs.
+        // Otherwise, use the input file.
+        if self.inputs.is_empty() {
+            let input_file_path = InputFile::new(&program_id.name().to_string()).setup_file_path(&package_path);
+            if input_file_path.exists() {
+                compiler.parse_input(input_file_path.to_path_buf())?;
+            }
+        }
+
+        // Stopping after a compiler stage leaves no program to interpret.
+        let Some((symbol_table, ..)) = compiler.front_end_passes()? else {
+            return Ok(());
+        };
+
+        let inputs = match (self.inputs.is_empty(), &compiler.input_ast) {
+            (true, Some(input_ast)) => {
+                input_ast.program_inputs(&self.name, symbol_table.structs, symbol_table.enums)?
+            }
+            _ => self.inputs,
+        };
+
+        // Run the program.
+        let mut interpreter = Interpreter::new(&compiler.ast.ast, caller.to_string())?;
+        let execution = interpreter.run(&self.name, &inputs)?;
+
+        tracing::info!("Interpreted '{}'", self.name);
+        tracing::info!("Outputs:");
+        for output in execution.outputs.iter() {
+            tracing::info!("    {}", format_value(output));
+        }
+        if !execution.updates.is_empty() {
+            tracing::info!("Mapping updates:");
+            for update in execution.updates.iter() {
+                tracing::info!("    {update}");
+            }
+        }
 
         Ok(())
     }
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::build::BuildOptions;
+use crate::commands::Network;
+use crate::{commands::Command, context::Context};
+use leo_compiler::Compiler;
+use leo_errors::{CliError, Result};
+use leo_interpreter::Interpreter;
+use leo_package::{
+    imports::IMPORTS_DIRECTORY_NAME, outputs::OutputsDirectory, source::MainFile, tests::TestsDirectory,
+};
+
+use clap::StructOpt;
+use snarkvm::prelude::Address;
+use tracing::span::Span;
+
+/// Run the `@test` functions of a Leo package with the Leo interpreter
+#[derive(StructOpt, Debug)]
+pub struct Test {
+    #[structopt(name = "FILTER", help = "Only run the tests whose names contain this string.")]
+    filter: Option<String>,
+
+    #[structopt(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Test {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path and the program id.
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+
+        // The caller is the address of the development private key, as in `leo run`.
+        let caller = Address::<Network>::try_from(context.development_private_key()?)
+            .map_err(CliError::failed_to_derive_caller_address)?;
+
+        // Parse the main file, and add the declarations of the test files to the program.
+        let handler = self.compiler_options.message_format.handler();
+        let mut compiler = Compiler::new(
+            program_id.name().to_string(),
+            program_id.network().to_string(),
+            &handler,
+            package_path.join(MainFile::filename()),
+            OutputsDirectory::create(&package_path)?,
+            Some(self.compiler_options.into()),
+        );
+        compiler.import_directory = package_path.join(IMPORTS_DIRECTORY_NAME);
+        compiler.parse_program()?;
+        for test_file_path in TestsDirectory::files(&package_path)? {
+            compiler.parse_test_file(&test_file_path)?;
+        }
+
+        // Type check the program and its tests.
+        // Stopping after a compiler stage leaves no program to test.
+        if compiler.front_end_passes()?.is_none() {
+            return Ok(());
+        }
+
+        // Collect the test functions to run.
+        // Note that parsing guarantees that there is exactly one program scope.
+        let program_scope = compiler.ast.ast.program_scopes.values().next().unwrap();
+        let tests: Vec<_> = program_scope
+            .functions
+            .values()
+            .filter(|function| function.is_test())
+            .map(|function| function.name().to_string())
+            .filter(|name| self.filter.as_ref().map_or(true, |filter| name.contains(filter)))
+            .collect();
+
+        tracing::info!("Running {} tests", tests.len());
+
+        // Run each test with its own interpreter, so that it starts with empty mappings.
+        let mut failed = 0;
+        for name in tests.iter() {
+            let mut interpreter = Interpreter::new(&compiler.ast.ast, caller.to_string())?;
+            match interpreter.run_test(name) {
+                Ok(()) => tracing::info!("{name} ... ok"),
+                Err(err) => {
+                    failed += 1;
+                    tracing::info!("{name} ... FAILED");
+                    handler.emit_err(err);
+                }
+            }
+        }
+
+        tracing::info!("{} passed; {failed} failed", tests.len() - failed);
+
+        match failed {
+            0 => Ok(()),
+            _ => Err(CliError::tests_failed(failed, tests.len()).into()),
+        }
+    }
+}
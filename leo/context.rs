@@ -17,10 +17,12 @@
 use crate::commands::Network;
 use leo_errors::{CliError, PackageError, Result};
 use snarkvm::file::Manifest;
+use snarkvm::prelude::PrivateKey;
 
 use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
 use std::fs::File;
 use std::io::Write;
+use std::str::FromStr;
 use std::{
     env::current_dir,
     path::{Path, PathBuf},
@@ -81,4 +83,17 @@ impl Context {
         // Get package name from program id.
         Ok(manifest)
     }
+
+    /// Returns the development private key in the manifest file `program.json`, with which programs are run.
+    pub fn development_private_key(&self) -> Result<PrivateKey<Network>> {
+        let path = self.dir()?.join(Manifest::<Network>::file_name());
+        let manifest = std::fs::read_to_string(&path).map_err(CliError::failed_to_read_development_private_key)?;
+        let json: serde_json::Value =
+            serde_json::from_str(&manifest).map_err(CliError::failed_to_read_development_private_key)?;
+
+        let private_key = json["development"]["private_key"]
+            .as_str()
+            .ok_or_else(|| CliError::failed_to_read_development_private_key("the private key is missing"))?;
+        Ok(PrivateKey::from_str(private_key).map_err(CliError::failed_to_read_development_private_key)?)
+    }
 }
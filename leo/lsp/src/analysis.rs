@@ -0,0 +1,286 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Function, Input, ProgramVisitor, Variant};
+use leo_compiler::disassemble;
+use leo_errors::emitter::Handler;
+use leo_passes::{ImportResolver, ModuleResolver, Pass, SymbolTableCreator, TypeChecker};
+use leo_span::{
+    source_map::FileName,
+    span::BytePos,
+    symbol::{with_session_globals, SessionGlobals, SESSION_GLOBALS},
+    Span,
+};
+
+use std::path::Path;
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// An error or warning reported for a document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The error or warning code, e.g. `EPAR0370005`.
+    pub code: String,
+    pub message: String,
+    pub help: Option<String>,
+    /// The byte range the diagnostic points to.
+    pub range: (usize, usize),
+}
+
+/// A struct, record, mapping, constant, or function declared in a document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Declaration {
+    pub name: String,
+    /// The byte range of the declared name.
+    pub range: (usize, usize),
+    /// The signature shown on hover, e.g. `mapping balances: address => u64`.
+    pub signature: String,
+}
+
+/// A variable found by the type checker.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Variable {
+    pub name: String,
+    /// The byte range of the variable's declaration.
+    pub range: (usize, usize),
+    /// The byte range of the function or finalize block that declares the variable, if any.
+    pub scope: Option<(usize, usize)>,
+    /// The description shown on hover, e.g. `amount: u64`.
+    pub signature: String,
+}
+
+/// The result of analyzing a document.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Analysis {
+    /// The analyzed text, with line endings normalized.
+    /// All byte ranges of the analysis point into this text.
+    pub text: String,
+    pub diagnostics: Vec<Diagnostic>,
+    pub declarations: Vec<Declaration>,
+    pub variables: Vec<Variable>,
+    /// The byte ranges of every function and finalize block.
+    scopes: Vec<(usize, usize)>,
+}
+
+impl Analysis {
+    /// Parses and type checks `text`.
+    ///
+    /// Each analysis runs in its own session, so that documents which are analyzed again and again
+    /// do not grow the source map or the symbol interner of the server.
+    pub fn new(text: &str) -> Self {
+        SESSION_GLOBALS.set(&SessionGlobals::default(), || {
+            let source_file = with_session_globals(|s| s.source_map.new_source(text, FileName::Custom("lsp".into())));
+            let start_pos = source_file.start_pos;
+            let mut analysis = Self {
+                text: source_file.src.clone(),
+                ..Default::default()
+            };
+
+            let (handler, buf) = Handler::new_with_buf();
+            // Note that the parser recovers from syntax errors, so a program with errors is still analyzed.
+            if let Ok(ast) =
+                leo_parser::parse_ast(&handler, &source_file.src, start_pos).map_err(|err| handler.emit_err(err))
+            {
+                let range = |span: Span| to_range(span, start_pos);
+                for scope in ast.as_repr().program_scopes.values() {
+                    for const_ in scope.consts.values() {
+                        analysis.declare(
+                            range(const_.identifier.span),
+                            format!("const {}: {}", const_.identifier, const_.type_),
+                        );
+                    }
+                    for struct_ in scope.structs.values() {
+                        let keyword = if struct_.is_record { "record" } else { "struct" };
+                        let members = struct_
+                            .members
+                            .iter()
+                            .map(|member| member.to_string())
+                            .collect::<Vec<_>>();
+                        analysis.declare(
+                            range(struct_.identifier.span),
+                            format!("{keyword} {} {{ {} }}", struct_.identifier, members.join(", ")),
+                        );
+                    }
+                    for mapping in scope.mappings.values() {
+                        analysis.declare(range(mapping.identifier.span), mapping.to_string());
+                    }
+                    for function in scope.functions.values() {
+                        analysis.declare(range(function.identifier.span), signature(function));
+                        analysis.scopes.push(range(function.span));
+                        if let Some(finalize) = &function.finalize {
+                            analysis.scopes.push(range(finalize.span));
+                        }
+                    }
+                }
+
+                // The modules are resolved from `src/`, and the imported programs from `imports/`,
+                // in the working directory of the server.
+                let resolved = ModuleResolver::do_pass((ast, &handler, Path::new("src")))
+                    .and_then(|ast| ImportResolver::do_pass((ast, &handler, Path::new("imports"), disassemble)));
+
+                // The variables are recorded by the type checker, which also reports the type errors.
+                if let Ok(ast) = resolved.map_err(|err| handler.emit_err(err)) {
+                    if let Ok(symbol_table) = SymbolTableCreator::do_pass((&ast, &handler)) {
+                        let mut type_checker = TypeChecker::new(symbol_table, &handler);
+                        type_checker.visit_program(ast.as_repr());
+                        for (name, variable) in type_checker.into_symbol_table().all_variables() {
+                            let range = range(variable.span);
+                            analysis.variables.push(Variable {
+                                name: name.to_string(),
+                                range,
+                                scope: analysis.scope_of(range.0),
+                                signature: format!("{name}: {}", variable.type_),
+                            });
+                        }
+                    }
+                }
+            }
+
+            for error in buf.extract_errs().into_inner() {
+                let Some(contents) = error.backtraced() else { continue };
+                analysis.diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    code: contents.error_code(),
+                    message: contents.message.clone(),
+                    help: contents.help.clone(),
+                    range: error.span().map_or((0, 0), |span| to_range(span, start_pos)),
+                });
+            }
+            for warning in buf.extract_warnings().into_inner() {
+                let contents = warning.backtraced();
+                analysis.diagnostics.push(Diagnostic {
+                    severity: Severity::Warning,
+                    code: contents.warning_code(),
+                    message: contents.message.clone(),
+                    help: contents.help.clone(),
+                    range: warning.span().map_or((0, 0), |span| to_range(span, start_pos)),
+                });
+            }
+
+            analysis
+        })
+    }
+
+    /// Returns the hover text for the identifier at byte `offset`, if there is one.
+    pub fn hover(&self, offset: usize) -> Option<String> {
+        let (name, start) = self.word_at(offset)?;
+        // Struct members are not tracked, so the name after a `.` is never looked up.
+        if self.text[..start].ends_with('.') {
+            return None;
+        }
+
+        // Prefer the closest preceding declaration of a variable in the enclosing function,
+        // then variables declared outside of any function, and finally the declarations of the program.
+        let scope = self.scope_of(offset);
+        let local = self
+            .variables
+            .iter()
+            .filter(|variable| variable.name == name && variable.scope.is_some() && variable.scope == scope)
+            .filter(|variable| variable.range.0 <= start)
+            .max_by_key(|variable| variable.range.0);
+        let global = || {
+            self.variables
+                .iter()
+                .find(|variable| variable.name == name && variable.scope.is_none())
+        };
+
+        match local.or_else(global) {
+            Some(variable) => Some(variable.signature.clone()),
+            None => self.declaration(name).map(|declaration| declaration.signature.clone()),
+        }
+    }
+
+    /// Returns the byte range of the declaration of the identifier at byte `offset`, if there is one.
+    pub fn definition(&self, offset: usize) -> Option<(usize, usize)> {
+        let (name, start) = self.word_at(offset)?;
+        if self.text[..start].ends_with('.') {
+            return None;
+        }
+        self.declaration(name).map(|declaration| declaration.range)
+    }
+
+    /// Returns the identifier at byte `offset` and its starting offset.
+    pub fn word_at(&self, offset: usize) -> Option<(&str, usize)> {
+        let offset = offset.min(self.text.len());
+        if !self.text.is_char_boundary(offset) {
+            return None;
+        }
+        let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        let start = self.text[..offset].rfind(|c| !is_ident(c)).map_or(0, |index| index + 1);
+        let end = self.text[offset..]
+            .find(|c| !is_ident(c))
+            .map_or(self.text.len(), |index| offset + index);
+        let word = &self.text[start..end];
+        match word.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            true => Some((word, start)),
+            false => None,
+        }
+    }
+
+    /// Returns the declaration named `name`, if there is one.
+    fn declaration(&self, name: &str) -> Option<&Declaration> {
+        self.declarations.iter().find(|declaration| declaration.name == name)
+    }
+
+    /// Records a declaration whose name spans `range`.
+    fn declare(&mut self, range: (usize, usize), signature: String) {
+        let name = self.text[range.0..range.1].to_string();
+        self.declarations.push(Declaration { name, range, signature });
+    }
+
+    /// Returns the range of the function or finalize block containing byte `offset`, if there is one.
+    fn scope_of(&self, offset: usize) -> Option<(usize, usize)> {
+        self.scopes
+            .iter()
+            .copied()
+            .find(|(start, end)| *start <= offset && offset <= *end)
+    }
+}
+
+/// Converts `span` into a byte range of the source starting at `start_pos`.
+fn to_range(span: Span, start_pos: BytePos) -> (usize, usize) {
+    let offset = |pos: BytePos| pos.0.saturating_sub(start_pos.0) as usize;
+    (offset(span.lo), offset(span.hi))
+}
+
+/// Returns the signature of `function`, e.g. `transition mint(receiver: address, amount: u64) -> Token`.
+fn signature(function: &Function) -> String {
+    let variant = match function.variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    };
+    let inputs = function
+        .input
+        .iter()
+        .map(|input| match input {
+            Input::Internal(input) => format!("{}: {}", input.identifier, input.type_),
+            Input::External(_) => input.to_string(),
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "{variant} {}({}) -> {}",
+        function.identifier,
+        inputs.join(", "),
+        function.output_type
+    )
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_core::CoreInstruction;
+use leo_span::{sym, symbol::create_session_if_not_set_then, Symbol};
+
+/// The modules that provide core functions, e.g. `BHP256`.
+const CORE_MODULES: &[Symbol] = &[
+    sym::BHP256,
+    sym::BHP512,
+    sym::BHP768,
+    sym::BHP1024,
+    sym::Pedersen64,
+    sym::Pedersen128,
+    sym::Poseidon2,
+    sym::Poseidon4,
+    sym::Poseidon8,
+];
+
+/// The names of the core functions, e.g. `hash`.
+const CORE_FUNCTIONS: &[Symbol] = &[sym::commit, sym::hash];
+
+/// The kind of a completion item.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompletionKind {
+    Module,
+    Function,
+}
+
+/// A suggestion for the text at the cursor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: CompletionKind,
+    /// A short description of the item, e.g. `BHP256::hash: 1 argument -> field`.
+    pub detail: Option<String>,
+}
+
+/// Returns the core modules and functions that complete the text before byte `offset` of `text`.
+///
+/// After `Module::`, the functions of that module are suggested,
+/// and otherwise the modules whose names start with the identifier being typed.
+pub fn completions(text: &str, offset: usize) -> Vec<CompletionItem> {
+    let offset = offset.min(text.len());
+    if !text.is_char_boundary(offset) {
+        return Vec::new();
+    }
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let prefix_start = text[..offset].rfind(|c| !is_ident(c)).map_or(0, |index| index + 1);
+    let prefix = &text[prefix_start..offset];
+
+    create_session_if_not_set_then(|_| match text[..prefix_start].strip_suffix("::") {
+        Some(before) => {
+            let module_start = before.rfind(|c| !is_ident(c)).map_or(0, |index| index + 1);
+            let module = Symbol::intern(&before[module_start..]);
+            CORE_FUNCTIONS
+                .iter()
+                .filter_map(|function| Some((function, CoreInstruction::from_symbols(module, *function)?)))
+                .filter(|(function, _)| function.to_string().starts_with(prefix))
+                .map(|(function, instruction)| {
+                    let num_args = instruction.num_args();
+                    let arguments = if num_args == 1 { "argument" } else { "arguments" };
+                    CompletionItem {
+                        label: function.to_string(),
+                        kind: CompletionKind::Function,
+                        detail: Some(format!(
+                            "{module}::{function}: {num_args} {arguments} -> {}",
+                            instruction.return_type()
+                        )),
+                    }
+                })
+                .collect()
+        }
+        None => CORE_MODULES
+            .iter()
+            .filter(|module| module.to_string().starts_with(prefix))
+            .map(|module| CompletionItem {
+                label: module.to_string(),
+                kind: CompletionKind::Module,
+                detail: None,
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde_json::{json, Value};
+
+/// A position in a document, as exchanged with the client.
+/// Both fields are zero-based, and `character` counts UTF-16 code units.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub character: usize,
+}
+
+impl Position {
+    /// Reads a position from its JSON representation.
+    pub fn from_json(value: &Value) -> Option<Self> {
+        Some(Self {
+            line: value.get("line")?.as_u64()? as usize,
+            character: value.get("character")?.as_u64()? as usize,
+        })
+    }
+
+    /// Returns the JSON representation of the position.
+    pub fn to_json(self) -> Value {
+        json!({ "line": self.line, "character": self.character })
+    }
+}
+
+/// Converts between byte offsets into a document and client positions.
+pub struct LineIndex<'a> {
+    /// The text of the document.
+    text: &'a str,
+    /// The byte offset at which each line starts.
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Indexes the lines of `text`.
+    pub fn new(text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect::<Vec<_>>();
+        Self { text, line_starts }
+    }
+
+    /// Returns the position of the byte `offset`, which is clamped to the end of the text.
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let start = self.line_starts[line];
+        let character = self.text[start..]
+            .char_indices()
+            .take_while(|(index, _)| start + index < offset)
+            .map(|(_, c)| c.len_utf16())
+            .sum();
+        Position { line, character }
+    }
+
+    /// Returns the byte offset of `position`.
+    /// Positions past the end of a line are clamped to the end of that line,
+    /// and positions past the last line are clamped to the end of the text.
+    pub fn offset(&self, position: Position) -> usize {
+        let Some(start) = self.line_starts.get(position.line).copied() else {
+            return self.text.len();
+        };
+        let end = self
+            .line_starts
+            .get(position.line + 1)
+            .map_or(self.text.len(), |next| next - 1);
+
+        let mut units = 0;
+        for (index, c) in self.text[start..end].char_indices() {
+            if units >= position.character {
+                return start + index;
+            }
+            units += c.len_utf16();
+        }
+        end
+    }
+
+    /// Returns the JSON representation of the range between the byte offsets `start` and `end`.
+    pub fn range_to_json(&self, start: usize, end: usize) -> Value {
+        json!({ "start": self.position(start).to_json(), "end": self.position(end).to_json() })
+    }
+
+    /// Reads a range from its JSON representation, returning its byte offsets.
+    pub fn range_from_json(&self, value: &Value) -> Option<(usize, usize)> {
+        let start = Position::from_json(value.get("start")?)?;
+        let end = Position::from_json(value.get("end")?)?;
+        Some((self.offset(start), self.offset(end)))
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{completions, read_message, write_message, Analysis, CompletionKind, LineIndex, Position, Severity};
+
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+};
+
+/// The JSON-RPC error code for a message that is not valid JSON.
+const PARSE_ERROR: i64 = -32700;
+/// The JSON-RPC error code for a request that cannot be handled in the current state.
+const INVALID_REQUEST: i64 = -32600;
+/// The JSON-RPC error code for an unknown request.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// The JSON-RPC error code for a request with missing or malformed parameters.
+const INVALID_PARAMS: i64 = -32602;
+
+/// A document opened by the client.
+struct Document {
+    /// The text of the document, as the client sees it.
+    text: String,
+    /// The analysis of the current text.
+    analysis: Analysis,
+}
+
+impl Document {
+    fn new(text: String) -> Self {
+        let analysis = Analysis::new(&text);
+        Self { text, analysis }
+    }
+}
+
+/// A language server that reads messages from `reader` and writes messages to `writer`.
+pub struct Server<R: BufRead, W: Write> {
+    reader: R,
+    writer: W,
+    /// The open documents, by URI.
+    documents: HashMap<String, Document>,
+    /// Whether the client has requested a shutdown.
+    shutdown: bool,
+}
+
+impl<R: BufRead, W: Write> Server<R, W> {
+    /// Returns a new server communicating over `reader` and `writer`.
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            documents: HashMap::new(),
+            shutdown: false,
+        }
+    }
+
+    /// Returns the writer of the server.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+
+    /// Handles messages until the client sends `exit` or closes the input.
+    pub fn run(&mut self) -> io::Result<()> {
+        while let Some(body) = read_message(&mut self.reader)? {
+            match serde_json::from_slice::<Value>(&body) {
+                Ok(message) => {
+                    if !self.handle(&message)? {
+                        break;
+                    }
+                }
+                Err(error) => self.send_error(Value::Null, PARSE_ERROR, &error.to_string())?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles a single message, returning whether the server should keep running.
+    fn handle(&mut self, message: &Value) -> io::Result<bool> {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // Responses to requests sent by the server are not used.
+            return Ok(true);
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        match message.get("id").cloned() {
+            Some(id) => self.handle_request(id, method, &params)?,
+            None => match method {
+                "exit" => return Ok(false),
+                "textDocument/didOpen" => self.did_open(&params)?,
+                "textDocument/didChange" => self.did_change(&params)?,
+                "textDocument/didClose" => self.did_close(&params)?,
+                // Other notifications, such as `initialized`, need no action.
+                _ => {}
+            },
+        }
+        Ok(true)
+    }
+
+    /// Answers the request `id`.
+    fn handle_request(&mut self, id: Value, method: &str, params: &Value) -> io::Result<()> {
+        if self.shutdown {
+            return self.send_error(id, INVALID_REQUEST, "the server is shutting down");
+        }
+
+        let result = match method {
+            "initialize" => Some(json!({
+                "capabilities": {
+                    "textDocumentSync": { "openClose": true, "change": 2 },
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "completionProvider": { "triggerCharacters": [":"] },
+                },
+                "serverInfo": { "name": "leo", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "shutdown" => {
+                self.shutdown = true;
+                Some(Value::Null)
+            }
+            "textDocument/hover" => self.hover(params),
+            "textDocument/definition" => self.definition(params),
+            "textDocument/completion" => self.completion(params),
+            _ => return self.send_error(id, METHOD_NOT_FOUND, &format!("unknown method `{method}`")),
+        };
+
+        match result {
+            Some(result) => self.send(&json!({ "jsonrpc": "2.0", "id": id, "result": result })),
+            None => self.send_error(id, INVALID_PARAMS, "unknown document or malformed parameters"),
+        }
+    }
+
+    /// Returns the document and the byte offset of the position in `params`.
+    fn locate<'a>(&'a self, params: &'a Value) -> Option<(&'a str, &'a Analysis, usize)> {
+        let uri = params.get("textDocument")?.get("uri")?.as_str()?;
+        let document = self.documents.get(uri)?;
+        let position = Position::from_json(params.get("position")?)?;
+        let offset = LineIndex::new(&document.analysis.text).offset(position);
+        Some((uri, &document.analysis, offset))
+    }
+
+    fn hover(&self, params: &Value) -> Option<Value> {
+        let (_, analysis, offset) = self.locate(params)?;
+        Some(match analysis.hover(offset) {
+            Some(signature) => json!({
+                "contents": { "kind": "markdown", "value": format!("```leo\n{signature}\n```") },
+            }),
+            None => Value::Null,
+        })
+    }
+
+    fn definition(&self, params: &Value) -> Option<Value> {
+        let (uri, analysis, offset) = self.locate(params)?;
+        Some(match analysis.definition(offset) {
+            Some((start, end)) => json!({
+                "uri": uri,
+                "range": LineIndex::new(&analysis.text).range_to_json(start, end),
+            }),
+            None => Value::Null,
+        })
+    }
+
+    fn completion(&self, params: &Value) -> Option<Value> {
+        let (_, analysis, offset) = self.locate(params)?;
+        let items = completions(&analysis.text, offset)
+            .into_iter()
+            .map(|item| {
+                let kind = match item.kind {
+                    CompletionKind::Module => 9,
+                    CompletionKind::Function => 3,
+                };
+                json!({ "label": item.label, "kind": kind, "detail": item.detail })
+            })
+            .collect::<Vec<_>>();
+        Some(Value::Array(items))
+    }
+
+    fn did_open(&mut self, params: &Value) -> io::Result<()> {
+        let document = &params["textDocument"];
+        let (Some(uri), Some(text)) = (document["uri"].as_str(), document["text"].as_str()) else {
+            return Ok(());
+        };
+        self.documents.insert(uri.to_string(), Document::new(text.to_string()));
+        self.publish_diagnostics(uri)
+    }
+
+    fn did_change(&mut self, params: &Value) -> io::Result<()> {
+        let Some(uri) = params["textDocument"]["uri"].as_str() else {
+            return Ok(());
+        };
+        let Some(document) = self.documents.get_mut(uri) else {
+            return Ok(());
+        };
+
+        // Apply the changes in order, each to the text produced by the previous one.
+        let mut text = std::mem::take(&mut document.text);
+        for change in params["contentChanges"].as_array().into_iter().flatten() {
+            let Some(new_text) = change["text"].as_str() else {
+                continue;
+            };
+            match change
+                .get("range")
+                .and_then(|range| LineIndex::new(&text).range_from_json(range))
+            {
+                Some((start, end)) => text.replace_range(start..end.max(start), new_text),
+                None => text = new_text.to_string(),
+            }
+        }
+        *document = Document::new(text);
+        self.publish_diagnostics(uri)
+    }
+
+    fn did_close(&mut self, params: &Value) -> io::Result<()> {
+        let Some(uri) = params["textDocument"]["uri"].as_str() else {
+            return Ok(());
+        };
+        self.documents.remove(uri);
+        // Clear the diagnostics of the closed document.
+        self.send(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": [] },
+        }))
+    }
+
+    /// Sends the diagnostics of the document `uri` to the client.
+    fn publish_diagnostics(&mut self, uri: &str) -> io::Result<()> {
+        let Some(document) = self.documents.get(uri) else {
+            return Ok(());
+        };
+        let index = LineIndex::new(&document.analysis.text);
+        let diagnostics = document
+            .analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let severity = match diagnostic.severity {
+                    Severity::Error => 1,
+                    Severity::Warning => 2,
+                };
+                let message = match &diagnostic.help {
+                    Some(help) => format!("{}\n{help}", diagnostic.message),
+                    None => diagnostic.message.clone(),
+                };
+                json!({
+                    "range": index.range_to_json(diagnostic.range.0, diagnostic.range.1),
+                    "severity": severity,
+                    "code": diagnostic.code,
+                    "source": "leo",
+                    "message": message,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let notification = json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        });
+        self.send(&notification)
+    }
+
+    fn send_error(&mut self, id: Value, code: i64, message: &str) -> io::Result<()> {
+        self.send(&json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } }))
+    }
+
+    fn send(&mut self, message: &Value) -> io::Result<()> {
+        write_message(&mut self.writer, message)
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_lsp::{read_message, write_message, Server};
+
+use serde_json::{json, Value};
+use std::io::Cursor;
+
+const URI: &str = "file:///token/src/main.leo";
+
+const PROGRAM: &str = "program token.aleo {
+    record Token {
+        owner: address,
+        gates: u64,
+        amount: u64,
+    }
+
+    mapping balances: address => u64;
+
+    transition mint(receiver: address, amount: u64) -> Token {
+        let digest: field = BHP256::hash(amount);
+        return Token { owner: receiver, gates: 0u64, amount };
+    }
+}
+";
+
+/// Runs a server on the given client messages, returning the messages it sent back.
+fn run(messages: &[Value]) -> Vec<Value> {
+    let mut input = Vec::new();
+    for message in messages {
+        write_message(&mut input, message).unwrap();
+    }
+
+    let mut server = Server::new(Cursor::new(input), Vec::new());
+    server.run().unwrap();
+
+    let mut output = Cursor::new(server.into_writer());
+    let mut responses = Vec::new();
+    while let Some(body) = read_message(&mut output).unwrap() {
+        responses.push(serde_json::from_slice(&body).unwrap());
+    }
+    responses
+}
+
+fn open(text: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/didOpen",
+        "params": { "textDocument": { "uri": URI, "languageId": "leo", "version": 1, "text": text } },
+    })
+}
+
+fn request(id: u64, method: &str, line: usize, character: usize) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": method,
+        "params": { "textDocument": { "uri": URI }, "position": { "line": line, "character": character } },
+    })
+}
+
+/// Returns the result of the response to the request `id`.
+fn result(responses: &[Value], id: u64) -> &Value {
+    &responses.iter().find(|response| response["id"] == id).unwrap()["result"]
+}
+
+/// Returns the diagnostics of the last `publishDiagnostics` notification.
+fn last_diagnostics(responses: &[Value]) -> &Vec<Value> {
+    responses
+        .iter()
+        .rev()
+        .find(|response| response["method"] == "textDocument/publishDiagnostics")
+        .unwrap()["params"]["diagnostics"]
+        .as_array()
+        .unwrap()
+}
+
+#[test]
+fn initialize_and_shutdown() {
+    let responses = run(&[
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": { "capabilities": {} } }),
+        json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "unknown/method" }),
+        json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }),
+        json!({ "jsonrpc": "2.0", "method": "exit" }),
+        json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" }),
+    ]);
+
+    let capabilities = &result(&responses, 1)["capabilities"];
+    assert_eq!(capabilities["hoverProvider"], true);
+    assert_eq!(capabilities["definitionProvider"], true);
+    assert_eq!(capabilities["textDocumentSync"]["change"], 2);
+    assert_eq!(responses[1]["error"]["code"], -32601);
+    assert_eq!(*result(&responses, 3), Value::Null);
+    // Messages after `exit` are not handled.
+    assert_eq!(responses.len(), 3);
+}
+
+#[test]
+fn diagnostics_of_a_valid_program_are_empty() {
+    let responses = run(&[open(PROGRAM)]);
+    assert!(last_diagnostics(&responses).is_empty());
+}
+
+#[test]
+fn diagnostics_are_published_for_parse_and_type_errors() {
+    let responses = run(&[open("program test.aleo {\n    transition main( -> u8 {}\n}\n")]);
+    let diagnostics = last_diagnostics(&responses);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]["code"].as_str().unwrap().starts_with("EPAR"));
+    assert_eq!(diagnostics[0]["range"]["start"], json!({ "line": 1, "character": 21 }));
+
+    let responses = run(&[open(
+        "program test.aleo {\n    transition main(a: u8) -> bool {\n        return a;\n    }\n}\n",
+    )]);
+    let diagnostics = last_diagnostics(&responses);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]["code"].as_str().unwrap().starts_with("ETYC"));
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 2);
+}
+
+#[test]
+fn changes_are_applied_to_the_open_document() {
+    let responses = run(&[
+        open(PROGRAM),
+        // Replace `BHP256` with `BHP9`, which is not a core module.
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didChange",
+            "params": {
+                "textDocument": { "uri": URI, "version": 2 },
+                "contentChanges": [{
+                    "range": { "start": { "line": 10, "character": 28 }, "end": { "line": 10, "character": 34 } },
+                    "text": "BHP9",
+                }],
+            },
+        }),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didClose",
+            "params": { "textDocument": { "uri": URI } },
+        }),
+    ]);
+
+    let published = responses
+        .iter()
+        .filter(|response| response["method"] == "textDocument/publishDiagnostics")
+        .map(|response| response["params"]["diagnostics"].as_array().unwrap().len())
+        .collect::<Vec<_>>();
+    // The type checker reports both the unknown instruction and the invalid call.
+    assert_eq!(published, [0, 2, 0]);
+}
+
+#[test]
+fn hover_shows_types_and_signatures() {
+    let responses = run(&[
+        open(PROGRAM),
+        // `amount` in `BHP256::hash(amount)`.
+        request(1, "textDocument/hover", 10, 42),
+        // `Token` in the return type.
+        request(2, "textDocument/hover", 9, 58),
+        // `mint`.
+        request(3, "textDocument/hover", 9, 16),
+        // The member `amount` of the record is not tracked.
+        request(4, "textDocument/hover", 4, 9),
+        // `digest`.
+        request(5, "textDocument/hover", 10, 13),
+    ]);
+
+    let hover = |id| result(&responses, id)["contents"]["value"].as_str().map(str::to_string);
+    assert_eq!(hover(1).unwrap(), "```leo\namount: u64\n```");
+    assert_eq!(
+        hover(2).unwrap(),
+        "```leo\nrecord Token { owner: address, gates: u64, amount: u64 }\n```"
+    );
+    assert_eq!(
+        hover(3).unwrap(),
+        "```leo\ntransition mint(receiver: address, amount: u64) -> Token\n```"
+    );
+    assert_eq!(hover(4), None);
+    assert_eq!(hover(5).unwrap(), "```leo\ndigest: field\n```");
+}
+
+#[test]
+fn definition_finds_declarations() {
+    let responses = run(&[
+        open(PROGRAM),
+        // `Token` in the struct expression.
+        request(1, "textDocument/definition", 11, 16),
+        // `receiver` is a variable, not a declaration.
+        request(2, "textDocument/definition", 11, 31),
+    ]);
+
+    let location = result(&responses, 1);
+    assert_eq!(location["uri"], URI);
+    assert_eq!(
+        location["range"],
+        json!({ "start": { "line": 1, "character": 11 }, "end": { "line": 1, "character": 16 } })
+    );
+    assert_eq!(*result(&responses, 2), Value::Null);
+}
+
+#[test]
+fn completion_suggests_core_functions() {
+    let text = "program test.aleo {\n    transition main(a: u8) -> field {\n        return Poseidon2::;\n    }\n}\n";
+    let responses = run(&[
+        open(text),
+        // After `Poseidon2::`.
+        request(1, "textDocument/completion", 2, 26),
+        // After `Pos`.
+        request(2, "textDocument/completion", 2, 18),
+    ]);
+
+    let functions = result(&responses, 1).as_array().unwrap();
+    assert_eq!(functions.len(), 1);
+    assert_eq!(functions[0]["label"], "hash");
+    assert_eq!(functions[0]["detail"], "Poseidon2::hash: 1 argument -> field");
+
+    let labels = result(&responses, 2)
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(labels, ["Poseidon2", "Poseidon4", "Poseidon8"]);
+}
+
+#[test]
+fn positions_count_utf16_code_units() {
+    // The comment contains a character outside of the basic multilingual plane,
+    // which takes two UTF-16 code units and four bytes.
+    let text = "program test.aleo {\n    transition main(a: u8) -> u8 { /* 🦀 */ let b: u8 = a; return b; }\n}\n";
+    let responses = run(&[open(text), request(1, "textDocument/hover", 1, 66)]);
+    assert_eq!(result(&responses, 1)["contents"]["value"], "```leo\nb: u8\n```");
+}
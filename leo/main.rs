@@ -82,6 +82,21 @@ enum Commands {
         #[structopt(flatten)]
         command: Run,
     },
+    #[structopt(about = "Run the `@test` functions in the tests/ directory")]
+    Test {
+        #[structopt(flatten)]
+        command: Test,
+    },
+    #[structopt(about = "Format the Leo files in the src/ and tests/ directories")]
+    Fmt {
+        #[structopt(flatten)]
+        command: Fmt,
+    },
+    #[structopt(about = "Run the Leo language server over stdio")]
+    Lsp {
+        #[structopt(flatten)]
+        command: Lsp,
+    },
     // #[structopt(subcommand)]
     // Node(Node),
     #[structopt(about = "Deploy a program")]
@@ -152,6 +167,9 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
         Commands::Build { command } => command.try_execute(context),
         Commands::Clean { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
+        Commands::Test { command } => command.try_execute(context),
+        Commands::Fmt { command } => command.try_execute(context),
+        Commands::Lsp { command } => command.try_execute(context),
         // Commands::Node(command) => command.try_execute(context),
         Commands::Deploy { command } => command.try_execute(context),
     }
@@ -18,6 +18,7 @@ use crate::{
     inputs::{InputFile, InputsDirectory},
     root::Gitignore,
     source::{MainFile, SourceDirectory},
+    tests::{TestFile, TestsDirectory},
 };
 
 use leo_errors::{PackageError, Result};
@@ -150,6 +151,9 @@ impl Package {
         // Create the inputs directory.
         InputsDirectory::create(path)?;
 
+        // Create the tests directory.
+        TestsDirectory::create(path)?;
+
         // Create the Leo build/ directory
         BuildDirectory::create(path)?;
 
@@ -159,6 +163,9 @@ impl Package {
         // Create the main file in the source directory.
         MainFile::new(package_name).write_to(path)?;
 
+        // Create an example test file in the tests directory.
+        TestFile::new(package_name).write_to(path)?;
+
         // Next, verify that a valid Leo package has been initialized in this directory
         if !Self::is_initialized(package_name, path) {
             return Err(PackageError::failed_to_initialize_package(package_name, path.as_os_str()).into());
@@ -57,11 +57,12 @@ impl SourceDirectory {
     }
 
     /// Check that the files in the source directory are valid.
+    /// The source directory contains the main file, and the modules it declares.
     pub fn check_files(paths: &[PathBuf]) -> Result<()> {
-        match paths.len() {
-            0 => Err(PackageError::empty_source_directory().into()),
-            1 if paths[0].as_path().ends_with(MAIN_FILENAME) => Ok(()),
-            _ => Err(PackageError::source_directory_can_contain_only_one_file().into()),
+        match paths.iter().any(|path| path.ends_with(MAIN_FILENAME)) {
+            _ if paths.is_empty() => Err(PackageError::empty_source_directory().into()),
+            true => Ok(()),
+            false => Err(PackageError::source_directory_must_contain_main_file().into()),
         }
     }
 }
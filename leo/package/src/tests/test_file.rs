@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `test_{package_name}.leo` file.
+
+use crate::tests::TESTS_DIRECTORY_NAME;
+use leo_errors::{PackageError, Result};
+
+use serde::Deserialize;
+use std::{borrow::Cow, fs::File, io::Write, path::Path};
+
+#[derive(Deserialize)]
+pub struct TestFile {
+    pub package_name: String,
+}
+
+impl TestFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn filename(&self) -> String {
+        format!("{TESTS_DIRECTORY_NAME}test_{}.leo", self.package_name)
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        self.setup_file_path(path).exists()
+    }
+
+    pub fn write_to(self, path: &Path) -> Result<()> {
+        let path = self.setup_file_path(path);
+
+        let mut file = File::create(&path).map_err(PackageError::io_error_test_file)?;
+        Ok(file
+            .write_all(self.template().as_bytes())
+            .map_err(PackageError::io_error_test_file)?)
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(TESTS_DIRECTORY_NAME) {
+                path.to_mut().push(TESTS_DIRECTORY_NAME);
+            }
+            path.to_mut().push(format!("test_{}.leo", self.package_name));
+        }
+        path
+    }
+
+    // TODO: Generalize to other networks.
+    fn template(&self) -> String {
+        format!(
+            r#"// The tests of the '{}' program.
+// Each `@test` function is run by `leo test`, and can call the program's transitions and inline functions.
+program {}.aleo {{
+    @test
+    function test_main() {{
+        let c: u32 = main(1u32, 2u32);
+        assert_eq(c, 3u32);
+    }}
+}}
+"#,
+            self.package_name, self.package_name
+        )
+    }
+}
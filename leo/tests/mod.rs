@@ -44,6 +44,43 @@ pub fn format_event() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn snapshot_flags_alias_emit() {
+    use crate::commands::build::BuildOptions;
+    use clap::StructOpt;
+    use leo_compiler::{Compiler, OutputOptions};
+
+    let options = BuildOptions::parse_from([
+        "leo",
+        "--emit",
+        "type_check",
+        "--enable-initial-ast-snapshot",
+        "--enable-unrolled-ast-snapshot",
+        "--enable-ssa-ast-snapshot",
+        "--enable-constant-folded-ast-snapshot",
+        "--enable-flattened-ast-snapshot",
+        "--enable-inlined-ast-snapshot",
+        "--enable-cse-ast-snapshot",
+        "--enable-dce-ast-snapshot",
+    ]);
+    let emit = OutputOptions::from(options).emit;
+    assert_eq!(
+        emit,
+        [
+            "type_check",
+            "parse",
+            "unroll",
+            "ssa",
+            "const_fold",
+            "flatten",
+            "inline",
+            "cse",
+            "dce"
+        ]
+    );
+    assert!(emit.iter().all(|stage| Compiler::STAGES.contains(&stage.as_str())));
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {
@@ -16,7 +16,7 @@
 
 //! This file contains tools for benchmarking the Leo compiler and its stages.
 
-use leo_compiler::Compiler;
+use leo_compiler::{Compiler, OutputOptions};
 use leo_errors::emitter::{Emitter, Handler};
 use leo_span::{source_map::FileName, symbol::SESSION_GLOBALS};
 use leo_test_framework::get_benches;
@@ -31,20 +31,11 @@ use std::{
 enum BenchMode {
     /// Benchmarks parsing.
     Parse,
-    /// Benchmarks symbol table generation.
-    Symbol,
-    /// Benchmarks type checking.
-    Type,
-    /// Benchmarks loop unrolling.
-    Unroll,
-    /// Benchmarks static single assignment.
-    Ssa,
-    /// Benchmarks flattening.
-    Flatten,
-    /// Benchmarks function inlining.
-    Inline,
+    /// Benchmarks the compiler pass with the given name, see `Compiler::STAGES`.
+    /// The passes before it run as they would in a full compilation, but are not timed.
+    Pass(&'static str),
     // TODO: Benchmark code generation
-    /// Benchmarks all the above stages.
+    /// Benchmarks parsing and all the compiler passes.
     Full,
 }
 
@@ -75,14 +66,14 @@ struct Sample {
 }
 
 /// A helper function to help create a Leo Compiler struct.
-fn new_compiler(handler: &Handler) -> Compiler<'_> {
+fn new_compiler(handler: &Handler, output_options: OutputOptions) -> Compiler<'_> {
     Compiler::new(
         String::from("bench"),
         String::from("aleo"),
         handler,
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
-        None,
+        Some(output_options),
     )
 }
 
@@ -104,43 +95,36 @@ impl Sample {
     fn bench(&self, c: &mut Criterion, mode: BenchMode) {
         match mode {
             BenchMode::Parse => self.bench_parse(c),
-            BenchMode::Symbol => self.bench_symbol_table(c),
-            BenchMode::Type => self.bench_type_checker(c),
-            BenchMode::Unroll => self.bench_loop_unroller(c),
-            BenchMode::Ssa => self.bench_ssa(c),
-            BenchMode::Flatten => self.bench_flattener(c),
-            BenchMode::Inline => self.bench_inline(c),
+            BenchMode::Pass(name) => self.bench_pass(c, name),
             BenchMode::Full => self.bench_full(c),
         }
     }
 
-    /// Benchmarks `logic(compiler)` where `compiler` is provided.
-    fn bencher(&self, c: &mut Criterion, mode: &str, mut logic: impl FnMut(Compiler) -> Duration) {
+    /// Benchmarks `logic(compiler)` where `compiler` is created with the given output options.
+    fn bencher(
+        &self,
+        c: &mut Criterion,
+        mode: &str,
+        output_options: OutputOptions,
+        mut logic: impl FnMut(Compiler) -> Duration,
+    ) {
         c.bench_function(&format!("{mode} {}", self.name), |b| {
             // Iter custom is used so we can use custom timings around the compiler stages.
             // This way we can only time the necessary stage.
             b.iter_custom(|iters| {
                 (0..iters)
-                    .map(|_| SESSION_GLOBALS.set(&<_>::default(), || logic(new_compiler(&BufEmitter::new_handler()))))
+                    .map(|_| {
+                        SESSION_GLOBALS.set(&<_>::default(), || {
+                            logic(new_compiler(&BufEmitter::new_handler(), output_options.clone()))
+                        })
+                    })
                     .sum()
             });
         });
     }
 
-    /// Benchmarks `logic(compiler)` where `compiler` is provided.
-    /// Parsing has already been done.
-    fn bencher_after_parse(&self, c: &mut Criterion, mode: &str, mut logic: impl FnMut(Compiler) -> Duration) {
-        self.bencher(c, mode, |mut compiler| {
-            let (input, name) = self.data();
-            compiler
-                .parse_program_from_string(input, name)
-                .expect("Failed to parse program");
-            logic(compiler)
-        });
-    }
-
     fn bench_parse(&self, c: &mut Criterion) {
-        self.bencher(c, "parse", |mut compiler| {
+        self.bencher(c, "parse", OutputOptions::default(), |mut compiler| {
             let (input, name) = self.data();
             let start = Instant::now();
             let out = compiler.parse_program_from_string(input, name);
@@ -150,124 +134,36 @@ impl Sample {
         })
     }
 
-    fn bench_symbol_table(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "symbol table pass", |compiler| {
-            let start = Instant::now();
-            let out = compiler.symbol_table_pass();
-            let time = start.elapsed();
-            out.expect("failed to generate symbol table");
-            time
-        });
-    }
-
-    fn bench_type_checker(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "type checker pass", |compiler| {
-            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let start = Instant::now();
-            let out = compiler.type_checker_pass(symbol_table);
-            let time = start.elapsed();
-            out.expect("failed to run type check pass");
-            time
-        });
-    }
-
-    fn bench_loop_unroller(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "loop unrolling pass", |mut compiler| {
-            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, _call_graph) = compiler
-                .type_checker_pass(symbol_table)
-                .expect("failed to run type check pass");
-            let start = Instant::now();
-            let out = compiler.loop_unrolling_pass(symbol_table);
-            let time = start.elapsed();
-            out.expect("failed to run loop unrolling pass");
-            time
-        });
-    }
-
-    fn bench_ssa(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "full", |mut compiler| {
-            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, _call_graph) = compiler
-                .type_checker_pass(symbol_table)
-                .expect("failed to run type check pass");
-            let symbol_table = compiler
-                .loop_unrolling_pass(symbol_table)
-                .expect("failed to run loop unrolling pass");
-            let start = Instant::now();
-            let out = compiler.static_single_assignment_pass(&symbol_table);
-            let time = start.elapsed();
-            out.expect("failed to run ssa pass");
-            time
+    /// Runs the compiler stages up to and including the pass with the given name,
+    /// and reports the time the pass manager recorded for that pass.
+    fn bench_pass(&self, c: &mut Criterion, pass: &'static str) {
+        let output_options = OutputOptions {
+            stop_after: Some(pass.to_string()),
+            time_passes: true,
+            ..Default::default()
+        };
+        self.bencher(c, &format!("{pass} pass"), output_options, |mut compiler| {
+            let (input, name) = self.data();
+            compiler
+                .parse_program_from_string(input, name)
+                .expect("Failed to parse program");
+            compiler.compiler_stages().expect("Failed to run the compiler stages");
+            compiler
+                .pass_timings()
+                .into_iter()
+                .find_map(|(name, time)| (name == pass).then_some(time))
+                .unwrap_or_else(|| panic!("the `{}` pass did not run", pass))
         })
     }
 
-    fn bench_flattener(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "flattener pass", |mut compiler| {
-            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, _call_graph) = compiler
-                .type_checker_pass(symbol_table)
-                .expect("failed to run type check pass");
-            let symbol_table = compiler
-                .loop_unrolling_pass(symbol_table)
-                .expect("failed to run loop unrolling pass");
-            let assigner = compiler
-                .static_single_assignment_pass(&symbol_table)
-                .expect("failed to run ssa pass");
-            let start = Instant::now();
-            let out = compiler.flattening_pass(&symbol_table, assigner);
-            let time = start.elapsed();
-            out.expect("failed to run flattener pass");
-            time
-        });
-    }
-
-    fn bench_inline(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "inliner pass", |mut compiler| {
-            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, call_graph) = compiler
-                .type_checker_pass(symbol_table)
-                .expect("failed to run type check pass");
-            let symbol_table = compiler
-                .loop_unrolling_pass(symbol_table)
-                .expect("failed to run loop unrolling pass");
-            let assigner = compiler
-                .static_single_assignment_pass(&symbol_table)
-                .expect("failed to run ssa pass");
-            let assigner = compiler
-                .flattening_pass(&symbol_table, assigner)
-                .expect("failed to run flattener pass");
-            let start = Instant::now();
-            let out = compiler.function_inlining_pass(&call_graph, assigner);
-            let time = start.elapsed();
-            out.expect("failed to run inliner pass");
-            time
-        });
-    }
-
     fn bench_full(&self, c: &mut Criterion) {
-        self.bencher(c, "full", |mut compiler| {
+        self.bencher(c, "full", OutputOptions::default(), |mut compiler| {
             let (input, name) = self.data();
             let start = Instant::now();
             compiler
                 .parse_program_from_string(input, name)
                 .expect("Failed to parse program");
-            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, call_graph) = compiler
-                .type_checker_pass(symbol_table)
-                .expect("failed to run type check pass");
-            let symbol_table = compiler
-                .loop_unrolling_pass(symbol_table)
-                .expect("failed to run loop unrolling pass");
-            let assigner = compiler
-                .static_single_assignment_pass(&symbol_table)
-                .expect("failed to run ssa pass");
-            let assigner = compiler
-                .flattening_pass(&symbol_table, assigner)
-                .expect("failed to run flattening pass");
-            compiler
-                .function_inlining_pass(&call_graph, assigner)
-                .expect("failed to run function inlining pass");
+            compiler.compiler_stages().expect("Failed to run the compiler stages");
             start.elapsed()
         })
     }
@@ -282,12 +178,15 @@ macro_rules! bench {
 }
 
 bench!(bench_parse, BenchMode::Parse);
-bench!(bench_symbol, BenchMode::Symbol);
-bench!(bench_type, BenchMode::Type);
-bench!(bench_unroll, BenchMode::Unroll);
-bench!(bench_ssa, BenchMode::Ssa);
-bench!(bench_flatten, BenchMode::Flatten);
-bench!(bench_inline, BenchMode::Inline);
+bench!(bench_symbol, BenchMode::Pass("symbol_table"));
+bench!(bench_type, BenchMode::Pass("type_check"));
+bench!(bench_unroll, BenchMode::Pass("unroll"));
+bench!(bench_ssa, BenchMode::Pass("ssa"));
+bench!(bench_constant_fold, BenchMode::Pass("const_fold"));
+bench!(bench_flatten, BenchMode::Pass("flatten"));
+bench!(bench_inline, BenchMode::Pass("inline"));
+bench!(bench_cse, BenchMode::Pass("cse"));
+bench!(bench_dce, BenchMode::Pass("dce"));
 bench!(bench_full, BenchMode::Full);
 
 criterion_group!(
@@ -299,8 +198,11 @@ criterion_group!(
         bench_type,
         bench_unroll,
         bench_ssa,
+        bench_constant_fold,
         bench_flatten,
         bench_inline,
+        bench_cse,
+        bench_dce,
         bench_full
 );
 criterion_main!(benches);